@@ -0,0 +1,372 @@
+use serde::{Deserialize, Serialize};
+use super::{BettingPhase, Episode, ErrorCode, GameError, GameState, Question, Round, RuleSet};
+
+/// Alle acties die het spelverloop veranderen. Elke Tauri/HTTP command vertaalt zich naar
+/// één `GameAction`, zodat het spel ook opnieuw opgebouwd kan worden uit het event log.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GameAction {
+    StartGame {
+        game_id: String,
+        created_at: String,
+        player_names: Vec<String>,
+        #[serde(default)]
+        rules: RuleSet,
+    },
+    UpdateAnswer {
+        player_id: String,
+        question_number: i32,
+        image_path: String, // Gerenderde tekening staat al op schijf
+        #[serde(default)]
+        drawing_path: String, // Pennenstreken ook; alleen het pad komt in de state en het event log
+    },
+    ClearPlayerAnswers { player_id: String },
+    ApproveAnswer { player_id: String, question_number: i32, is_correct: bool },
+    CollectInitialBets,
+    AddBetsToPot,
+    PlaceBet { player_id: String, amount: i32 }, // Verhogen met `amount` bovenop de hoogste inzet
+    Check { player_id: String },
+    Call { player_id: String },
+    AllIn { player_id: String },
+    PlayerFold { player_id: String },
+    AdvancePhase,
+    CompleteRound { winner_id: Option<String> },
+    StartNextRound,
+    SetRoundNumber { round_num: i32 },
+    ResetGame,
+    TogglePlayerActive { player_id: String, is_active: bool },
+    RevealQuestion { question_number: i32 },
+    ToggleVideoMode,
+    ToggleWriting { enabled: bool },
+    TimerExpired, // Tijd is om: schrijven dicht, zonder undo stap
+    UpdatePlayerName { player_id: String, new_name: String },
+    AssignQuestions { questions: Vec<Question> },
+    LoadEpisode { episode: Episode },
+    Undo,
+    Redo,
+}
+
+impl GameAction {
+    /// Label voor de undo stack, of `None` als de actie geen eigen undo stap krijgt
+    /// (tablets syncen hun antwoord na elke pennenstreek; het aflopen van de timer mag de redo
+    /// stack van de quizmaster niet weggooien; een draaiboek hoort bij het starten van het spel).
+    pub fn undo_label(&self) -> Option<String> {
+        let label = match self {
+            GameAction::StartGame { .. }
+            | GameAction::UpdateAnswer { .. }
+            | GameAction::TimerExpired
+            | GameAction::LoadEpisode { .. }
+            | GameAction::Undo
+            | GameAction::Redo => return None,
+            GameAction::ClearPlayerAnswers { player_id } => format!("clear_player_answers {}", player_id),
+            GameAction::ApproveAnswer { player_id, question_number, is_correct } => format!(
+                "approve_answer {} vraag {} {}",
+                player_id, question_number, if *is_correct { "goed" } else { "fout" }
+            ),
+            GameAction::CollectInitialBets => "collect_initial_bets".to_string(),
+            GameAction::AddBetsToPot => "add_bets_to_pot".to_string(),
+            GameAction::PlaceBet { player_id, amount } => format!("place_bet {} €{}", player_id, amount),
+            GameAction::Check { player_id } => format!("check {}", player_id),
+            GameAction::Call { player_id } => format!("call {}", player_id),
+            GameAction::AllIn { player_id } => format!("all_in {}", player_id),
+            GameAction::PlayerFold { player_id } => format!("player_fold {}", player_id),
+            GameAction::AdvancePhase => "advance_phase".to_string(),
+            GameAction::CompleteRound { winner_id: Some(id) } => format!("complete_round winnaar {}", id),
+            GameAction::CompleteRound { winner_id: None } => "complete_round".to_string(),
+            GameAction::StartNextRound => "start_next_round".to_string(),
+            GameAction::SetRoundNumber { round_num } => format!("set_round_number {}", round_num),
+            GameAction::ResetGame => "reset_game".to_string(),
+            GameAction::TogglePlayerActive { player_id, is_active } => format!(
+                "toggle_player_active {} {}",
+                player_id, if *is_active { "actief" } else { "geëlimineerd" }
+            ),
+            GameAction::RevealQuestion { question_number } => format!("reveal_question {}", question_number),
+            GameAction::ToggleVideoMode => "toggle_video_mode".to_string(),
+            GameAction::ToggleWriting { enabled } => format!("toggle_writing {}", if *enabled { "aan" } else { "uit" }),
+            GameAction::UpdatePlayerName { player_id, new_name } => format!("update_player_name {} \"{}\"", player_id, new_name),
+            GameAction::AssignQuestions { questions } => format!(
+                "assign_questions {}",
+                questions.iter().map(|q| q.id.to_string()).collect::<Vec<_>>().join(",")
+            ),
+        };
+        Some(label)
+    }
+}
+
+impl GameState {
+    /// Nieuw spel zoals `start_new_game` het opzet: spelers aangemaakt en ronde 1 klaargezet.
+    pub fn start(game_id: String, created_at: String, player_names: Vec<String>, rules: RuleSet) -> Self {
+        let mut game = GameState::new(player_names, rules);
+        game.game_id = game_id;
+        game.created_at = created_at;
+
+        // Start eerste ronde (zonder inzetten verzamelen)
+        let round = Round::new(1, &game.rules);
+        game.start_new_round(round);
+        game
+    }
+
+    /// Pas een actie toe op het spel. `timestamp` is het moment van de actie, zodat
+    /// een replay van het event log exact dezelfde state oplevert.
+    /// `StartGame`, `Undo` en `Redo` worden door de aanroeper afgehandeld.
+    pub fn apply(&mut self, action: &GameAction, timestamp: &str) -> Result<(), GameError> {
+        match action {
+            GameAction::StartGame { .. } | GameAction::Undo | GameAction::Redo => {
+                return Err(GameError::invalid_input("Actie kan niet direct op een spel worden toegepast"));
+            }
+            GameAction::UpdateAnswer { player_id, question_number, image_path, drawing_path } => {
+                self.check_writing_allowed(player_id, *question_number)?;
+                let player = self.find_player_mut(player_id)?;
+                player.add_answer(*question_number, image_path.clone(), drawing_path.clone(), timestamp.to_string());
+            }
+            GameAction::ClearPlayerAnswers { player_id } => {
+                self.find_player_mut(player_id)?.clear_answers();
+            }
+            GameAction::ApproveAnswer { player_id, question_number, is_correct } => {
+                self.find_player_mut(player_id)?.approve_answer(*question_number, *is_correct);
+            }
+            GameAction::CollectInitialBets => {
+                if let Some(round) = &mut self.current_round {
+                    round.collect_initial_bets(&mut self.players)
+                        .map_err(|e| e.context("Fout bij verzamelen inzetten"))?;
+                }
+            }
+            GameAction::AddBetsToPot => {
+                if let Some(round) = &mut self.current_round {
+                    round.collect_bets_into_pot(&mut self.players);
+                }
+            }
+            GameAction::PlaceBet { player_id, amount } => {
+                let round = self.current_round.as_mut()
+                    .ok_or_else(GameError::no_active_round)?;
+                round.raise(&mut self.players, player_id, *amount, &self.rules)?;
+            }
+            GameAction::Check { player_id } => {
+                let round = self.current_round.as_mut()
+                    .ok_or_else(GameError::no_active_round)?;
+                round.check(&mut self.players, player_id)?;
+            }
+            GameAction::Call { player_id } => {
+                let round = self.current_round.as_mut()
+                    .ok_or_else(GameError::no_active_round)?;
+                round.call(&mut self.players, player_id)?;
+            }
+            GameAction::AllIn { player_id } => {
+                let round = self.current_round.as_mut()
+                    .ok_or_else(GameError::no_active_round)?;
+                round.all_in(&mut self.players, player_id)?;
+            }
+            GameAction::PlayerFold { player_id } => {
+                let round = self.current_round.as_mut()
+                    .ok_or_else(GameError::no_active_round)?;
+                round.fold(&mut self.players, player_id)?;
+            }
+            GameAction::AdvancePhase => {
+                if let Some(round) = &mut self.current_round {
+                    // Handmatig verder tijdens een inzetronde: wat er staat gaat alsnog de pot in
+                    if round.is_betting_phase() {
+                        round.collect_bets_into_pot(&mut self.players);
+                    }
+                    round.advance_phase();
+                    if round.is_betting_phase() {
+                        round.open_betting(&mut self.players);
+                    }
+                }
+            }
+            GameAction::CompleteRound { winner_id } => {
+                self.complete_round_with_winner(winner_id.as_deref(), timestamp)?;
+            }
+            GameAction::StartNextRound => {
+                if self.is_finished {
+                    return Err(GameError::new(ErrorCode::GameFinished, "Spel is afgelopen"));
+                }
+
+                let next_round_num = self.round_number + 1;
+                if next_round_num > self.rules.rounds {
+                    return Err(GameError::new(ErrorCode::GameFinished, "Maximum aantal rondes bereikt"));
+                }
+
+                // Ronde die nog loopt ("Schoon"): inzetten terug, anders verdwijnt de pot
+                if let Some(current) = &mut self.current_round {
+                    current.refund(&mut self.players);
+                }
+
+                // Start nieuwe ronde ZONDER inzetten te verzamelen
+                // Quizmaster moet handmatig op "Verzamel Inzetten" klikken
+                let round = Round::new(next_round_num, &self.rules);
+                self.start_new_round(round);
+            }
+            GameAction::SetRoundNumber { round_num } => {
+                let round_num = *round_num;
+                if !(1..=self.rules.rounds).contains(&round_num) {
+                    return Err(GameError::invalid_input(format!("Rondenummer moet tussen 1 en {} zijn", self.rules.rounds)));
+                }
+
+                self.round_number = round_num;
+
+                // Als er een actieve ronde is, update deze ook (incl. minimale inzet)
+                if let Some(ref mut current_round) = self.current_round {
+                    current_round.round_number = round_num;
+                    current_round.min_bet = self.rules.min_bet_for_round(round_num);
+                    current_round.assign_dealer(&self.players);
+                }
+                self.load_episode_questions();
+            }
+            GameAction::ResetGame => {
+                // Reset alle spelers naar startgeld en clear data
+                for player in &mut self.players {
+                    player.balance = self.rules.starting_balance;
+                    player.current_bet = 0;
+                    player.has_folded = false;
+                    player.is_active = true;
+                    player.clear_answers();
+                }
+
+                // Start nieuwe ronde 1
+                self.round_number = 0;
+                self.is_finished = false;
+                let round = Round::new(1, &self.rules);
+                self.start_new_round(round);
+            }
+            GameAction::TogglePlayerActive { player_id, is_active } => {
+                self.toggle_player_active(player_id, *is_active)?;
+            }
+            GameAction::RevealQuestion { question_number } => {
+                let round = self.current_round.as_mut()
+                    .ok_or_else(GameError::no_active_round)?;
+
+                // Toggle: als revealed, dan unrevealen, anders revealen
+                if let Some(pos) = round.revealed_questions.iter().position(|q| q == question_number) {
+                    round.revealed_questions.remove(pos);
+                } else {
+                    round.revealed_questions.push(*question_number);
+                }
+            }
+            GameAction::ToggleVideoMode => {
+                self.video_mode_active = !self.video_mode_active;
+
+                // Automatisch schrijven blokkeren tijdens video mode, inschakelen bij uitschakelen video
+                self.writing_enabled = !self.video_mode_active;
+            }
+            GameAction::ToggleWriting { enabled } => {
+                // Schrijven mag niet worden ingeschakeld tijdens video mode
+                if *enabled && self.video_mode_active {
+                    return Err(GameError::new(ErrorCode::IllegalPhase, "Schrijven kan niet worden ingeschakeld tijdens video mode"));
+                }
+
+                self.writing_enabled = *enabled;
+            }
+            GameAction::TimerExpired => {
+                self.writing_enabled = false;
+            }
+            GameAction::UpdatePlayerName { player_id, new_name } => {
+                let player = self.players.iter_mut()
+                    .find(|p| p.id == *player_id)
+                    .ok_or_else(|| GameError::player_not_found(player_id))?;
+                player.name = new_name.clone();
+            }
+            GameAction::LoadEpisode { episode } => {
+                episode.validate(&self.rules)?;
+                if episode.player_names.len() != self.players.len() {
+                    return Err(GameError::invalid_input(format!(
+                        "Draaiboek heeft {} spelers, het spel {}",
+                        episode.player_names.len(), self.players.len()
+                    )));
+                }
+
+                self.episode = Some(episode.clone());
+                self.load_episode_questions();
+            }
+            GameAction::AssignQuestions { questions } => {
+                let round = self.current_round.as_mut()
+                    .ok_or_else(GameError::no_active_round)?;
+                round.assign_questions(questions.clone())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn find_player_mut(&mut self, player_id: &str) -> Result<&mut super::Player, GameError> {
+        self.players.iter_mut()
+            .find(|p| p.id == player_id)
+            .ok_or_else(|| GameError::player_not_found(player_id))
+    }
+
+    /// Tablets mogen alleen schrijven zolang het schrijven open staat, de ronde nog in de
+    /// schrijffase is, de vraag bestaat en de speler nog meedoet. De app controleert dit ook
+    /// vóór het wegschrijven van de tekening, zodat een geweigerde tekening niets overschrijft.
+    pub fn check_writing_allowed(&self, player_id: &str, question_number: i32) -> Result<(), GameError> {
+        if !self.writing_enabled {
+            return Err(GameError::new(ErrorCode::WritingLocked, "Schrijven is gesloten"));
+        }
+        let round = self.current_round.as_ref()
+            .ok_or_else(GameError::no_active_round)?;
+        if round.phase != BettingPhase::Initial {
+            return Err(GameError::new(
+                ErrorCode::WritingLocked,
+                format!("Schrijven is gesloten, de ronde is al verder (fase: {:?})", round.phase),
+            ));
+        }
+        if !(1..=round.questions_count).contains(&question_number) {
+            return Err(GameError::invalid_input(format!(
+                "Vraag {} bestaat niet in deze ronde (1 t/m {})",
+                question_number, round.questions_count
+            )));
+        }
+
+        let player = self.players.iter()
+            .find(|p| p.id == player_id)
+            .ok_or_else(|| GameError::player_not_found(player_id))?;
+        if !player.is_active || player.has_folded {
+            return Err(GameError::new(ErrorCode::PlayerNotActive, format!("{} doet niet meer mee en kan niet schrijven", player.name)));
+        }
+        Ok(())
+    }
+
+    fn complete_round_with_winner(&mut self, winner_id: Option<&str>, timestamp: &str) -> Result<(), GameError> {
+        let Some(round) = &mut self.current_round else {
+            return Ok(());
+        };
+        // Versie van de antwoordbestanden: de cijfers van het moment van afsluiten
+        let version: String = timestamp.chars()
+            .take_while(|c| *c != '+' && *c != 'Z')
+            .filter(char::is_ascii_digit)
+            .collect();
+        for player in &mut self.players {
+            player.freeze_answers(&version);
+        }
+        // Inzetten die nog voor de spelers staan horen ook bij de pot
+        round.collect_bets_into_pot(&mut self.players);
+
+        let result = round.settle(&self.players, winner_id, self.rules.tie_rule)?;
+        self.complete_round(result);
+        Ok(())
+    }
+
+    fn toggle_player_active(&mut self, player_id: &str, is_active: bool) -> Result<(), GameError> {
+        // Als we na de eliminatieronde een speler willen elimineren (is_active = false), controleer eerst of er al een geëlimineerde speler is
+        let after_elimination = self.rules.elimination_after_round.is_some_and(|round| self.round_number > round);
+        if !is_active && after_elimination {
+            // Tel hoeveel spelers al geëlimineerd zijn (handmatig, niet door balance)
+            let manually_eliminated_count = self.players.iter()
+                .filter(|p| !p.is_active && p.balance > 0) // Geëlimineerd maar nog geld
+                .count();
+
+            // Als er al een speler handmatig is geëlimineerd, activeer die eerst
+            if manually_eliminated_count >= 1 {
+                for player in self.players.iter_mut() {
+                    if !player.is_active && player.balance > 0 && player.id != player_id {
+                        player.is_active = true;
+                        break;
+                    }
+                }
+            }
+        }
+
+        let player = self.players.iter_mut()
+            .find(|p| p.id == player_id)
+            .ok_or_else(|| GameError::player_not_found(player_id))?;
+        player.is_active = is_active;
+        Ok(())
+    }
+}
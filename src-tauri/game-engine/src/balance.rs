@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use super::{GameAction, GameError, GameState};
+
+/// Waarom het saldo van een speler veranderde
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BalanceReason {
+    Start,      // Startgeld bij een nieuw spel
+    Ante,       // Inleg aan het begin van de ronde
+    Bet,        // Inzet, meegaan of all-in
+    PotWin,     // (Deel van) de pot gewonnen
+    Correction, // Handmatig door de quizmaster: undo, redo of een andere ingreep
+    Reset,      // Spel opnieuw begonnen, iedereen terug naar het startgeld
+}
+
+impl BalanceReason {
+    fn for_action(action: &GameAction) -> Self {
+        match action {
+            GameAction::StartGame { .. } => BalanceReason::Start,
+            GameAction::CollectInitialBets => BalanceReason::Ante,
+            GameAction::PlaceBet { .. } | GameAction::Call { .. } | GameAction::AllIn { .. } => BalanceReason::Bet,
+            GameAction::CompleteRound { .. } => BalanceReason::PotWin,
+            GameAction::ResetGame => BalanceReason::Reset,
+            _ => BalanceReason::Correction,
+        }
+    }
+}
+
+/// Eén wijziging van het saldo van één speler
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BalanceChange {
+    pub player_id: String,
+    pub timestamp: String,
+    pub round_number: i32,
+    pub reason: BalanceReason,
+    pub amount: i32,  // Positief bij winst, negatief bij inzetten
+    pub balance: i32, // Saldo na deze wijziging
+}
+
+/// Saldoverloop van één speler, voor grafieken en om discussies na te lopen
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PlayerTimeline {
+    pub player_id: String,
+    pub player_name: String,
+    pub changes: Vec<BalanceChange>,
+}
+
+impl GameState {
+    /// Leg de saldowijzigingen van een uitgevoerde actie vast in `balance_timeline`.
+    /// De tijdlijn loopt altijd door vanaf `before`, ook bij undo en redo: die zetten
+    /// de saldo's terug maar verschijnen zelf als correctie, zodat niets ongemerkt verdwijnt.
+    pub fn record_balance_changes(&mut self, before: Option<&GameState>, action: &GameAction, timestamp: &str) {
+        let mut timeline = before.map(|b| b.balance_timeline.clone()).unwrap_or_default();
+        let reason = BalanceReason::for_action(action);
+        for player in &self.players {
+            let previous = match before {
+                Some(before) => before.players.iter().find(|p| p.id == player.id).map(|p| p.balance).unwrap_or(0),
+                None => 0,
+            };
+            if player.balance != previous {
+                timeline.push(BalanceChange {
+                    player_id: player.id.clone(),
+                    timestamp: timestamp.to_string(),
+                    round_number: self.round_number,
+                    reason,
+                    amount: player.balance - previous,
+                    balance: player.balance,
+                });
+            }
+        }
+        self.balance_timeline = timeline;
+    }
+
+    /// Tijdlijn per speler in tafelvolgorde; met `player_id` alleen die speler
+    pub fn balance_timelines(&self, player_id: Option<&str>) -> Result<Vec<PlayerTimeline>, GameError> {
+        if let Some(id) = player_id.filter(|id| !self.players.iter().any(|p| p.id == *id)) {
+            return Err(GameError::player_not_found(id));
+        }
+        Ok(self.players.iter()
+            .filter(|p| player_id.is_none_or(|id| p.id == id))
+            .map(|p| PlayerTimeline {
+                player_id: p.id.clone(),
+                player_name: p.name.clone(),
+                changes: self.balance_timeline.iter()
+                    .filter(|c| c.player_id == p.id)
+                    .cloned()
+                    .collect(),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+use super::GameError;
+
+/// Bovengrens voor één antwoord, zodat een haperende tablet de state niet kan opblazen
+pub const MAX_DRAWING_POINTS: usize = 20_000;
+
+/// Eén punt van de pen, in canvas coördinaten van de tablet
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct StrokePoint {
+    pub x: f32,
+    pub y: f32,
+    pub pressure: f32, // 0.0 - 1.0; muis en vinger zonder druk sturen 0.5
+    pub t: u32,        // Milliseconden sinds het eerste punt van de tekening
+}
+
+/// Eén pennenstreek: van neerzetten tot optillen
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Stroke {
+    pub points: Vec<StrokePoint>,
+}
+
+/// Handgeschreven antwoord als pennenstreken, los van de schermresolutie.
+/// `width` en `height` zijn de afmetingen van het canvas waarop getekend is; een renderer
+/// schaalt vanuit dat vlak naar elk formaat en kan de streken in volgorde afspelen.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Drawing {
+    pub width: f32,
+    pub height: f32,
+    pub strokes: Vec<Stroke>,
+}
+
+impl Drawing {
+    pub fn is_empty(&self) -> bool {
+        self.strokes.iter().all(|s| s.points.is_empty())
+    }
+
+    pub fn point_count(&self) -> usize {
+        self.strokes.iter().map(|s| s.points.len()).sum()
+    }
+
+    /// Tijd van het eerste tot het laatste punt
+    pub fn duration_ms(&self) -> u32 {
+        self.strokes.iter()
+            .flat_map(|s| s.points.last())
+            .map(|p| p.t)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Een lege tekening (gewist canvas) is altijd geldig
+    pub fn validate(&self) -> Result<(), GameError> {
+        if self.is_empty() {
+            return Ok(());
+        }
+        if !(self.width.is_finite() && self.height.is_finite() && self.width > 0.0 && self.height > 0.0) {
+            return Err(GameError::invalid_input("Tekening heeft geen geldige afmetingen"));
+        }
+        if self.point_count() > MAX_DRAWING_POINTS {
+            return Err(GameError::invalid_input(format!(
+                "Tekening heeft te veel punten ({}, maximaal {})",
+                self.point_count(), MAX_DRAWING_POINTS
+            )));
+        }
+        let points = self.strokes.iter().flat_map(|s| &s.points);
+        if points.clone().any(|p| !(p.x.is_finite() && p.y.is_finite() && p.pressure.is_finite())) {
+            return Err(GameError::invalid_input("Tekening bevat ongeldige punten"));
+        }
+        if points.clone().any(|p| !(0.0..=1.0).contains(&p.pressure)) {
+            return Err(GameError::invalid_input("Druk van de pen moet tussen 0 en 1 liggen"));
+        }
+        Ok(())
+    }
+
+    /// Afspeeltijd per punt (zelfde volgorde als de streken), voor een animatie van het schrijven.
+    /// Nadenken tussen twee streken wordt ingekort tot `max_pause_ms`, zodat een replay
+    /// niet stilstaat terwijl de speler twijfelde.
+    pub fn replay_times(&self, max_pause_ms: u32) -> Vec<Vec<u32>> {
+        let mut skipped = 0;
+        let mut last = 0;
+        self.strokes.iter()
+            .map(|stroke| {
+                stroke.points.iter()
+                    .enumerate()
+                    .map(|(index, point)| {
+                        let t = point.t.max(last); // Tijden lopen nooit terug
+                        // Alleen tussen streken inkorten; binnen een streek is de snelheid het handschrift
+                        if index == 0 && t - last > max_pause_ms {
+                            skipped += t - last - max_pause_ms;
+                        }
+                        last = t;
+                        t - skipped
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stroke(times: &[u32]) -> Stroke {
+        Stroke { points: times.iter().map(|&t| StrokePoint { x: 0.0, y: 0.0, pressure: 0.5, t }).collect() }
+    }
+
+    #[test]
+    fn replay_shortens_pauses_between_strokes_only() {
+        let drawing = Drawing {
+            width: 600.0,
+            height: 150.0,
+            strokes: vec![stroke(&[0, 100, 2_100]), stroke(&[10_000, 10_050]), stroke(&[10_200])],
+        };
+        // Pauze binnen de eerste streek blijft, 7,9 s nadenken wordt 400 ms, 150 ms blijft staan
+        assert_eq!(drawing.replay_times(400), vec![vec![0, 100, 2_100], vec![2_500, 2_550], vec![2_700]]);
+        assert_eq!(drawing.duration_ms(), 10_200);
+    }
+}
@@ -0,0 +1,102 @@
+use super::{ErrorCode, GameAction, GameError, GameState, UndoHistory};
+
+/// Resultaat van `Engine::apply`
+#[derive(Debug)]
+pub struct Applied {
+    /// State van vóór de actie, voor het event log; `None` bij een nieuw spel
+    pub before: Option<GameState>,
+    /// Bij undo en redo: het label van de teruggedraaide of opnieuw uitgevoerde actie
+    pub label: Option<String>,
+}
+
+/// Het actieve spel plus zijn undo historie. Tauri commands, de HTTP API en de replay
+/// van het event log voeren alle acties via `apply` uit, zodat de regels maar op één plek staan.
+#[derive(Debug, Default)]
+pub struct Engine {
+    game: Option<GameState>,
+    history: UndoHistory,
+}
+
+impl Engine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Verder met een bestaand spel (hervat na een crash), zonder undo historie
+    pub fn with_game(game: GameState) -> Self {
+        Self {
+            game: Some(game),
+            history: UndoHistory::default(),
+        }
+    }
+
+    pub fn game(&self) -> Option<&GameState> {
+        self.game.as_ref()
+    }
+
+    /// Directe toegang voor wijzigingen buiten het spelverloop om (timer): geen undo stap
+    pub fn game_mut(&mut self) -> Option<&mut GameState> {
+        self.game.as_mut()
+    }
+
+    pub fn history(&self) -> &UndoHistory {
+        &self.history
+    }
+
+    /// Voer een actie uit. `StartGame` begint een nieuw spel met een lege historie,
+    /// `Undo`/`Redo` stappen door de historie en alle andere acties gaan naar `GameState::apply`.
+    /// Mislukt een actie, of klopt het geld daarna niet meer (`GameState::check_money`),
+    /// dan blijven de oude state en historie staan.
+    ///
+    /// Elke saldowijziging komt daarna in `GameState::balance_timeline`.
+    pub fn apply(&mut self, action: &GameAction, timestamp: &str) -> Result<Applied, GameError> {
+        let applied = self.apply_action(action, timestamp)?;
+        if let Some(game) = self.game.as_mut() {
+            game.record_balance_changes(applied.before.as_ref(), action, timestamp);
+        }
+        Ok(applied)
+    }
+
+    fn apply_action(&mut self, action: &GameAction, timestamp: &str) -> Result<Applied, GameError> {
+        match action {
+            GameAction::StartGame { game_id, created_at, player_names, rules } => {
+                rules.validate()?;
+                self.game = Some(GameState::start(game_id.clone(), created_at.clone(), player_names.clone(), rules.clone()));
+                // Undo stappen van een vorig spel zijn niet meer relevant
+                self.history.clear();
+                Ok(Applied { before: None, label: None })
+            }
+            GameAction::Undo | GameAction::Redo => {
+                let game = self.game.as_mut().ok_or_else(GameError::no_active_game)?;
+                let history = self.history.clone();
+                let (label, restored) = if *action == GameAction::Undo {
+                    self.history.undo(game.clone())
+                        .ok_or_else(|| GameError::new(ErrorCode::NothingToUndo, "Geen vorige state beschikbaar om te herstellen"))?
+                } else {
+                    self.history.redo(game.clone())
+                        .ok_or_else(|| GameError::new(ErrorCode::NothingToUndo, "Geen actie beschikbaar om opnieuw uit te voeren"))?
+                };
+                // Ook een herstelde state moet kloppen; zo niet, dan blijft alles zoals het was
+                if let Err(e) = restored.check_money() {
+                    self.history = history;
+                    return Err(e);
+                }
+
+                Ok(Applied { before: Some(std::mem::replace(game, restored)), label: Some(label) })
+            }
+            action => {
+                let game = self.game.as_mut().ok_or_else(GameError::no_active_game)?;
+                let before = game.clone();
+                if let Err(e) = game.apply(action, timestamp).and_then(|_| game.check_money()) {
+                    *game = before;
+                    return Err(e);
+                }
+
+                if let Some(label) = action.undo_label() {
+                    self.history.record(label, timestamp, before.clone());
+                }
+                Ok(Applied { before: Some(before), label: None })
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+use super::{GameError, GameState, Question, RuleSet};
+
+/// Vraag in het draaiboek, met optionele regie-aantekening voor de quizmaster
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EpisodeQuestion {
+    #[serde(flatten)]
+    pub question: Question,
+    #[serde(default)]
+    pub notes: Option<String>, // Bijv. "Na deze vraag naar camera 2"
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EpisodeRound {
+    pub round_number: i32,
+    pub questions: Vec<EpisodeQuestion>,
+}
+
+/// Draaiboek van een aflevering: spelers en per ronde de vragen, vooraf voorbereid door de redactie
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Episode {
+    pub id: String, // Ook de bestandsnaam in de episodes map
+    pub title: String,
+    #[serde(default)]
+    pub recording_date: Option<String>,
+    pub player_names: Vec<String>,
+    #[serde(default)]
+    pub rule_set: Option<String>, // Naam van het regelbestand, leeg = standaardregels
+    pub rounds: Vec<EpisodeRound>,
+}
+
+impl Episode {
+    /// Het id wordt een bestandsnaam in de episodes map en komt ook uit HTTP requests:
+    /// geen lege id's en geen `/`, `\` of `.`, zodat het nooit buiten die map wijst
+    pub fn check_id(id: &str) -> Result<(), GameError> {
+        if id.trim().is_empty() || id.contains(['/', '\\', '.']) {
+            return Err(GameError::invalid_input(format!("Ongeldig afleverings-id: '{}'", id)));
+        }
+        Ok(())
+    }
+
+    /// Controleer of het draaiboek compleet is: elke ronde precies één keer en per ronde genoeg vragen
+    pub fn validate(&self, rules: &RuleSet) -> Result<(), GameError> {
+        Self::check_id(&self.id)?;
+        if self.player_names.is_empty() {
+            return Err(GameError::invalid_input("Draaiboek bevat geen spelers"));
+        }
+
+        for round_number in 1..=rules.rounds {
+            let rounds: Vec<&EpisodeRound> = self.rounds.iter()
+                .filter(|r| r.round_number == round_number)
+                .collect();
+            let round = match rounds.as_slice() {
+                [round] => round,
+                [] => return Err(GameError::invalid_input(format!("Ronde {} ontbreekt in het draaiboek", round_number))),
+                _ => return Err(GameError::invalid_input(format!("Ronde {} staat meerdere keren in het draaiboek", round_number))),
+            };
+
+            let expected = rules.questions_per_round as usize;
+            if round.questions.len() != expected {
+                return Err(GameError::invalid_input(format!(
+                    "Ronde {} heeft {} vragen nodig, draaiboek heeft er {}",
+                    round_number, expected, round.questions.len()
+                )));
+            }
+        }
+
+        if let Some(round) = self.rounds.iter().find(|r| !(1..=rules.rounds).contains(&r.round_number)) {
+            return Err(GameError::invalid_input(format!("Onbekende ronde {} in het draaiboek", round.round_number)));
+        }
+        Ok(())
+    }
+
+    pub fn round(&self, round_number: i32) -> Option<&EpisodeRound> {
+        self.rounds.iter().find(|r| r.round_number == round_number)
+    }
+}
+
+impl GameState {
+    /// Zet de vragen en aantekeningen uit het draaiboek klaar voor de huidige ronde
+    pub fn load_episode_questions(&mut self) {
+        let (Some(episode), Some(round)) = (&self.episode, &mut self.current_round) else {
+            return;
+        };
+        let Some(script) = episode.round(round.round_number) else {
+            return;
+        };
+
+        round.questions = script.questions.iter().map(|q| q.question.clone()).collect();
+        round.question_notes = script.questions.iter().map(|q| q.notes.clone()).collect();
+    }
+}
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Vaste foutcodes voor frontend en scripts. De namen zijn onderdeel van de API:
+/// niet hernoemen, alleen toevoegen.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ErrorCode {
+    NoActiveGame,
+    NoActiveRound,
+    GameFinished,
+    PlayerNotFound,
+    PlayerNotActive,
+    NotPlayersTurn,
+    IllegalPhase,
+    InsufficientBalance,
+    InvalidBet,
+    TieUnresolved,       // Gelijke stand, de quizmaster moet een winnaar kiezen
+    InvalidInput,        // Ongeldige regelset, draaiboek, rondenummer, ...
+    NotFound,            // Vraag, draaiboek of regelset bestaat niet
+    NothingToUndo,
+    StorageError,        // Lezen of schrijven op schijf / in de database mislukt
+    SerialPortError,
+    Internal,
+    MoneyMismatch,       // Saldo's, inzetten en pot tellen niet meer op tot het startgeld
+    WritingLocked,       // Tablet probeert te schrijven terwijl dat niet (meer) mag
+}
+
+/// Fout zoals de Tauri commands en de HTTP API hem teruggeven:
+/// `{ "code": "PlayerNotFound", "message": "Speler niet gevonden: player_3" }`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GameError {
+    pub code: ErrorCode,
+    pub message: String, // Nederlandse melding voor de quizmaster
+}
+
+impl GameError {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self { code, message: message.into() }
+    }
+
+    pub fn no_active_game() -> Self {
+        Self::new(ErrorCode::NoActiveGame, "Geen actief spel")
+    }
+
+    pub fn no_active_round() -> Self {
+        Self::new(ErrorCode::NoActiveRound, "Geen actieve ronde")
+    }
+
+    pub fn player_not_found(player_id: &str) -> Self {
+        Self::new(ErrorCode::PlayerNotFound, format!("Speler niet gevonden: {}", player_id))
+    }
+
+    pub fn invalid_input(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::InvalidInput, message)
+    }
+
+    pub fn storage(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::StorageError, message)
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Internal, message)
+    }
+
+    /// Zelfde code, met context voor de melding (bijv. het eventnummer bij replay)
+    pub fn context(self, context: impl fmt::Display) -> Self {
+        Self::new(self.code, format!("{}: {}", context, self.message))
+    }
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for GameError {}
+
+/// Een vergiftigde lock betekent dat een andere thread halverwege een wijziging crashte
+impl<T> From<std::sync::PoisonError<T>> for GameError {
+    fn from(e: std::sync::PoisonError<T>) -> Self {
+        Self::internal(format!("Interne fout: {}", e))
+    }
+}
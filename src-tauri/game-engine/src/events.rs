@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+use super::{Engine, GameAction, GameError, GameState};
+
+/// Saldo van één speler op een moment, voor de voor/na-vastlegging in het event log
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PlayerBalance {
+    pub player_id: String,
+    pub balance: i32,
+    pub current_bet: i32,
+}
+
+/// Eén uitgevoerde actie zoals vastgelegd in het append-only event log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameEvent {
+    pub sequence: u64,
+    pub timestamp: String,
+    pub actor: String, // "quizmaster", "tablet:player_0", ...
+    pub action: GameAction,
+    pub balances_before: Vec<PlayerBalance>,
+    pub balances_after: Vec<PlayerBalance>,
+    pub pot_before: i32,
+    pub pot_after: i32,
+}
+
+impl GameEvent {
+    pub fn new(
+        sequence: u64,
+        timestamp: String,
+        actor: String,
+        action: GameAction,
+        before: Option<&GameState>,
+        after: &GameState,
+    ) -> Self {
+        Self {
+            sequence,
+            timestamp,
+            actor,
+            action,
+            balances_before: before.map(balances_of).unwrap_or_default(),
+            balances_after: balances_of(after),
+            pot_before: before.map(pot_of).unwrap_or(0),
+            pot_after: pot_of(after),
+        }
+    }
+}
+
+fn balances_of(game: &GameState) -> Vec<PlayerBalance> {
+    game.players.iter()
+        .map(|p| PlayerBalance {
+            player_id: p.id.clone(),
+            balance: p.balance,
+            current_bet: p.current_bet,
+        })
+        .collect()
+}
+
+fn pot_of(game: &GameState) -> i32 {
+    game.current_round.as_ref().map(|r| r.pot).unwrap_or(0)
+}
+
+/// Bouw de `GameState` opnieuw op door alle events in volgorde af te spelen via een eigen
+/// `Engine`, dus met precies dezelfde regels (en undo/redo) als live.
+/// Het eerste event moet een `StartGame` zijn.
+pub fn replay(events: &[GameEvent]) -> Result<GameState, GameError> {
+    let mut engine = Engine::new();
+    for event in events {
+        engine.apply(&event.action, &event.timestamp)
+            .map_err(|e| e.context(format!("Event {}", event.sequence)))?;
+    }
+
+    engine.game()
+        .cloned()
+        .ok_or_else(|| GameError::invalid_input("Event log bevat geen StartGame"))
+}
@@ -0,0 +1,103 @@
+use serde::Serialize;
+use std::collections::VecDeque;
+use super::GameState;
+
+/// Maximaal aantal stappen dat de quizmaster terug kan
+pub const MAX_UNDO_ENTRIES: usize = 50;
+
+#[derive(Debug, Clone)]
+struct HistoryEntry {
+    label: String,
+    timestamp: String,
+    state: GameState,
+}
+
+/// Wat de frontend te zien krijgt: welke actie ongedaan gemaakt (of opnieuw gedaan) wordt
+#[derive(Debug, Clone, Serialize)]
+pub struct HistoryItem {
+    pub label: String,
+    pub timestamp: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HistoryOverview {
+    pub undo: Vec<HistoryItem>, // Meest recente eerst
+    pub redo: Vec<HistoryItem>, // Eerstvolgende eerst
+}
+
+/// Begrensde undo/redo stack. Per actie bewaren we de `GameState` van vóór die actie,
+/// met een label zoals "place_bet player_1 €30".
+#[derive(Debug, Clone)]
+pub struct UndoHistory {
+    undo: VecDeque<HistoryEntry>,
+    redo: Vec<HistoryEntry>,
+    limit: usize,
+}
+
+impl Default for UndoHistory {
+    fn default() -> Self {
+        Self::new(MAX_UNDO_ENTRIES)
+    }
+}
+
+impl UndoHistory {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            limit,
+        }
+    }
+
+    /// Leg de state van vóór een actie vast. Een nieuwe actie maakt redo ongeldig.
+    pub fn record(&mut self, label: String, timestamp: &str, before: GameState) {
+        self.undo.push_back(HistoryEntry {
+            label,
+            timestamp: timestamp.to_string(),
+            state: before,
+        });
+        if self.undo.len() > self.limit {
+            self.undo.pop_front();
+        }
+        self.redo.clear();
+    }
+
+    /// Draai de laatste actie terug. Geeft het label en de te herstellen state terug;
+    /// `current` gaat naar de redo stack.
+    pub fn undo(&mut self, current: GameState) -> Option<(String, GameState)> {
+        let entry = self.undo.pop_back()?;
+        self.redo.push(HistoryEntry {
+            label: entry.label.clone(),
+            timestamp: entry.timestamp,
+            state: current,
+        });
+        Some((entry.label, entry.state))
+    }
+
+    /// Voer de laatst teruggedraaide actie opnieuw uit.
+    pub fn redo(&mut self, current: GameState) -> Option<(String, GameState)> {
+        let entry = self.redo.pop()?;
+        self.undo.push_back(HistoryEntry {
+            label: entry.label.clone(),
+            timestamp: entry.timestamp,
+            state: current,
+        });
+        Some((entry.label, entry.state))
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    pub fn overview(&self) -> HistoryOverview {
+        let to_item = |e: &HistoryEntry| HistoryItem {
+            label: e.label.clone(),
+            timestamp: e.timestamp.clone(),
+        };
+        HistoryOverview {
+            undo: self.undo.iter().rev().map(to_item).collect(),
+            redo: self.redo.iter().rev().map(to_item).collect(),
+        }
+    }
+}
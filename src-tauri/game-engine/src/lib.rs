@@ -0,0 +1,35 @@
+//! Spelregels van Met het Mes op Tafel: spelers, rondes, inzetten en potten.
+//! Geen Tauri, geen HTTP: alles gaat via `Engine::apply` met een `GameAction`,
+//! zodat een volledig spel zonder webview getest en afgespeeld kan worden.
+
+pub mod action;
+pub mod balance;
+pub mod drawing;
+pub mod engine;
+pub mod episode;
+pub mod error;
+pub mod events;
+pub mod history;
+pub mod player;
+pub mod question;
+pub mod round;
+pub mod rules;
+pub mod state;
+pub mod timer;
+#[cfg(feature = "test-support")]
+pub mod test_support;
+
+pub use action::GameAction;
+pub use balance::{BalanceChange, BalanceReason, PlayerTimeline};
+pub use drawing::{Drawing, Stroke, StrokePoint};
+pub use engine::{Applied, Engine};
+pub use episode::{Episode, EpisodeQuestion, EpisodeRound};
+pub use error::{ErrorCode, GameError};
+pub use events::{GameEvent, PlayerBalance};
+pub use history::{HistoryItem, HistoryOverview, UndoHistory};
+pub use player::{Player, Answer};
+pub use question::Question;
+pub use round::{Round, BettingPhase, PotResult, PotShare, RoundAnswers, RoundResult, SidePot};
+pub use rules::{RuleSet, TieRule};
+pub use state::GameState;
+pub use timer::{TimerClock, TimerMode, TimerPresets};
@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+use super::{ErrorCode, GameError};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Answer {
+    pub question_number: i32,
+    pub image_path: String, // Pad van de tekening in de antwoordenopslag van de app, niet de afbeelding zelf
+    #[serde(default)]
+    pub drawing_path: String, // Pennenstreken (JSON) naast de afbeelding; leeg bij antwoorden zonder streken
+    pub is_correct: Option<bool>, // None = nog niet beoordeeld
+    pub timestamp: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Player {
+    pub id: String,
+    pub name: String,
+    pub balance: i32,
+    pub current_bet: i32,
+    pub answers: Vec<Answer>,
+    pub is_active: bool,
+    pub has_folded: bool,
+    #[serde(default)]
+    pub is_all_in: bool, // Heeft deze ronde alles ingezet wat er nog was
+}
+
+/// `-<versie>` voor de extensie; een leeg pad (antwoord zonder streken) blijft leeg
+fn frozen_path(path: &str, version: &str) -> String {
+    match path.rsplit_once('.') {
+        _ if path.is_empty() => String::new(),
+        Some((stem, extension)) if !extension.contains('/') => format!("{}-{}.{}", stem, version, extension),
+        _ => format!("{}-{}", path, version),
+    }
+}
+
+impl Player {
+    pub fn new(id: String, name: String, starting_balance: i32) -> Self {
+        Self {
+            id,
+            name,
+            balance: starting_balance,
+            current_bet: 0,
+            answers: Vec::new(),
+            is_active: true,
+            has_folded: false,
+            is_all_in: false,
+        }
+    }
+
+    pub fn place_bet(&mut self, amount: i32) -> Result<(), GameError> {
+        if amount > self.balance {
+            return Err(GameError::new(ErrorCode::InsufficientBalance, "Onvoldoende saldo"));
+        }
+        
+        self.balance -= amount;
+        self.current_bet += amount;
+        Ok(())
+    }
+
+    /// Zet `amount` in, of alles wat er nog is als het saldo niet toereikend is (all-in).
+    /// Geeft het werkelijk ingezette bedrag terug.
+    pub fn bet_up_to(&mut self, amount: i32) -> i32 {
+        let paid = amount.min(self.balance).max(0);
+        self.balance -= paid;
+        self.current_bet += paid;
+        if paid > 0 && self.balance == 0 {
+            self.is_all_in = true;
+        }
+        paid
+    }
+
+    /// Passen geldt alleen voor deze ronde; `is_active` blijft staan zodat de speler
+    /// de volgende ronde gewoon weer meedoet (en aan de beurt komt)
+    pub fn fold(&mut self) {
+        self.has_folded = true;
+    }
+
+    pub fn win_pot(&mut self, pot_amount: i32) {
+        self.balance += pot_amount;
+    }
+
+    pub fn add_answer(&mut self, question_number: i32, image_path: String, drawing_path: String, timestamp: String) {
+        // Update bestaand antwoord of voeg nieuw toe
+        if let Some(existing) = self.answers.iter_mut().find(|a| a.question_number == question_number) {
+            existing.image_path = image_path;
+            existing.drawing_path = drawing_path;
+            existing.timestamp = timestamp;
+        } else {
+            let answer = Answer {
+                question_number,
+                image_path,
+                drawing_path,
+                is_correct: None,
+                timestamp,
+            };
+            self.answers.push(answer);
+        }
+    }
+
+    /// Antwoorden vastzetten bij het afsluiten van de ronde. Tijdens het schrijven overschrijft
+    /// de tablet steeds hetzelfde bestand; de afgesloten ronde verwijst naar een eigen versie
+    /// (`q1.png` wordt `q1-<versie>.png`), zodat een ronde met hetzelfde nummer na `ResetGame`
+    /// of `SetRoundNumber` die niet overschrijft. Het kopiëren van de bestanden doet de app.
+    pub fn freeze_answers(&mut self, version: &str) {
+        for answer in &mut self.answers {
+            answer.image_path = frozen_path(&answer.image_path, version);
+            answer.drawing_path = frozen_path(&answer.drawing_path, version);
+        }
+    }
+
+    pub fn clear_answers(&mut self) {
+        self.answers.clear();
+    }
+
+    pub fn approve_answer(&mut self, question_number: i32, is_correct: bool) {
+        if let Some(answer) = self.answers.iter_mut().find(|a| a.question_number == question_number) {
+            answer.is_correct = Some(is_correct);
+        }
+    }
+
+    pub fn count_correct_answers(&self) -> i32 {
+        self.answers.iter().filter(|a| a.is_correct == Some(true)).count() as i32
+    }
+
+    pub fn reset_for_round(&mut self) {
+        self.current_bet = 0;
+        self.answers.clear();
+        self.has_folded = false;
+        self.is_all_in = false;
+        // NIET is_active resetten - die blijft behouden tussen rondes!
+        // is_active wordt alleen false bij handmatige eliminatie of na ronde 4
+    }
+
+    pub fn is_eliminated(&self) -> bool {
+        self.balance <= 0
+    }
+
+    /// Doet deze speler nog mee aan de inzetronde (en kan hij dus aan de beurt komen)?
+    /// Wie all-in is doet nog mee om de pot, maar kan niet meer inzetten.
+    pub fn can_bet(&self) -> bool {
+        self.is_active && !self.has_folded && !self.is_all_in && !self.is_eliminated()
+    }
+}
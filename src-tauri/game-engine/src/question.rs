@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// Een vraag uit de vragenbank, zoals die aan een ronde gekoppeld wordt.
+/// De hele vraag wordt in de ronde opgeslagen (niet alleen het id), zodat snapshots
+/// en het event log ook zonder de database leesbaar blijven.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Question {
+    #[serde(default)]
+    pub id: i64, // 0 = niet (meer) in de vragenbank, bijv. direct in een draaiboek geschreven
+    pub text: String,
+    pub answer: String,
+    pub accepted_variants: Vec<String>, // Andere schrijfwijzen die ook goed zijn
+    pub category: Option<String>,
+    pub difficulty: Option<i32>, // 1 (makkelijk) t/m 5 (moeilijk)
+    pub source: Option<String>,
+}
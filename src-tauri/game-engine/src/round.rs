@@ -0,0 +1,550 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use super::{Answer, ErrorCode, GameError, Player, Question, RuleSet, TieRule};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum BettingPhase {
+    Initial,              // Antwoorden schrijven
+    CollectingBets,       // Verzamel 3x min inzet
+    FirstBetting,         // 1e inzetronde (dealer eerst)
+    RevealingAnswers,     // Antwoorden 1-voor-1 tonen
+    SecondBetting,        // 2e inzetronde (dealer eerst)
+    DetermineWinner,      // Winnaar bepalen (handmatig)
+    Completed,            // Ronde voltooid
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Round {
+    pub round_number: i32,
+    pub questions_count: i32, // Aantal vragen per ronde
+    pub pot: i32,
+    pub min_bet: i32,
+    pub phase: BettingPhase,
+    pub current_player_index: usize,
+    pub dealer_index: usize,
+    pub revealed_questions: Vec<i32>, // Welke vraagnummers zijn ge-revealed (1-4)
+    #[serde(default)]
+    pub questions: Vec<Question>, // Vragen uit de vragenbank, index 0 = vraag 1
+    #[serde(default)]
+    pub question_notes: Vec<Option<String>>, // Aantekeningen uit het draaiboek, zelfde volgorde
+    #[serde(default)]
+    pub highest_bet: i32, // Hoogste inzet in de lopende inzetronde; wie minder heeft staan moet bijleggen
+    #[serde(default)]
+    pub acted: Vec<String>, // Spelers die sinds de laatste verhoging aan de beurt zijn geweest
+    #[serde(default)]
+    pub contributions: BTreeMap<String, i32>, // Totaal per speler in de pot deze ronde, voor de zijpotten
+}
+
+/// Deel van de pot en wie er aanspraak op maakt. Spelers die all-in gaan kunnen alleen
+/// winnen wat iedereen tot hun eigen inzet heeft ingelegd; de rest komt in een zijpot.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SidePot {
+    pub amount: i32,
+    pub eligible: Vec<String>,
+}
+
+/// Deel van een pot dat naar één speler gaat
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PotShare {
+    pub player_id: String,
+    pub player_name: String,
+    pub amount: i32,
+}
+
+/// Uitbetaling van één (zij)pot; bij een gedeelde pot meerdere aandelen
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PotResult {
+    pub amount: i32,
+    pub shares: Vec<PotShare>,
+}
+
+/// Wat één speler in een afgeronde ronde heeft opgeschreven, zoals het bij de uitslag stond
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoundAnswers {
+    pub player_id: String,
+    pub player_name: String,
+    pub has_folded: bool,
+    pub answers: Vec<Answer>, // Tekening, oordeel en tijdstip per vraag
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoundResult {
+    #[serde(default)]
+    pub round_number: i32,
+    pub winner_id: String,
+    pub winner_name: String,
+    pub pot_amount: i32,
+    #[serde(default)]
+    pub min_bet: i32, // Minimale inzet zoals die aan het eind van de ronde stond
+    pub correct_answers: i32,
+    pub player_scores: Vec<(String, i32)>,
+    #[serde(default)]
+    pub pots: Vec<PotResult>, // Hoofdpot eerst, dan de zijpotten
+    #[serde(default)]
+    pub winners: Vec<PotShare>, // Totaal per winnaar over alle potten; meer dan één bij een gedeelde pot
+    #[serde(default)]
+    pub questions: Vec<Question>, // Gestelde vragen, index 0 = vraag 1
+    #[serde(default)]
+    pub answers: Vec<RoundAnswers>, // Antwoorden van alle spelers die deze ronde aan tafel zaten
+    #[serde(default)]
+    pub balances: Vec<(String, i32)>, // Saldo per speler na het uitbetalen van de potten
+}
+
+impl Round {
+    pub fn new(round_number: i32, rules: &RuleSet) -> Self {
+        Self {
+            round_number,
+            questions_count: rules.questions_per_round,
+            pot: 0,
+            min_bet: rules.min_bet_for_round(round_number),
+            phase: BettingPhase::Initial,
+            current_player_index: 0,
+            dealer_index: 0,
+            revealed_questions: Vec::new(), // Start met geen revealed questions
+            questions: Vec::new(),
+            question_notes: Vec::new(),
+            highest_bet: 0,
+            acted: Vec::new(),
+            contributions: BTreeMap::new(),
+        }
+    }
+
+    pub fn collect_initial_bets(&mut self, players: &mut [Player]) -> Result<(), GameError> {
+        for player in players.iter_mut() {
+            if player.is_active && !player.is_eliminated() {
+                // Wie de inleg niet kan betalen gaat all-in met wat er nog is
+                player.bet_up_to(self.min_bet);
+                // Pot wordt NIET verhoogd hier - dat gebeurt pas bij "Bij Pot"
+            }
+        }
+        self.phase = BettingPhase::CollectingBets;
+        Ok(())
+    }
+
+    pub fn advance_phase(&mut self) {
+        self.phase = match self.phase {
+            BettingPhase::Initial => BettingPhase::CollectingBets,
+            BettingPhase::CollectingBets => BettingPhase::FirstBetting,
+            BettingPhase::FirstBetting => BettingPhase::RevealingAnswers,
+            BettingPhase::RevealingAnswers => BettingPhase::SecondBetting,
+            BettingPhase::SecondBetting => BettingPhase::DetermineWinner,
+            BettingPhase::DetermineWinner => BettingPhase::Completed,
+            BettingPhase::Completed => BettingPhase::Completed,
+        };
+    }
+    
+    pub fn is_betting_phase(&self) -> bool {
+        matches!(self.phase, BettingPhase::FirstBetting | BettingPhase::SecondBetting)
+    }
+
+    /// Eerste speler vanaf `start` (die zelf meegerekend) die nog kan inzetten, rondgaand langs de tafel
+    fn next_eligible(players: &[Player], start: usize) -> Option<usize> {
+        (0..players.len())
+            .map(|offset| (start + offset) % players.len())
+            .find(|&index| players[index].can_bet())
+    }
+
+    /// Bepaal de deler van deze ronde: (ronde - 1) mod aantal spelers, of de eerstvolgende actieve speler
+    pub fn assign_dealer(&mut self, players: &[Player]) {
+        if players.is_empty() {
+            return;
+        }
+        let base = (self.round_number.max(1) - 1) as usize % players.len();
+        self.dealer_index = Self::next_eligible(players, base).unwrap_or(base);
+        self.current_player_index = self.dealer_index;
+    }
+
+    /// Bij het begin van een inzetronde is de deler (of de eerstvolgende die nog meedoet) als eerste aan de beurt
+    pub fn start_betting_turns(&mut self, players: &[Player]) {
+        if let Some(index) = Self::next_eligible(players, self.dealer_index) {
+            self.current_player_index = index;
+        }
+    }
+
+    /// Geef de beurt door aan de volgende speler die nog meedoet
+    pub fn advance_turn(&mut self, players: &[Player]) {
+        if players.is_empty() {
+            return;
+        }
+        if let Some(index) = Self::next_eligible(players, self.current_player_index + 1) {
+            self.current_player_index = index;
+        }
+    }
+
+    /// Controleer of een inzet of pas van deze speler nu mag
+    pub fn check_turn(&self, players: &[Player], player_id: &str) -> Result<(), GameError> {
+        if !self.is_betting_phase() {
+            return Err(GameError::new(
+                ErrorCode::IllegalPhase,
+                format!("Inzetten of passen kan alleen tijdens een inzetronde (huidige fase: {:?})", self.phase),
+            ));
+        }
+
+        let player = players.iter()
+            .find(|p| p.id == player_id)
+            .ok_or_else(|| GameError::player_not_found(player_id))?;
+        if !player.can_bet() {
+            return Err(GameError::new(ErrorCode::PlayerNotActive, format!("{} doet niet meer mee in deze ronde", player.name)));
+        }
+
+        match players.get(self.current_player_index) {
+            Some(current) if current.id == player_id => Ok(()),
+            Some(current) => Err(GameError::new(
+                ErrorCode::NotPlayersTurn,
+                format!("{} is niet aan de beurt, {} is aan de beurt", player.name, current.name),
+            )),
+            None => Err(GameError::new(ErrorCode::NotPlayersTurn, "Niemand is aan de beurt")),
+        }
+    }
+
+    /// Koppel de vragen voor deze ronde, precies één per vraagnummer
+    pub fn assign_questions(&mut self, questions: Vec<Question>) -> Result<(), GameError> {
+        if questions.len() != self.questions_count as usize {
+            return Err(GameError::invalid_input(format!(
+                "Er zijn {} vragen nodig voor deze ronde, {} ontvangen",
+                self.questions_count, questions.len()
+            )));
+        }
+        self.questions = questions;
+        self.question_notes.clear();
+        Ok(())
+    }
+
+    pub fn add_to_pot(&mut self, amount: i32) {
+        self.pot += amount;
+    }
+
+    /// Bedrag dat een speler nog moet bijleggen om de hoogste inzet te evenaren
+    pub fn amount_to_call(&self, player: &Player) -> i32 {
+        (self.highest_bet - player.current_bet).max(0)
+    }
+
+    /// Zet alle lopende inzetten in de pot ("Bij pot")
+    pub fn collect_bets_into_pot(&mut self, players: &mut [Player]) {
+        let total_bets: i32 = players.iter().map(|p| p.current_bet).sum();
+        self.add_to_pot(total_bets);
+        for player in players.iter_mut() {
+            if player.current_bet > 0 {
+                *self.contributions.entry(player.id.clone()).or_insert(0) += player.current_bet;
+            }
+            player.current_bet = 0;
+        }
+        self.highest_bet = 0;
+    }
+
+    /// Ronde afbreken zonder winnaar: lopende inzetten en wat iedereen al in de pot had gaan terug
+    pub fn refund(&mut self, players: &mut [Player]) {
+        for player in players.iter_mut() {
+            let contribution = self.contributions.remove(&player.id).unwrap_or(0);
+            player.balance += player.current_bet + contribution;
+            player.current_bet = 0;
+            self.pot -= contribution;
+        }
+        self.highest_bet = 0;
+    }
+
+    /// Begin van een inzetronde: lopende inzetten (de inleg) naar de pot en niemand heeft nog gehandeld
+    pub fn open_betting(&mut self, players: &mut [Player]) {
+        self.collect_bets_into_pot(players);
+        self.acted.clear();
+        self.start_betting_turns(players);
+
+        // Kan er niemand meer tegen elkaar inzetten (iedereen op één na all-in of gepast), dan slaan we de inzetronde over
+        if self.is_betting_complete(players) {
+            self.advance_phase();
+        }
+    }
+
+    fn player_mut<'a>(players: &'a mut [Player], player_id: &str) -> Result<&'a mut Player, GameError> {
+        players.iter_mut()
+            .find(|p| p.id == player_id)
+            .ok_or_else(|| GameError::player_not_found(player_id))
+    }
+
+    /// Check: niets bijleggen, kan alleen als de speler de hoogste inzet al evenaart
+    pub fn check(&mut self, players: &mut [Player], player_id: &str) -> Result<(), GameError> {
+        self.check_turn(players, player_id)?;
+        let player = Self::player_mut(players, player_id)?;
+        let to_call = self.amount_to_call(player);
+        if to_call > 0 {
+            return Err(GameError::new(
+                ErrorCode::InvalidBet,
+                format!("{} kan niet checken: €{} bijleggen (mee) of passen", player.name, to_call),
+            ));
+        }
+
+        self.finish_action(players, player_id);
+        Ok(())
+    }
+
+    /// Mee: leg bij tot de hoogste inzet
+    pub fn call(&mut self, players: &mut [Player], player_id: &str) -> Result<(), GameError> {
+        self.check_turn(players, player_id)?;
+        let player = Self::player_mut(players, player_id)?;
+        let to_call = self.amount_to_call(player);
+        if to_call == 0 {
+            return Err(GameError::new(ErrorCode::InvalidBet, format!("{} hoeft niets bij te leggen, gebruik check", player.name)));
+        }
+        // Te weinig saldo om mee te gaan: all-in met wat er nog is
+        player.bet_up_to(to_call);
+
+        self.finish_action(players, player_id);
+        Ok(())
+    }
+
+    /// Verhoog: eerst bijleggen tot de hoogste inzet, dan `amount` erbovenop
+    pub fn raise(&mut self, players: &mut [Player], player_id: &str, amount: i32, rules: &RuleSet) -> Result<(), GameError> {
+        if !(rules.bet_min..=rules.bet_max).contains(&amount) {
+            return Err(GameError::new(
+                ErrorCode::InvalidBet,
+                format!("Inzet moet tussen €{} en €{} zijn", rules.bet_min, rules.bet_max),
+            ));
+        }
+        self.check_turn(players, player_id)?;
+        let player = Self::player_mut(players, player_id)?;
+        let to_call = self.amount_to_call(player);
+        if to_call + amount > player.balance {
+            return Err(GameError::new(
+                ErrorCode::InsufficientBalance,
+                format!("Onvoldoende saldo voor deze verhoging, {} kan nog all-in met €{}", player.name, player.balance),
+            ));
+        }
+        player.place_bet(to_call + amount)?;
+        self.highest_bet = player.current_bet;
+
+        // Na een verhoging moet iedereen opnieuw aan de beurt komen
+        self.acted.clear();
+        self.finish_action(players, player_id);
+        Ok(())
+    }
+
+    /// All-in: het hele saldo erin. Komt dat boven de hoogste inzet, dan telt het als verhoging.
+    pub fn all_in(&mut self, players: &mut [Player], player_id: &str) -> Result<(), GameError> {
+        self.check_turn(players, player_id)?;
+        let player = Self::player_mut(players, player_id)?;
+        player.bet_up_to(player.balance);
+
+        if player.current_bet > self.highest_bet {
+            self.highest_bet = player.current_bet;
+            self.acted.clear();
+        }
+        self.finish_action(players, player_id);
+        Ok(())
+    }
+
+    pub fn fold(&mut self, players: &mut [Player], player_id: &str) -> Result<(), GameError> {
+        self.check_turn(players, player_id)?;
+        Self::player_mut(players, player_id)?.fold();
+
+        self.finish_action(players, player_id);
+        Ok(())
+    }
+
+    /// Klaar als iedereen die nog kan inzetten na de laatste verhoging aan de beurt is geweest
+    /// en de hoogste inzet evenaart. Is er nog maar één zo'n speler, dan hoeft die alleen
+    /// de hoogste inzet (van een all-in speler) te evenaren.
+    pub fn is_betting_complete(&self, players: &[Player]) -> bool {
+        let remaining: Vec<&Player> = players.iter().filter(|p| p.can_bet()).collect();
+        match remaining.as_slice() {
+            [] => true,
+            [only] => only.current_bet >= self.highest_bet,
+            _ => remaining.iter().all(|p| self.acted.contains(&p.id) && p.current_bet == self.highest_bet),
+        }
+    }
+
+    /// Na elke actie: beurt doorgeven, of de inzetronde afsluiten en door naar de volgende fase
+    fn finish_action(&mut self, players: &mut [Player], player_id: &str) {
+        if !self.acted.iter().any(|id| id == player_id) {
+            self.acted.push(player_id.to_string());
+        }
+
+        if self.is_betting_complete(players) {
+            self.collect_bets_into_pot(players);
+            self.advance_phase();
+        } else {
+            self.advance_turn(players);
+        }
+    }
+
+    /// Verdeel de pot in een hoofdpot en zijpotten op basis van wat iedereen deze ronde heeft ingelegd.
+    /// Elk niveau waarop een speler all-in ging begint een nieuwe pot waar alleen spelers
+    /// met minstens die inleg aanspraak op maken.
+    pub fn side_pots(&self, players: &[Player]) -> Vec<SidePot> {
+        let contribution = |id: &str| self.contributions.get(id).copied().unwrap_or(0);
+        let live: Vec<&Player> = players.iter()
+            .filter(|p| !p.has_folded && contribution(&p.id) > 0)
+            .collect();
+
+        // Niemand met inleg doet nog mee: één pot. Heeft niemand iets ingelegd, dan strijdt iedereen
+        // die nog meedoet om de (lege) pot; hebben alle inleggers gepast, dan gaat hij terug (zie `settle`)
+        if live.is_empty() {
+            let eligible = match self.contributions.is_empty() {
+                true => players.iter()
+                    .filter(|p| p.is_active && !p.has_folded)
+                    .map(|p| p.id.clone())
+                    .collect(),
+                false => Vec::new(),
+            };
+            return vec![SidePot { amount: self.pot, eligible }];
+        }
+
+        let mut levels: Vec<i32> = live.iter().map(|p| contribution(&p.id)).collect();
+        levels.sort_unstable();
+        levels.dedup();
+
+        let mut pots: Vec<SidePot> = Vec::new();
+        let mut previous = 0;
+        for level in levels {
+            let amount: i32 = self.contributions.values()
+                .map(|c| (*c).min(level) - (*c).min(previous))
+                .sum();
+            let eligible: Vec<String> = live.iter()
+                .filter(|p| contribution(&p.id) >= level)
+                .map(|p| p.id.clone())
+                .collect();
+            previous = level;
+
+            match pots.last_mut() {
+                Some(last) if last.eligible == eligible => last.amount += amount,
+                _ => pots.push(SidePot { amount, eligible }),
+            }
+        }
+
+        // Wat niet in de niveaus past (inleg van spelers die daarna pasten) gaat naar de hoofdpot
+        let accounted: i32 = pots.iter().map(|p| p.amount).sum();
+        pots[0].amount += self.pot - accounted;
+        pots
+    }
+
+    /// Verdeel een pot gelijk over de winnaars. Losse euro's gaan één voor één naar de
+    /// winnaars vanaf links van de deler, net als aan de pokertafel.
+    fn split_pot(&self, amount: i32, winners: &[&Player], players: &[Player]) -> Vec<PotShare> {
+        let seat = |player: &Player| {
+            let index = players.iter().position(|p| p.id == player.id).unwrap_or(0);
+            (index + players.len() - self.dealer_index % players.len().max(1) - 1) % players.len().max(1)
+        };
+        let mut ordered: Vec<&Player> = winners.to_vec();
+        ordered.sort_by_key(|p| seat(p));
+
+        let count = ordered.len() as i32;
+        let base = amount / count;
+        let odd_euros = amount % count;
+        ordered.iter()
+            .enumerate()
+            .map(|(index, player)| PotShare {
+                player_id: player.id.clone(),
+                player_name: player.name.clone(),
+                amount: base + if (index as i32) < odd_euros { 1 } else { 0 },
+            })
+            .collect()
+    }
+
+    /// Pot waar niemand meer aanspraak op maakt omdat iedereen gepast heeft: net als bij `refund`
+    /// krijgt ieder zijn inleg terug
+    fn refund_pot(&self, players: &[Player]) -> Vec<PotShare> {
+        players.iter()
+            .filter_map(|p| self.contributions.get(&p.id).map(|amount| PotShare {
+                player_id: p.id.clone(),
+                player_name: p.name.clone(),
+                amount: *amount,
+            }))
+            .filter(|share| share.amount > 0)
+            .collect()
+    }
+
+    /// Bepaal wie welke pot wint. Een gekozen winnaar krijgt elke pot waar hij aanspraak op maakt;
+    /// de overige potten gaan naar de speler met de meeste goede antwoorden (gelijke stand: pot delen).
+    /// Zonder gekozen winnaar bepaalt `tie_rule` wat er bij een gelijke stand gebeurt.
+    /// Heeft iedereen gepast, dan gaat de pot terug naar wie hem inlegde (`refund_pot`).
+    pub fn settle(&self, players: &[Player], chosen_winner: Option<&str>, tie_rule: TieRule) -> Result<RoundResult, GameError> {
+        let chosen = match chosen_winner {
+            Some(id) => Some(players.iter()
+                .find(|p| p.id == id)
+                .ok_or_else(|| GameError::player_not_found(id))?),
+            None => None,
+        };
+        // Wie gepast heeft of niet meer meedoet kan geen pot winnen
+        if let Some(winner) = chosen.filter(|w| !w.is_active || w.has_folded) {
+            return Err(GameError::new(ErrorCode::PlayerNotActive, format!("{} doet deze ronde niet meer mee en kan niet winnen", winner.name)));
+        }
+
+        let mut pots = Vec::new();
+        for pot in self.side_pots(players) {
+            if pot.eligible.is_empty() {
+                pots.push(PotResult { amount: pot.amount, shares: self.refund_pot(players) });
+                continue;
+            }
+            let shares = match chosen.filter(|w| pot.eligible.contains(&w.id)) {
+                Some(winner) => vec![PotShare {
+                    player_id: winner.id.clone(),
+                    player_name: winner.name.clone(),
+                    amount: pot.amount,
+                }],
+                None => {
+                    let eligible: Vec<&Player> = players.iter()
+                        .filter(|p| pot.eligible.contains(&p.id))
+                        .collect();
+                    let best = eligible.iter()
+                        .map(|p| p.count_correct_answers())
+                        .max()
+                        .ok_or_else(|| GameError::internal("Kan winnaar niet bepalen"))?;
+                    let leaders: Vec<&Player> = eligible.into_iter()
+                        .filter(|p| p.count_correct_answers() == best)
+                        .collect();
+
+                    if leaders.len() > 1 && chosen.is_none() && tie_rule != TieRule::SplitPot {
+                        let names = leaders.iter().map(|p| p.name.as_str()).collect::<Vec<_>>().join(" en ");
+                        let message = match tie_rule {
+                            TieRule::SuddenDeath => format!(
+                                "Gelijke stand tussen {} ({} goed): stel de beslisvraag en kies daarna de winnaar", names, best
+                            ),
+                            _ => format!("Gelijke stand tussen {} ({} goed): kies de winnaar", names, best),
+                        };
+                        return Err(GameError::new(ErrorCode::TieUnresolved, message));
+                    }
+                    self.split_pot(pot.amount, &leaders, players)
+                }
+            };
+            pots.push(PotResult { amount: pot.amount, shares });
+        }
+
+        // Totaal per winnaar, in volgorde van de eerste pot waarin ze winnen
+        let mut winners: Vec<PotShare> = Vec::new();
+        for share in pots.iter().flat_map(|pot| pot.shares.iter()) {
+            match winners.iter_mut().find(|w| w.player_id == share.player_id) {
+                Some(winner) => winner.amount += share.amount,
+                None => winners.push(share.clone()),
+            }
+        }
+
+        let winner = chosen
+            .or_else(|| winners.first().and_then(|w| players.iter().find(|p| p.id == w.player_id)))
+            .ok_or_else(|| GameError::internal("Kan winnaar niet bepalen"))?;
+
+        Ok(RoundResult {
+            round_number: self.round_number,
+            winner_id: winner.id.clone(),
+            winner_name: winner.name.clone(),
+            pot_amount: self.pot,
+            min_bet: self.min_bet,
+            correct_answers: winner.count_correct_answers(),
+            player_scores: players.iter()
+                .filter(|p| p.is_active && !p.has_folded)
+                .map(|p| (p.id.clone(), p.count_correct_answers()))
+                .collect(),
+            pots,
+            winners,
+            questions: self.questions.clone(),
+            answers: players.iter()
+                .filter(|p| p.is_active || !p.answers.is_empty())
+                .map(|p| RoundAnswers {
+                    player_id: p.id.clone(),
+                    player_name: p.name.clone(),
+                    has_folded: p.has_folded,
+                    answers: p.answers.clone(),
+                })
+                .collect(),
+            balances: Vec::new(), // Vult `GameState::complete_round` na het uitbetalen
+        })
+    }
+}
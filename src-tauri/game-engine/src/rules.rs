@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+use super::{GameError, TimerPresets};
+
+/// Wat er gebeurt als meerdere spelers evenveel goede antwoorden hebben en de ronde
+/// automatisch wordt afgerekend (zonder gekozen winnaar)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TieRule {
+    SplitPot,         // Pot gelijk verdelen; losse euro's gaan naar de eerste spelers links van de deler
+    SuddenDeath,      // Beslisvraag; daarna kiest de quizmaster de winnaar
+    #[default]
+    QuizmasterChoice, // Quizmaster kiest de winnaar
+}
+
+/// Spelregels van een format. `Default` is de uitzending zoals die op tv komt;
+/// pilots, kinderedities en korte repetities krijgen een eigen bestand.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct RuleSet {
+    pub name: String,
+    pub starting_balance: i32,
+    pub min_bets: Vec<i32>,                   // Minimale inzet per ronde; de laatste geldt ook voor latere rondes
+    pub bet_min: i32,                         // Kleinste inzet in een inzetronde
+    pub bet_max: i32,                         // Grootste inzet in een inzetronde
+    pub rounds: i32,
+    pub questions_per_round: i32,
+    pub elimination_after_round: Option<i32>, // Na deze ronde valt de speler met het minste geld af
+    pub elimination_min_players: usize,       // Alleen elimineren als er meer actieve spelers zijn dan dit
+    pub tie_rule: TieRule,
+    pub timer: TimerPresets,                  // Standaardtijden voor schrijven en inzetten
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self {
+            name: "Standaard".to_string(),
+            starting_balance: 750,
+            min_bets: vec![10, 20, 40, 80],
+            bet_min: 10,
+            bet_max: 50,
+            rounds: 7,
+            questions_per_round: 4,
+            elimination_after_round: Some(4),
+            elimination_min_players: 2,
+            tie_rule: TieRule::default(),
+            timer: TimerPresets::default(),
+        }
+    }
+}
+
+impl RuleSet {
+    /// Minimale inzet voor een ronde (rondes tellen vanaf 1)
+    pub fn min_bet_for_round(&self, round_number: i32) -> i32 {
+        let index = (round_number.max(1) - 1) as usize;
+        self.min_bets.get(index)
+            .or(self.min_bets.last())
+            .copied()
+            .unwrap_or(0)
+    }
+
+    pub fn validate(&self) -> Result<(), GameError> {
+        if self.starting_balance <= 0 {
+            return Err(GameError::invalid_input("Startsaldo moet positief zijn"));
+        }
+        if self.min_bets.is_empty() || self.min_bets.iter().any(|b| *b <= 0) {
+            return Err(GameError::invalid_input("Minimale inzetten moeten positief zijn"));
+        }
+        if self.bet_min <= 0 || self.bet_max < self.bet_min {
+            return Err(GameError::invalid_input(format!("Ongeldige inzetgrenzen €{}–€{}", self.bet_min, self.bet_max)));
+        }
+        if self.rounds < 1 {
+            return Err(GameError::invalid_input("Er moet minstens één ronde zijn"));
+        }
+        if self.questions_per_round < 1 {
+            return Err(GameError::invalid_input("Er moet minstens één vraag per ronde zijn"));
+        }
+        if self.timer.writing_seconds < 0 || self.timer.betting_seconds < 0 {
+            return Err(GameError::invalid_input("Timertijden kunnen niet negatief zijn"));
+        }
+        if let Some(round) = self.elimination_after_round {
+            if !(1..=self.rounds).contains(&round) {
+                return Err(GameError::invalid_input(format!("Eliminatie na ronde {} valt buiten de {} rondes", round, self.rounds)));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+use super::{Answer, BalanceChange, Episode, ErrorCode, GameError, Player, Round, RoundResult, RuleSet, TimerMode};
+use chrono::Utc;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameState {
+    pub game_id: String,
+    pub players: Vec<Player>,
+    pub current_round: Option<Round>,
+    pub round_number: i32,
+    pub round_history: Vec<RoundResult>,
+    pub created_at: String,
+    pub is_finished: bool,
+    pub video_mode_active: bool, // Toggle voor externe video weergave
+    pub video_device_id: Option<String>, // Optioneel: specifiek video device
+    pub writing_enabled: bool, // Toggle om schrijven toe te staan
+    pub timer_seconds: i32, // Timer in seconden zoals getoond: resterend bij aftellen, gelopen bij stopwatch
+    pub timer_running: bool, // Of de timer loopt
+    #[serde(default)]
+    pub timer_mode: TimerMode,
+    #[serde(default)]
+    pub timer_duration: i32, // Ingestelde tijd bij aftellen
+    #[serde(default)]
+    pub timer_remaining_ms: i64, // Stand van de serverklok bij deze update; clients tellen hier zelf vanaf door
+    #[serde(default)]
+    pub episode: Option<Episode>, // Draaiboek van deze aflevering, als die vooraf is voorbereid
+    #[serde(default)]
+    pub rules: RuleSet, // Spelregels van dit format (oude snapshots krijgen de standaardregels)
+    #[serde(default)]
+    pub balance_timeline: Vec<BalanceChange>, // Elke saldowijziging van elke speler, zie `Engine::apply`
+}
+
+impl GameState {
+    pub fn new(player_names: Vec<String>, rules: RuleSet) -> Self {
+        let game_id = format!("game_{}", Utc::now().timestamp());
+        let players: Vec<Player> = player_names
+            .into_iter()
+            .enumerate()
+            .map(|(idx, name)| Player::new(format!("player_{}", idx), name, rules.starting_balance))
+            .collect();
+
+        Self {
+            game_id,
+            players,
+            current_round: None,
+            round_number: 0,
+            round_history: Vec::new(),
+            created_at: Utc::now().to_rfc3339(),
+            is_finished: false,
+            video_mode_active: false,
+            video_device_id: None,
+            writing_enabled: true, // Standaard aan
+            timer_seconds: 0,
+            timer_running: false,
+            timer_mode: TimerMode::default(),
+            timer_duration: 0,
+            timer_remaining_ms: 0,
+            episode: None,
+            rules,
+            balance_timeline: Vec::new(),
+        }
+    }
+
+    pub fn start_new_round(&mut self, mut round: Round) {
+        self.round_number += 1;
+        
+        for player in &mut self.players {
+            // Markeer spelers met balance <= 0 als inactief
+            if player.balance <= 0 {
+                player.is_active = false;
+            }
+            player.reset_for_round();
+        }
+        
+        round.assign_dealer(&self.players);
+        self.current_round = Some(round);
+        self.load_episode_questions();
+    }
+
+    pub fn complete_round(&mut self, mut result: RoundResult) {
+        // Hoofdpot en zijpotten elk naar hun eigen winnaar
+        let payouts: Vec<(String, i32)> = result.pots.iter()
+            .flat_map(|pot| pot.shares.iter())
+            .map(|share| (share.player_id.clone(), share.amount))
+            .collect();
+        for (winner_id, amount) in payouts {
+            if let Some(winner) = self.players.iter_mut().find(|p| p.id == winner_id) {
+                winner.win_pot(amount);
+            }
+        }
+
+        result.balances = self.players.iter().map(|p| (p.id.clone(), p.balance)).collect();
+        self.round_history.push(result);
+        self.current_round = None;
+
+        // Update is_active voor spelers met balance <= 0
+        for player in &mut self.players {
+            if player.balance <= 0 && player.is_active {
+                player.is_active = false;
+            }
+        }
+
+        // Na de eliminatieronde (standaard ronde 4): elimineer de speler met het minste geld
+        if self.rules.elimination_after_round == Some(self.round_number) {
+            let active_players: Vec<_> = self.players.iter_mut()
+                .filter(|p| p.is_active && p.balance > 0)
+                .collect();
+            
+            if active_players.len() > self.rules.elimination_min_players {
+                // Vind de speler met de laagste balance
+                let min_balance = active_players.iter().map(|p| p.balance).min().unwrap_or(0);
+                let loser = self.players.iter_mut()
+                    .find(|p| p.is_active && p.balance == min_balance);
+                
+                if let Some(player) = loser {
+                    player.is_active = false;
+                }
+            }
+        }
+
+        let active_players = self.players.iter().filter(|p| !p.is_eliminated()).count();
+        if self.round_number >= self.rules.rounds || active_players <= 1 {
+            self.is_finished = true;
+        }
+    }
+
+    /// Al het geld aan tafel: saldo's, inzetten die nog voor de spelers staan en de pot
+    pub fn money_on_table(&self) -> i32 {
+        let players: i32 = self.players.iter().map(|p| p.balance + p.current_bet).sum();
+        players + self.current_round.as_ref().map(|r| r.pot).unwrap_or(0)
+    }
+
+    /// Geld kan alleen van eigenaar wisselen: saldo's + inzetten + pot is altijd
+    /// het startgeld maal het aantal spelers, en niemand staat onder nul
+    pub fn check_money(&self) -> Result<(), GameError> {
+        let expected = self.rules.starting_balance * self.players.len() as i32;
+        let actual = self.money_on_table();
+        if actual != expected {
+            return Err(GameError::new(
+                ErrorCode::MoneyMismatch,
+                format!("Geld klopt niet: €{} aan tafel, €{} verwacht", actual, expected),
+            ));
+        }
+        if let Some(player) = self.players.iter().find(|p| p.balance < 0 || p.current_bet < 0) {
+            return Err(GameError::new(
+                ErrorCode::MoneyMismatch,
+                format!("Geld klopt niet: {} staat op €{} met €{} inzet", player.name, player.balance, player.current_bet),
+            ));
+        }
+        if let Some(round) = self.current_round.as_ref().filter(|r| r.pot < 0) {
+            return Err(GameError::new(ErrorCode::MoneyMismatch, format!("Geld klopt niet: pot staat op €{}", round.pot)));
+        }
+        Ok(())
+    }
+
+    /// Antwoord bij een tekening, op het pad uit `Answer.image_path`: uit de lopende ronde
+    /// of uit het archief van een afgeronde ronde
+    pub fn find_answer(&self, image_path: &str) -> Option<&Answer> {
+        let archived = self.round_history.iter()
+            .flat_map(|r| &r.answers)
+            .flat_map(|p| &p.answers);
+        self.players.iter()
+            .flat_map(|p| &p.answers)
+            .chain(archived)
+            .find(|a| !a.image_path.is_empty() && a.image_path == image_path)
+    }
+
+    pub fn get_leaderboard(&self) -> Vec<(&Player, i32)> {
+        let mut leaderboard: Vec<(&Player, i32)> = self.players
+            .iter()
+            .map(|p| (p, p.balance))
+            .collect();
+        
+        leaderboard.sort_by_key(|entry| std::cmp::Reverse(entry.1));
+        leaderboard
+    }
+}
@@ -0,0 +1,94 @@
+//! Gedeelde hulpjes voor de integratietests en de tests van de app (feature `test-support`):
+//! een spel opzetten en acties uitvoeren via de `Engine`.
+
+use crate::{BettingPhase, Engine, ErrorCode, GameAction, GameState, Round, RuleSet};
+
+pub const TIMESTAMP: &str = "2025-01-01T20:00:00+00:00";
+
+/// Speler-id zoals `GameState::new` ze uitdeelt (stoel 0 = "player_0")
+pub fn p(seat: usize) -> String {
+    format!("player_{}", seat)
+}
+
+pub fn start(player_names: &[&str], rules: RuleSet) -> Engine {
+    let mut engine = Engine::new();
+    engine.apply(&GameAction::StartGame {
+        game_id: "game_test".to_string(),
+        created_at: TIMESTAMP.to_string(),
+        player_names: player_names.iter().map(|n| n.to_string()).collect(),
+        rules,
+    }, TIMESTAMP).expect("StartGame");
+    engine
+}
+
+pub fn start_default(players: usize) -> Engine {
+    let names = ["Anna", "Bert", "Carla", "Daan", "Eva", "Fleur"];
+    start(&names[..players], RuleSet::default())
+}
+
+pub fn act(engine: &mut Engine, action: GameAction) {
+    if let Err(e) = engine.apply(&action, TIMESTAMP) {
+        panic!("{:?} mislukt: {:?}: {}", action, e.code, e);
+    }
+}
+
+/// Voer een actie uit die geweigerd moet worden en geef de foutcode terug
+pub fn reject(engine: &mut Engine, action: GameAction) -> ErrorCode {
+    match engine.apply(&action, TIMESTAMP) {
+        Ok(_) => panic!("{:?} had geweigerd moeten worden", action),
+        Err(e) => e.code,
+    }
+}
+
+pub fn game(engine: &Engine) -> &GameState {
+    engine.game().expect("actief spel")
+}
+
+pub fn round(engine: &Engine) -> &Round {
+    game(engine).current_round.as_ref().expect("actieve ronde")
+}
+
+pub fn phase(engine: &Engine) -> BettingPhase {
+    round(engine).phase.clone()
+}
+
+pub fn balances(engine: &Engine) -> Vec<i32> {
+    game(engine).players.iter().map(|p| p.balance).collect()
+}
+
+/// Inleg verzamelen en door naar de eerste inzetronde
+pub fn open_first_betting(engine: &mut Engine) {
+    act(engine, GameAction::CollectInitialBets);
+    act(engine, GameAction::AdvancePhase);
+}
+
+pub fn raise(player: usize, amount: i32) -> GameAction {
+    GameAction::PlaceBet { player_id: p(player), amount }
+}
+
+pub fn check(player: usize) -> GameAction {
+    GameAction::Check { player_id: p(player) }
+}
+
+pub fn call(player: usize) -> GameAction {
+    GameAction::Call { player_id: p(player) }
+}
+
+pub fn all_in(player: usize) -> GameAction {
+    GameAction::AllIn { player_id: p(player) }
+}
+
+pub fn fold(player: usize) -> GameAction {
+    GameAction::PlayerFold { player_id: p(player) }
+}
+
+pub fn answer(player: usize, question_number: i32, is_correct: bool) -> [GameAction; 2] {
+    [
+        GameAction::UpdateAnswer { player_id: p(player), question_number, image_path: format!("{}/q{}.png", p(player), question_number), drawing_path: String::new() },
+        GameAction::ApproveAnswer { player_id: p(player), question_number, is_correct },
+    ]
+}
+
+pub fn complete(winner: Option<usize>) -> GameAction {
+    GameAction::CompleteRound { winner_id: winner.map(p) }
+}
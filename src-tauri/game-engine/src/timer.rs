@@ -0,0 +1,204 @@
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+use super::{BettingPhase, GameState};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TimerMode {
+    #[default]
+    Countdown, // Aftellen naar nul, daarna verloopt de timer
+    Stopwatch, // Optellen zonder einde
+}
+
+/// Standaardtijden per fase, onderdeel van de regelset van een format
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct TimerPresets {
+    pub writing_seconds: i32,         // Antwoorden schrijven (fase Initial)
+    pub betting_seconds: i32,         // Eerste en tweede inzetronde
+    pub lock_writing_on_expiry: bool, // Schrijven blokkeren als de tijd om is
+}
+
+impl Default for TimerPresets {
+    fn default() -> Self {
+        Self {
+            writing_seconds: 90,
+            betting_seconds: 30,
+            lock_writing_on_expiry: true,
+        }
+    }
+}
+
+impl TimerPresets {
+    /// Tijd voor een fase, of `None` als die fase geen eigen timer heeft
+    pub fn for_phase(&self, phase: &BettingPhase) -> Option<i32> {
+        match phase {
+            BettingPhase::Initial => Some(self.writing_seconds),
+            BettingPhase::FirstBetting | BettingPhase::SecondBetting => Some(self.betting_seconds),
+            _ => None,
+        }
+    }
+}
+
+/// De klok achter de timer. Rekent met `Instant`, dus een verzette systeemklok of een
+/// trage tick verandert niets aan de resterende tijd. Alle methodes krijgen `now` mee,
+/// zodat er maar één moment per tick is en de klok zonder wachten te testen is.
+#[derive(Debug, Clone)]
+pub struct TimerClock {
+    mode: TimerMode,
+    duration: Duration,          // Ingestelde tijd bij aftellen
+    elapsed: Duration,           // Gelopen tijd tot de laatste pauze
+    started_at: Option<Instant>, // Sinds wanneer de timer (weer) loopt
+}
+
+impl Default for TimerClock {
+    fn default() -> Self {
+        Self::new(TimerMode::Countdown, 0)
+    }
+}
+
+impl TimerClock {
+    pub fn new(mode: TimerMode, seconds: i32) -> Self {
+        Self {
+            mode,
+            duration: Duration::from_secs(seconds.max(0) as u64),
+            elapsed: Duration::ZERO,
+            started_at: None,
+        }
+    }
+
+    pub fn mode(&self) -> TimerMode {
+        self.mode
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.started_at.is_some()
+    }
+
+    /// Nieuwe tijd instellen; de timer staat daarna stil op de volle tijd
+    pub fn set(&mut self, mode: TimerMode, seconds: i32) {
+        *self = Self::new(mode, seconds);
+    }
+
+    /// Starten of hervatten. Loopt de timer al, dan gebeurt er niets.
+    pub fn start(&mut self, now: Instant) {
+        if self.started_at.is_none() && !self.is_expired(now) {
+            self.started_at = Some(now);
+        }
+    }
+
+    pub fn pause(&mut self, now: Instant) {
+        if let Some(started_at) = self.started_at.take() {
+            self.elapsed += now.saturating_duration_since(started_at);
+        }
+    }
+
+    /// Terug naar de ingestelde tijd, stilstaand
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.started_at = None;
+    }
+
+    pub fn elapsed(&self, now: Instant) -> Duration {
+        let running = self.started_at
+            .map(|started_at| now.saturating_duration_since(started_at))
+            .unwrap_or_default();
+        self.elapsed + running
+    }
+
+    pub fn remaining(&self, now: Instant) -> Duration {
+        self.duration.saturating_sub(self.elapsed(now))
+    }
+
+    pub fn is_expired(&self, now: Instant) -> bool {
+        self.mode == TimerMode::Countdown && !self.duration.is_zero() && self.remaining(now).is_zero()
+    }
+
+    /// Loopt een aftellende timer net af, dan stopt hij op nul. Geeft `true` terug op het
+    /// moment van aflopen, zodat de aanroeper dat maar één keer afhandelt.
+    pub fn expire_if_due(&mut self, now: Instant) -> bool {
+        if self.is_running() && self.is_expired(now) {
+            self.started_at = None;
+            self.elapsed = self.duration;
+            return true;
+        }
+        false
+    }
+
+    /// Wat er op het scherm staat: resterende seconden (naar boven afgerond, zodat "0:00"
+    /// pas verschijnt als de tijd echt om is) of gelopen seconden bij de stopwatch
+    pub fn display_seconds(&self, now: Instant) -> i32 {
+        match self.mode {
+            TimerMode::Countdown => {
+                let remaining = self.remaining(now).as_millis();
+                remaining.div_ceil(1000) as i32
+            }
+            TimerMode::Stopwatch => self.elapsed(now).as_secs() as i32,
+        }
+    }
+
+    /// Zet de stand van de klok in de `GameState` die naar de clients gaat.
+    /// Geeft `true` als er iets veranderd is dat de clients moeten zien.
+    pub fn write_to(&self, game: &mut GameState, now: Instant) -> bool {
+        let seconds = self.display_seconds(now);
+        let duration = self.duration.as_secs() as i32;
+        let changed = game.timer_seconds != seconds
+            || game.timer_running != self.is_running()
+            || game.timer_mode != self.mode
+            || game.timer_duration != duration;
+
+        game.timer_seconds = seconds;
+        game.timer_running = self.is_running();
+        game.timer_mode = self.mode;
+        game.timer_duration = duration;
+        game.timer_remaining_ms = match self.mode {
+            TimerMode::Countdown => self.remaining(now).as_millis() as i64,
+            TimerMode::Stopwatch => self.elapsed(now).as_millis() as i64,
+        };
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(start: Instant, millis: u64) -> Instant {
+        start + Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn countdown_pauses_resumes_and_expires_once() {
+        let t0 = Instant::now();
+        let mut clock = TimerClock::new(TimerMode::Countdown, 10);
+        clock.start(t0);
+        clock.start(at(t0, 3_000)); // Tweede start telt niet dubbel
+        assert_eq!(clock.display_seconds(at(t0, 3_500)), 7);
+
+        clock.pause(at(t0, 4_000));
+        assert_eq!(clock.display_seconds(at(t0, 60_000)), 6);
+
+        clock.start(at(t0, 60_000));
+        assert!(!clock.expire_if_due(at(t0, 65_999)));
+        assert_eq!(clock.display_seconds(at(t0, 65_999)), 1);
+        assert!(clock.expire_if_due(at(t0, 66_000)));
+        assert!(!clock.expire_if_due(at(t0, 67_000)));
+        assert!(!clock.is_running());
+        assert_eq!(clock.display_seconds(at(t0, 70_000)), 0);
+
+        // Afgelopen timer start pas weer na een reset
+        clock.start(at(t0, 70_000));
+        assert!(!clock.is_running());
+        clock.reset();
+        assert_eq!(clock.display_seconds(at(t0, 70_000)), 10);
+    }
+
+    #[test]
+    fn stopwatch_counts_up_without_expiring() {
+        let t0 = Instant::now();
+        let mut clock = TimerClock::new(TimerMode::Stopwatch, 0);
+        clock.start(t0);
+        assert_eq!(clock.display_seconds(at(t0, 125_400)), 125);
+        assert!(!clock.expire_if_due(at(t0, 125_400)));
+    }
+}
@@ -0,0 +1,140 @@
+//! Geld kan niet ontstaan of verdwijnen. Willekeurige reeksen acties (ook ongeldige, undo/redo,
+//! resets en eliminaties) moeten saldo's + inzetten + pot gelijk houden aan het startgeld.
+
+use game_engine::test_support::*;
+use game_engine::{Engine, ErrorCode, GameAction, RuleSet, TieRule};
+use proptest::prelude::*;
+
+/// Willekeurige actie; stoel `players` is een speler die niet bestaat
+fn action(players: usize) -> impl Strategy<Value = GameAction> {
+    let seat = 0..=players;
+    prop_oneof![
+        3 => Just(GameAction::CollectInitialBets),
+        1 => Just(GameAction::AddBetsToPot),
+        3 => Just(GameAction::AdvancePhase),
+        4 => (seat.clone(), 0..70i32).prop_map(|(s, amount)| raise(s, amount)),
+        4 => seat.clone().prop_map(check),
+        4 => seat.clone().prop_map(call),
+        2 => seat.clone().prop_map(all_in),
+        2 => seat.clone().prop_map(fold),
+        2 => (seat.clone(), 1..=4i32).prop_map(|(s, question_number)| GameAction::UpdateAnswer {
+            player_id: p(s),
+            question_number,
+            image_path: String::new(),
+            drawing_path: String::new(),
+        }),
+        2 => (seat.clone(), 1..=4i32, any::<bool>()).prop_map(|(s, question_number, is_correct)| GameAction::ApproveAnswer {
+            player_id: p(s),
+            question_number,
+            is_correct,
+        }),
+        3 => proptest::option::of(seat.clone()).prop_map(complete),
+        2 => Just(GameAction::StartNextRound),
+        1 => (1..=7i32).prop_map(|round_num| GameAction::SetRoundNumber { round_num }),
+        1 => Just(GameAction::ResetGame),
+        1 => (seat, any::<bool>()).prop_map(|(s, is_active)| GameAction::TogglePlayerActive { player_id: p(s), is_active }),
+        2 => Just(GameAction::Undo),
+        1 => Just(GameAction::Redo),
+    ]
+}
+
+fn rules() -> impl Strategy<Value = RuleSet> {
+    // Kleine startsaldo's zodat all-in, zijpotten en failliete spelers vaak voorkomen
+    (20..=400i32, prop_oneof![Just(TieRule::SplitPot), Just(TieRule::SuddenDeath), Just(TieRule::QuizmasterChoice)])
+        .prop_map(|(starting_balance, tie_rule)| RuleSet { starting_balance, tie_rule, ..RuleSet::default() })
+}
+
+fn game_setup() -> impl Strategy<Value = (usize, RuleSet, Vec<GameAction>)> {
+    (2..=5usize, rules()).prop_flat_map(|(players, rules)| {
+        (Just(players), Just(rules), proptest::collection::vec(action(players), 1..120))
+    })
+}
+
+fn new_game(players: usize, rules: RuleSet) -> Engine {
+    let names = ["Anna", "Bert", "Carla", "Daan", "Eva"];
+    start(&names[..players], rules)
+}
+
+proptest! {
+    #[test]
+    fn money_is_conserved_after_every_action((players, rules, actions) in game_setup()) {
+        let total = rules.starting_balance * players as i32;
+        let mut engine = new_game(players, rules);
+
+        for action in &actions {
+            let before = game(&engine).clone();
+            match engine.apply(action, TIMESTAMP) {
+                Ok(_) => {}
+                Err(e) => {
+                    prop_assert_ne!(e.code, ErrorCode::MoneyMismatch, "{:?}: {}", action, e);
+                    // Een geweigerde actie verandert niets
+                    prop_assert_eq!(serde_json::to_value(game(&engine)).unwrap(), serde_json::to_value(&before).unwrap());
+                }
+            }
+
+            let game = game(&engine);
+            prop_assert_eq!(game.money_on_table(), total, "na {:?}", action);
+            prop_assert!(game.players.iter().all(|p| p.balance >= 0 && p.current_bet >= 0), "negatief saldo na {:?}", action);
+            prop_assert!(game.current_round.as_ref().is_none_or(|r| r.pot >= 0), "negatieve pot na {:?}", action);
+            // Elke saldowijziging staat in de tijdlijn, ook na undo en redo
+            let timelines = game.balance_timelines(None).unwrap();
+            prop_assert!(
+                timelines.iter().zip(&game.players).all(|(t, p)| t.changes.iter().map(|c| c.amount).sum::<i32>() == p.balance),
+                "tijdlijn klopt niet met de saldo's na {:?}", action
+            );
+        }
+    }
+
+    #[test]
+    fn same_actions_give_the_same_game((players, rules, actions) in game_setup()) {
+        let mut live = new_game(players, rules.clone());
+        let mut replayed = new_game(players, rules);
+        for action in &actions {
+            let _ = live.apply(action, TIMESTAMP);
+        }
+        for action in &actions {
+            let _ = replayed.apply(action, TIMESTAMP);
+        }
+
+        prop_assert_eq!(serde_json::to_value(game(&live)).unwrap(), serde_json::to_value(game(&replayed)).unwrap());
+    }
+}
+
+#[test]
+fn actions_that_would_break_the_money_are_refused() {
+    let mut engine = start_default(3);
+    open_first_betting(&mut engine);
+
+    // Buiten het spelverloop om geld erbij: de volgende actie wordt geweigerd en teruggedraaid
+    engine.game_mut().unwrap().players[0].balance += 5;
+    let code = reject(&mut engine, raise(0, 10));
+    assert_eq!(code, ErrorCode::MoneyMismatch);
+    assert_eq!(game(&engine).players[0].current_bet, 0);
+}
+
+#[test]
+fn starting_a_new_round_midway_returns_all_stakes() {
+    let mut engine = start_default(3);
+    open_first_betting(&mut engine);
+    act(&mut engine, raise(0, 20));
+    act(&mut engine, call(1));
+
+    // "Schoon": nieuwe ronde terwijl er €30 inleg in de pot en €40 voor de spelers staat
+    act(&mut engine, GameAction::StartNextRound);
+
+    assert_eq!(balances(&engine), vec![750, 750, 750]);
+    assert_eq!(round(&engine).pot, 0);
+    assert_eq!(game(&engine).round_number, 2);
+}
+
+#[test]
+fn reset_brings_everyone_back_to_the_starting_balance() {
+    let mut engine = start_default(3);
+    open_first_betting(&mut engine);
+    act(&mut engine, raise(0, 50));
+
+    act(&mut engine, GameAction::ResetGame);
+
+    assert_eq!(balances(&engine), vec![750, 750, 750]);
+    assert_eq!(game(&engine).money_on_table(), 2250);
+}
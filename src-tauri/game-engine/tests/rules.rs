@@ -0,0 +1,870 @@
+//! Spelregels per onderdeel: fases, inzetten, passen, eliminatie en het einde van het spel.
+
+use game_engine::test_support::*;
+use game_engine::drawing::MAX_DRAWING_POINTS;
+use game_engine::{BalanceReason, BettingPhase, Drawing, Engine, Episode, EpisodeQuestion, EpisodeRound, ErrorCode, GameAction, Question, Round, RuleSet, Stroke, StrokePoint, TieRule};
+
+// --- Fases ---
+
+#[test]
+fn advance_phase_walks_through_every_phase_in_order() {
+    let mut round = Round::new(1, &RuleSet::default());
+    let expected = [
+        BettingPhase::CollectingBets,
+        BettingPhase::FirstBetting,
+        BettingPhase::RevealingAnswers,
+        BettingPhase::SecondBetting,
+        BettingPhase::DetermineWinner,
+        BettingPhase::Completed,
+        BettingPhase::Completed, // Blijft staan
+    ];
+    assert_eq!(round.phase, BettingPhase::Initial);
+    for phase in expected {
+        round.advance_phase();
+        assert_eq!(round.phase, phase);
+    }
+}
+
+#[test]
+fn only_the_two_betting_rounds_are_betting_phases() {
+    let mut round = Round::new(1, &RuleSet::default());
+    let mut betting = Vec::new();
+    for _ in 0..6 {
+        if round.is_betting_phase() {
+            betting.push(round.phase.clone());
+        }
+        round.advance_phase();
+    }
+    assert_eq!(betting, vec![BettingPhase::FirstBetting, BettingPhase::SecondBetting]);
+}
+
+#[test]
+fn collecting_initial_bets_takes_the_minimum_bet_but_leaves_the_pot_alone() {
+    let mut engine = start_default(3);
+    act(&mut engine, GameAction::CollectInitialBets);
+
+    assert_eq!(phase(&engine), BettingPhase::CollectingBets);
+    assert_eq!(round(&engine).pot, 0);
+    for player in &game(&engine).players {
+        assert_eq!(player.balance, 740);
+        assert_eq!(player.current_bet, 10);
+    }
+}
+
+#[test]
+fn add_bets_to_pot_moves_the_running_bets_into_the_pot() {
+    let mut engine = start_default(3);
+    act(&mut engine, GameAction::CollectInitialBets);
+    act(&mut engine, GameAction::AddBetsToPot);
+
+    assert_eq!(round(&engine).pot, 30);
+    assert!(game(&engine).players.iter().all(|p| p.current_bet == 0));
+    assert_eq!(round(&engine).contributions.values().sum::<i32>(), 30);
+}
+
+#[test]
+fn opening_the_first_betting_round_pots_the_antes_and_starts_at_the_dealer() {
+    let mut engine = start_default(3);
+    open_first_betting(&mut engine);
+
+    let round = round(&engine);
+    assert_eq!(round.phase, BettingPhase::FirstBetting);
+    assert_eq!(round.pot, 30);
+    assert_eq!(round.highest_bet, 0);
+    assert_eq!(round.dealer_index, 0);
+    assert_eq!(round.current_player_index, 0);
+}
+
+#[test]
+fn dealer_moves_one_seat_each_round() {
+    let mut engine = start_default(3);
+    let mut dealers = vec![round(&engine).dealer_index];
+    for _ in 0..3 {
+        act(&mut engine, complete(Some(0)));
+        act(&mut engine, GameAction::StartNextRound);
+        dealers.push(round(&engine).dealer_index);
+    }
+    assert_eq!(dealers, vec![0, 1, 2, 0]);
+}
+
+#[test]
+fn completed_betting_rounds_advance_to_reveal_and_determine_winner() {
+    let mut engine = start_default(3);
+    open_first_betting(&mut engine);
+    for seat in 0..3 {
+        act(&mut engine, check(seat));
+    }
+    assert_eq!(phase(&engine), BettingPhase::RevealingAnswers);
+
+    act(&mut engine, GameAction::AdvancePhase);
+    assert_eq!(phase(&engine), BettingPhase::SecondBetting);
+    for seat in 0..3 {
+        act(&mut engine, check(seat));
+    }
+    assert_eq!(phase(&engine), BettingPhase::DetermineWinner);
+    assert_eq!(round(&engine).pot, 30);
+}
+
+#[test]
+fn advancing_during_a_betting_round_pots_what_is_on_the_table() {
+    let mut engine = start_default(3);
+    open_first_betting(&mut engine);
+    act(&mut engine, raise(0, 20));
+    act(&mut engine, GameAction::AdvancePhase);
+
+    assert_eq!(phase(&engine), BettingPhase::RevealingAnswers);
+    assert_eq!(round(&engine).pot, 50);
+    assert_eq!(game(&engine).players[0].current_bet, 0);
+    assert_eq!(game(&engine).players[0].balance, 720);
+}
+
+#[test]
+fn betting_round_is_skipped_when_nobody_can_bet_against_each_other() {
+    let rules = RuleSet { starting_balance: 10, ..RuleSet::default() };
+    let mut engine = start(&["Anna", "Bert"], rules);
+    open_first_betting(&mut engine);
+
+    // Beide spelers zijn met de inleg all-in gegaan
+    assert!(game(&engine).players.iter().all(|p| p.is_all_in));
+    assert_eq!(phase(&engine), BettingPhase::RevealingAnswers);
+}
+
+// --- Inzetten ---
+
+#[test]
+fn raise_must_stay_within_the_bet_limits() {
+    let mut engine = start_default(3);
+    open_first_betting(&mut engine);
+
+    assert_eq!(reject(&mut engine, raise(0, 5)), ErrorCode::InvalidBet);
+    assert_eq!(reject(&mut engine, raise(0, 55)), ErrorCode::InvalidBet);
+    act(&mut engine, raise(0, 10));
+    act(&mut engine, raise(1, 50));
+    assert_eq!(round(&engine).highest_bet, 60);
+}
+
+#[test]
+fn raise_limits_come_from_the_rule_set() {
+    let rules = RuleSet { bet_min: 5, bet_max: 20, ..RuleSet::default() };
+    let mut engine = start(&["Anna", "Bert"], rules);
+    open_first_betting(&mut engine);
+
+    assert_eq!(reject(&mut engine, raise(0, 25)), ErrorCode::InvalidBet);
+    act(&mut engine, raise(0, 5));
+}
+
+#[test]
+fn betting_is_only_allowed_during_a_betting_round() {
+    let mut engine = start_default(3);
+    assert_eq!(reject(&mut engine, raise(0, 10)), ErrorCode::IllegalPhase);
+    assert_eq!(reject(&mut engine, check(0)), ErrorCode::IllegalPhase);
+    assert_eq!(reject(&mut engine, fold(0)), ErrorCode::IllegalPhase);
+
+    act(&mut engine, GameAction::CollectInitialBets);
+    assert_eq!(reject(&mut engine, call(0)), ErrorCode::IllegalPhase);
+}
+
+#[test]
+fn only_the_player_whose_turn_it_is_may_act() {
+    let mut engine = start_default(3);
+    open_first_betting(&mut engine);
+
+    assert_eq!(reject(&mut engine, raise(1, 10)), ErrorCode::NotPlayersTurn);
+    act(&mut engine, check(0));
+    assert_eq!(reject(&mut engine, check(0)), ErrorCode::NotPlayersTurn);
+    act(&mut engine, check(1));
+}
+
+#[test]
+fn unknown_players_are_rejected() {
+    let mut engine = start_default(3);
+    open_first_betting(&mut engine);
+    assert_eq!(reject(&mut engine, raise(7, 10)), ErrorCode::PlayerNotFound);
+}
+
+#[test]
+fn check_and_call_depend_on_the_amount_to_call() {
+    let mut engine = start_default(3);
+    open_first_betting(&mut engine);
+
+    // Nog niets ingezet: mee kan niet, checken wel
+    assert_eq!(reject(&mut engine, call(0)), ErrorCode::InvalidBet);
+    act(&mut engine, check(0));
+    act(&mut engine, raise(1, 20));
+
+    // Er staat €20: checken kan niet meer
+    assert_eq!(reject(&mut engine, check(2)), ErrorCode::InvalidBet);
+    act(&mut engine, call(2));
+    assert_eq!(game(&engine).players[2].current_bet, 20);
+}
+
+#[test]
+fn a_raise_reopens_the_betting_for_everyone() {
+    let mut engine = start_default(3);
+    open_first_betting(&mut engine);
+
+    act(&mut engine, check(0));
+    act(&mut engine, check(1));
+    act(&mut engine, raise(2, 10));
+    assert_eq!(phase(&engine), BettingPhase::FirstBetting);
+    act(&mut engine, call(0));
+    assert_eq!(phase(&engine), BettingPhase::FirstBetting);
+    act(&mut engine, call(1));
+
+    assert_eq!(phase(&engine), BettingPhase::RevealingAnswers);
+    assert_eq!(round(&engine).pot, 60);
+}
+
+#[test]
+fn raise_beyond_the_balance_is_refused_but_all_in_is_allowed() {
+    let rules = RuleSet { starting_balance: 40, ..RuleSet::default() };
+    let mut engine = start(&["Anna", "Bert"], rules);
+    open_first_betting(&mut engine);
+
+    act(&mut engine, raise(0, 30)); // Anna heeft niets meer
+    // Bert moet €30 bijleggen en heeft precies €30: verhogen past niet, all-in wel
+    assert_eq!(reject(&mut engine, raise(1, 10)), ErrorCode::InsufficientBalance);
+    act(&mut engine, all_in(1));
+
+    let players = &game(&engine).players;
+    assert!(players.iter().all(|p| p.balance == 0));
+    assert_eq!(round(&engine).pot, 80);
+}
+
+#[test]
+fn calling_without_enough_money_goes_all_in() {
+    let rules = RuleSet { starting_balance: 40, ..RuleSet::default() };
+    let mut engine = start(&["Anna", "Bert"], rules);
+    act(&mut engine, GameAction::CollectInitialBets);
+    act(&mut engine, complete(Some(0)));
+    act(&mut engine, GameAction::StartNextRound);
+    // Ronde 2 (inleg €20): Anna €30, Bert €10, Bert is deler
+    open_first_betting(&mut engine);
+    act(&mut engine, check(1));
+    act(&mut engine, raise(0, 20));
+    act(&mut engine, call(1));
+
+    let bert = &game(&engine).players[1];
+    assert_eq!(bert.balance, 0);
+    assert_eq!(bert.current_bet, 0);
+    assert!(bert.is_all_in);
+    assert_eq!(round(&engine).pot, 40 + 20 + 10);
+}
+
+#[test]
+fn rejected_actions_leave_the_game_untouched() {
+    let mut engine = start_default(3);
+    open_first_betting(&mut engine);
+    act(&mut engine, raise(0, 20));
+    let before = serde_json::to_value(game(&engine)).unwrap();
+
+    reject(&mut engine, check(1));
+    reject(&mut engine, raise(1, 100));
+    reject(&mut engine, raise(0, 10));
+
+    assert_eq!(serde_json::to_value(game(&engine)).unwrap(), before);
+}
+
+// --- Passen ---
+
+#[test]
+fn folded_players_are_skipped_and_cannot_act_again() {
+    let mut engine = start_default(3);
+    open_first_betting(&mut engine);
+
+    act(&mut engine, raise(0, 10));
+    act(&mut engine, fold(1));
+    assert_eq!(round(&engine).current_player_index, 2);
+    act(&mut engine, raise(2, 10));
+
+    // Bert is overgeslagen, Anna is weer aan de beurt
+    assert_eq!(round(&engine).current_player_index, 0);
+    act(&mut engine, call(0));
+    assert_eq!(phase(&engine), BettingPhase::RevealingAnswers);
+
+    act(&mut engine, GameAction::AdvancePhase);
+    assert_eq!(reject(&mut engine, check(1)), ErrorCode::PlayerNotActive);
+}
+
+#[test]
+fn when_everyone_else_folds_the_betting_round_ends() {
+    let mut engine = start_default(3);
+    open_first_betting(&mut engine);
+
+    act(&mut engine, raise(0, 10));
+    act(&mut engine, fold(1));
+    act(&mut engine, fold(2));
+
+    assert_eq!(phase(&engine), BettingPhase::RevealingAnswers);
+    assert_eq!(round(&engine).pot, 40);
+}
+
+#[test]
+fn when_everyone_has_folded_the_pot_goes_back_to_who_put_it_in() {
+    let mut engine = start_default(3);
+    let ante = RuleSet::default().min_bet_for_round(1);
+    open_first_betting(&mut engine);
+    act(&mut engine, raise(0, 10));
+    act(&mut engine, fold(1));
+    act(&mut engine, fold(2));
+
+    // Via de tafel kan de laatste niet meer passen, maar een hervatte of gecorrigeerde state wel
+    let mut players = game(&engine).players.clone();
+    players[0].has_folded = true;
+    let result = round(&engine).settle(&players, None, TieRule::SplitPot).unwrap();
+
+    let refunds: Vec<(String, i32)> = result.winners.iter().map(|w| (w.player_id.clone(), w.amount)).collect();
+    assert_eq!(refunds, vec![(p(0), ante + 10), (p(1), ante), (p(2), ante)]);
+    assert_eq!(result.winners.iter().map(|w| w.amount).sum::<i32>(), round(&engine).pot);
+}
+
+#[test]
+fn folded_players_lose_their_stake_and_cannot_win_the_pot() {
+    let mut engine = start_default(3);
+    // Carla heeft de meeste goede antwoorden, maar gaat passen
+    for action in answer(2, 1, true).into_iter().chain(answer(2, 2, true)).chain(answer(1, 1, true)) {
+        act(&mut engine, action);
+    }
+    open_first_betting(&mut engine);
+    act(&mut engine, raise(0, 10));
+    act(&mut engine, call(1));
+    act(&mut engine, fold(2));
+    act(&mut engine, complete(None));
+
+    assert_eq!(balances(&engine), vec![730, 780, 740]);
+    let result = game(&engine).round_history.last().unwrap();
+    assert_eq!(result.winner_id, p(1));
+    assert!(!result.player_scores.iter().any(|(id, _)| *id == p(2)));
+}
+
+#[test]
+fn the_quizmaster_cannot_give_the_pot_to_a_player_who_is_out() {
+    let mut engine = start_default(3);
+    open_first_betting(&mut engine);
+    act(&mut engine, raise(0, 10));
+    act(&mut engine, call(1));
+    act(&mut engine, fold(2));
+
+    assert_eq!(reject(&mut engine, complete(Some(2))), ErrorCode::PlayerNotActive);
+    let mut players = game(&engine).players.clone();
+    players[1].is_active = false;
+    assert_eq!(round(&engine).settle(&players, Some(&p(1)), TieRule::SplitPot).unwrap_err().code, ErrorCode::PlayerNotActive);
+
+    // Er is niets veranderd, een geldige keuze gaat gewoon door
+    act(&mut engine, complete(Some(1)));
+    assert_eq!(game(&engine).round_history[0].winner_id, p(1));
+}
+
+#[test]
+fn folding_only_lasts_for_the_current_round() {
+    let mut engine = start_default(3);
+    open_first_betting(&mut engine);
+    act(&mut engine, fold(0));
+    act(&mut engine, complete(Some(1)));
+    act(&mut engine, GameAction::StartNextRound);
+
+    let anna = &game(&engine).players[0];
+    assert!(!anna.has_folded);
+    assert!(anna.is_active);
+}
+
+// --- Antwoorden schrijven ---
+
+fn write(player: usize, question_number: i32) -> GameAction {
+    GameAction::UpdateAnswer { player_id: p(player), question_number, image_path: format!("{}/q{}.png", p(player), question_number), drawing_path: String::new() }
+}
+
+#[test]
+fn answers_can_be_written_while_the_round_is_in_the_writing_phase() {
+    let mut engine = start_default(3);
+    act(&mut engine, write(0, 1));
+    act(&mut engine, write(0, 4));
+    act(&mut engine, write(0, 1)); // Overschrijven mag
+
+    assert_eq!(game(&engine).players[0].answers.len(), 2);
+}
+
+#[test]
+fn writing_is_refused_when_the_quizmaster_closed_it() {
+    let mut engine = start_default(3);
+    act(&mut engine, GameAction::ToggleWriting { enabled: false });
+    assert_eq!(reject(&mut engine, write(0, 1)), ErrorCode::WritingLocked);
+
+    act(&mut engine, GameAction::ToggleWriting { enabled: true });
+    act(&mut engine, GameAction::ToggleVideoMode);
+    assert_eq!(reject(&mut engine, write(0, 1)), ErrorCode::WritingLocked);
+}
+
+#[test]
+fn an_expiring_timer_closes_writing_without_taking_away_the_redo() {
+    let mut engine = start_default(3);
+    open_first_betting(&mut engine);
+    act(&mut engine, raise(0, 10));
+    let raised = balances(&engine);
+    act(&mut engine, GameAction::Undo);
+
+    act(&mut engine, GameAction::TimerExpired);
+    assert!(!game(&engine).writing_enabled);
+    act(&mut engine, GameAction::Redo);
+    assert_eq!(balances(&engine), raised);
+}
+
+#[test]
+fn writing_is_refused_once_the_round_has_moved_on() {
+    let mut engine = start_default(3);
+    act(&mut engine, GameAction::CollectInitialBets);
+    assert_eq!(reject(&mut engine, write(0, 1)), ErrorCode::WritingLocked);
+
+    act(&mut engine, complete(Some(0)));
+    assert_eq!(reject(&mut engine, write(0, 1)), ErrorCode::NoActiveRound);
+}
+
+#[test]
+fn only_existing_questions_can_be_answered() {
+    let mut engine = start_default(3);
+    assert_eq!(reject(&mut engine, write(0, 0)), ErrorCode::InvalidInput);
+    assert_eq!(reject(&mut engine, write(0, 5)), ErrorCode::InvalidInput);
+
+    let rules = RuleSet { questions_per_round: 2, ..RuleSet::default() };
+    let mut engine = start(&["Anna", "Bert"], rules);
+    act(&mut engine, write(0, 2));
+    assert_eq!(reject(&mut engine, write(0, 3)), ErrorCode::InvalidInput);
+}
+
+#[test]
+fn eliminated_players_cannot_write() {
+    let mut engine = start_default(3);
+    act(&mut engine, GameAction::TogglePlayerActive { player_id: p(2), is_active: false });
+
+    assert_eq!(reject(&mut engine, write(2, 1)), ErrorCode::PlayerNotActive);
+    assert_eq!(reject(&mut engine, write(5, 1)), ErrorCode::PlayerNotFound);
+    act(&mut engine, write(1, 1));
+}
+
+fn pen(x: f32, y: f32, t: u32) -> StrokePoint {
+    StrokePoint { x, y, pressure: 0.5, t }
+}
+
+#[test]
+fn only_the_path_of_the_pen_strokes_goes_into_the_state() {
+    let mut engine = start_default(3);
+    let update = |version: u32| GameAction::UpdateAnswer {
+        player_id: p(0),
+        question_number: 1,
+        image_path: format!("q1-{}.png", version),
+        drawing_path: format!("q1-{}.json", version),
+    };
+    act(&mut engine, update(1));
+    act(&mut engine, update(2));
+
+    let answers = &game(&engine).players[0].answers;
+    assert_eq!(answers.len(), 1);
+    assert_eq!((answers[0].image_path.as_str(), answers[0].drawing_path.as_str()), ("q1-2.png", "q1-2.json"));
+    // Ook het event log krijgt alleen de paden
+    assert!(!serde_json::to_string(&update(2)).unwrap().contains("strokes"));
+}
+
+#[test]
+fn broken_drawings_are_refused() {
+    let drawing = Drawing {
+        width: 600.0,
+        height: 150.0,
+        strokes: vec![
+            Stroke { points: vec![pen(10.0, 10.0, 0), pen(40.0, 80.0, 120)] },
+            Stroke { points: vec![pen(60.0, 20.0, 900)] },
+        ],
+    };
+    assert!(drawing.validate().is_ok());
+    assert_eq!(drawing.duration_ms(), 900);
+
+    let mut broken = Drawing { strokes: vec![Stroke { points: vec![pen(f32::NAN, 10.0, 0)] }], ..drawing.clone() };
+    assert_eq!(broken.validate().unwrap_err().code, ErrorCode::InvalidInput);
+
+    broken.strokes[0].points[0] = StrokePoint { pressure: 3.0, ..pen(10.0, 10.0, 0) };
+    assert_eq!(broken.validate().unwrap_err().code, ErrorCode::InvalidInput);
+
+    broken.strokes[0].points = vec![pen(1.0, 1.0, 0); MAX_DRAWING_POINTS + 1];
+    assert_eq!(broken.validate().unwrap_err().code, ErrorCode::InvalidInput);
+
+    // Gewist canvas: geen streken, altijd goed
+    broken.strokes.clear();
+    assert!(broken.validate().is_ok());
+}
+
+// --- Eliminatie en einde van het spel ---
+
+/// Speel rondes tot en met `last_round` waarin steeds `winner` de pot krijgt
+fn play_rounds(engine: &mut Engine, last_round: i32, winner: usize) {
+    loop {
+        act(engine, GameAction::CollectInitialBets);
+        act(engine, complete(Some(winner)));
+        if game(engine).round_number >= last_round || game(engine).is_finished {
+            break;
+        }
+        act(engine, GameAction::StartNextRound);
+    }
+}
+
+#[test]
+fn after_round_four_the_poorest_player_is_eliminated() {
+    let mut engine = start_default(4);
+    act(&mut engine, GameAction::CollectInitialBets);
+    act(&mut engine, GameAction::AdvancePhase);
+    act(&mut engine, raise(0, 50));
+    act(&mut engine, call(1));
+    act(&mut engine, fold(2));
+    act(&mut engine, fold(3));
+    act(&mut engine, complete(Some(0)));
+    act(&mut engine, GameAction::StartNextRound);
+    // Bert staat nu het laagst: €690 tegen €740 voor Carla en Daan
+    play_rounds(&mut engine, 3, 0);
+    let before_round_four: Vec<bool> = game(&engine).players.iter().map(|p| p.is_active).collect();
+    assert_eq!(before_round_four, vec![true; 4]);
+
+    act(&mut engine, GameAction::StartNextRound);
+    play_rounds(&mut engine, 4, 0);
+
+    let active: Vec<bool> = game(&engine).players.iter().map(|p| p.is_active).collect();
+    assert_eq!(active, vec![true, false, true, true]);
+    assert!(!game(&engine).is_finished);
+}
+
+#[test]
+fn elimination_round_comes_from_the_rule_set() {
+    let rules = RuleSet { elimination_after_round: Some(1), ..RuleSet::default() };
+    let mut engine = start(&["Anna", "Bert", "Carla"], rules);
+    act(&mut engine, GameAction::CollectInitialBets);
+    act(&mut engine, GameAction::AddBetsToPot);
+    act(&mut engine, complete(Some(2)));
+
+    assert_eq!(game(&engine).players.iter().filter(|p| p.is_active).count(), 2);
+
+    let rules = RuleSet { elimination_after_round: None, ..RuleSet::default() };
+    let mut engine = start(&["Anna", "Bert", "Carla"], rules);
+    play_rounds(&mut engine, 7, 2);
+    assert!(game(&engine).players.iter().all(|p| p.is_active));
+}
+
+#[test]
+fn only_one_manual_elimination_after_the_elimination_round_of_the_rule_set() {
+    let eliminate = |seat: usize| GameAction::TogglePlayerActive { player_id: p(seat), is_active: false };
+    let active = |engine: &Engine| -> Vec<bool> { game(engine).players.iter().map(|p| p.is_active).collect() };
+
+    // Na de eliminatieronde (hier ronde 2) brengt een tweede eliminatie de eerste terug
+    let rules = RuleSet { elimination_after_round: Some(2), ..RuleSet::default() };
+    let mut engine = start(&["Anna", "Bert", "Carla", "Dirk"], rules);
+    act(&mut engine, GameAction::SetRoundNumber { round_num: 3 });
+    act(&mut engine, eliminate(1));
+    act(&mut engine, eliminate(2));
+    assert_eq!(active(&engine), vec![true, true, false, true]);
+
+    // Met de standaardregels (na ronde 4) is ronde 3 nog vrij
+    let mut engine = start_default(4);
+    act(&mut engine, GameAction::SetRoundNumber { round_num: 3 });
+    act(&mut engine, eliminate(1));
+    act(&mut engine, eliminate(2));
+    assert_eq!(active(&engine), vec![true, false, false, true]);
+}
+
+#[test]
+fn no_elimination_when_too_few_players_are_left() {
+    let mut engine = start_default(2);
+    play_rounds(&mut engine, 4, 0);
+    assert!(game(&engine).players.iter().all(|p| p.is_active));
+}
+
+#[test]
+fn players_without_money_are_out() {
+    let rules = RuleSet { starting_balance: 10, ..RuleSet::default() };
+    let mut engine = start(&["Anna", "Bert", "Carla"], rules);
+    act(&mut engine, GameAction::CollectInitialBets);
+    act(&mut engine, complete(Some(0)));
+
+    let players = &game(&engine).players;
+    assert_eq!(players[0].balance, 30);
+    assert!(!players[1].is_active && !players[2].is_active);
+    // Eén speler met geld over: spel afgelopen
+    assert!(game(&engine).is_finished);
+}
+
+#[test]
+fn the_game_finishes_after_seven_rounds() {
+    let mut engine = start_default(3);
+    play_rounds(&mut engine, 6, 1);
+    assert!(!game(&engine).is_finished);
+
+    act(&mut engine, GameAction::StartNextRound);
+    assert_eq!(round(&engine).min_bet, 80);
+    play_rounds(&mut engine, 7, 1);
+
+    assert_eq!(game(&engine).round_number, 7);
+    assert!(game(&engine).is_finished);
+    assert_eq!(reject(&mut engine, GameAction::StartNextRound), ErrorCode::GameFinished);
+}
+
+#[test]
+fn round_count_comes_from_the_rule_set() {
+    let rules = RuleSet { rounds: 3, elimination_after_round: None, ..RuleSet::default() };
+    let mut engine = start(&["Anna", "Bert"], rules);
+    play_rounds(&mut engine, 3, 0);
+
+    assert!(game(&engine).is_finished);
+    assert_eq!(reject(&mut engine, GameAction::SetRoundNumber { round_num: 4 }), ErrorCode::InvalidInput);
+}
+
+#[test]
+fn minimum_bet_follows_the_round_and_repeats_the_last_value() {
+    let rules = RuleSet::default();
+    let minimums: Vec<i32> = (1..=7).map(|round| rules.min_bet_for_round(round)).collect();
+    assert_eq!(minimums, vec![10, 20, 40, 80, 80, 80, 80]);
+}
+
+// --- Gelijke stand ---
+
+fn tied_round(tie_rule: TieRule) -> Engine {
+    let mut engine = start(&["Anna", "Bert", "Carla"], RuleSet { tie_rule, ..RuleSet::default() });
+    for action in answer(0, 1, true).into_iter().chain(answer(1, 1, true)) {
+        act(&mut engine, action);
+    }
+    act(&mut engine, GameAction::CollectInitialBets);
+    engine
+}
+
+#[test]
+fn a_tie_needs_the_quizmaster_unless_the_pot_is_split() {
+    for tie_rule in [TieRule::QuizmasterChoice, TieRule::SuddenDeath] {
+        let mut engine = tied_round(tie_rule);
+        assert_eq!(reject(&mut engine, complete(None)), ErrorCode::TieUnresolved);
+        act(&mut engine, complete(Some(1)));
+        assert_eq!(balances(&engine), vec![740, 770, 740]);
+    }
+
+    let mut engine = tied_round(TieRule::SplitPot);
+    act(&mut engine, complete(None));
+    assert_eq!(balances(&engine), vec![755, 755, 740]);
+    assert_eq!(game(&engine).round_history[0].winners.len(), 2);
+}
+
+// --- Rondegeschiedenis ---
+
+fn write_in_round(player: usize, question_number: i32, round_number: i32) -> GameAction {
+    GameAction::UpdateAnswer {
+        player_id: p(player),
+        question_number,
+        image_path: format!("round-{}/{}/q{}.png", round_number, p(player), question_number),
+        drawing_path: String::new(),
+    }
+}
+
+#[test]
+fn every_round_keeps_its_answers_in_the_history() {
+    let mut engine = start_default(3);
+    act(&mut engine, write_in_round(0, 1, 1));
+    act(&mut engine, GameAction::ApproveAnswer { player_id: p(0), question_number: 1, is_correct: true });
+    act(&mut engine, write_in_round(1, 2, 1));
+    open_first_betting(&mut engine);
+    act(&mut engine, fold(0));
+    act(&mut engine, complete(Some(1)));
+    act(&mut engine, GameAction::StartNextRound);
+
+    act(&mut engine, write_in_round(1, 1, 2));
+    act(&mut engine, GameAction::CollectInitialBets);
+    act(&mut engine, complete(Some(1)));
+    act(&mut engine, GameAction::StartNextRound);
+
+    // De lopende ronde begint leeg, het archief heeft alles
+    assert!(game(&engine).players.iter().all(|p| p.answers.is_empty()));
+    let history = &game(&engine).round_history;
+    assert_eq!(history.iter().map(|r| r.round_number).collect::<Vec<_>>(), vec![1, 2]);
+
+    let first = &history[0].answers;
+    assert_eq!(first.len(), 3);
+    assert_eq!(first[0].answers[0].is_correct, Some(true));
+    assert_eq!(first[0].answers[0].timestamp, TIMESTAMP);
+    assert_eq!(first[1].answers[0].question_number, 2);
+    assert_eq!(first[1].answers[0].is_correct, None);
+    assert!(first[0].has_folded);
+    assert!(!first[2].has_folded && first[2].answers.is_empty());
+    // Het archief verwijst naar de versie die bij het afsluiten is vastgezet
+    assert_eq!(history[1].answers[1].answers[0].image_path, "round-2/player_1/q1-20250101200000.png");
+
+    // Tekeningen uit het archief blijven vindbaar, bijvoorbeeld om opnieuw te renderen
+    assert_eq!(game(&engine).find_answer("round-1/player_1/q2-20250101200000.png").unwrap().question_number, 2);
+    assert!(game(&engine).find_answer("round-1/player_1/q2.png").is_none());
+}
+
+#[test]
+fn completing_a_round_freezes_a_version_of_every_answer() {
+    let mut engine = start_default(3);
+    act(&mut engine, GameAction::UpdateAnswer {
+        player_id: p(0),
+        question_number: 1,
+        image_path: "game_1/round-1/player_0/q1.png".to_string(),
+        drawing_path: "game_1/round-1/player_0/q1.json".to_string(),
+    });
+    act(&mut engine, write_in_round(1, 1, 1));
+    act(&mut engine, GameAction::CollectInitialBets);
+    engine.apply(&complete(Some(0)), "2025-01-01T21:15:30.5+00:00").unwrap();
+
+    let frozen = &game(&engine).round_history[0].answers[0].answers[0];
+    assert_eq!(frozen.image_path, "game_1/round-1/player_0/q1-202501012115305.png");
+    assert_eq!(frozen.drawing_path, "game_1/round-1/player_0/q1-202501012115305.json");
+    // Zonder streken blijft het pad leeg
+    let without_strokes = &game(&engine).round_history[0].answers[1].answers[0];
+    assert_eq!((without_strokes.image_path.as_str(), without_strokes.drawing_path.as_str()), ("round-1/player_1/q1-202501012115305.png", ""));
+}
+
+#[test]
+fn undoing_a_round_result_takes_its_answers_out_of_the_history() {
+    let mut engine = start_default(3);
+    act(&mut engine, write_in_round(0, 1, 1));
+    act(&mut engine, GameAction::CollectInitialBets);
+    act(&mut engine, complete(Some(0)));
+    act(&mut engine, GameAction::Undo);
+
+    assert!(game(&engine).round_history.is_empty());
+    assert_eq!(game(&engine).players[0].answers.len(), 1);
+    // Weer het live bestand, zodat de tablet er verder in kan schrijven
+    assert_eq!(game(&engine).players[0].answers[0].image_path, "round-1/player_0/q1.png");
+}
+
+#[test]
+fn undo_and_redo_return_the_label_of_the_action() {
+    let mut engine = start_default(2);
+    open_first_betting(&mut engine);
+    act(&mut engine, raise(0, 10));
+
+    let undone = engine.apply(&GameAction::Undo, TIMESTAMP).unwrap();
+    let redone = engine.apply(&GameAction::Redo, TIMESTAMP).unwrap();
+    assert_eq!(undone.label, redone.label);
+    assert!(undone.label.unwrap().contains("player_0"));
+    assert_eq!(engine.apply(&raise(1, 10), TIMESTAMP).unwrap().label, None);
+}
+
+#[test]
+fn undo_and_redo_never_restore_a_state_where_the_money_is_off() {
+    let mut engine = start_default(2);
+    open_first_betting(&mut engine);
+    act(&mut engine, raise(0, 10));
+    // Buiten de engine om geknoeid, zoals een bug in een wijziging zonder undo stap zou doen
+    engine.game_mut().unwrap().players[1].balance += 50;
+    act(&mut engine, GameAction::Undo);
+    let restored = balances(&engine);
+    let steps = |engine: &Engine| {
+        let overview = engine.history().overview();
+        (overview.undo.len(), overview.redo.len())
+    };
+    let before = steps(&engine);
+
+    assert_eq!(reject(&mut engine, GameAction::Redo), ErrorCode::MoneyMismatch);
+    assert_eq!(balances(&engine), restored);
+    assert_eq!(steps(&engine), before);
+    assert_eq!(before.1, 1);
+}
+
+#[test]
+fn the_history_keeps_the_min_bet_and_balances_after_payout() {
+    let mut engine = start_default(3);
+    open_first_betting(&mut engine);
+    act(&mut engine, fold(0));
+    act(&mut engine, complete(Some(1)));
+
+    let result = &game(&engine).round_history[0];
+    let expected: Vec<(String, i32)> = game(&engine).players.iter().map(|p| (p.id.clone(), p.balance)).collect();
+    assert_eq!(result.min_bet, RuleSet::default().min_bet_for_round(1));
+    assert_eq!(result.balances, expected);
+    assert_eq!(result.balances.iter().map(|(_, b)| b).sum::<i32>(), 3 * RuleSet::default().starting_balance);
+}
+
+// --- Draaiboek ---
+
+fn episode(players: usize) -> Episode {
+    let rules = RuleSet::default();
+    Episode {
+        id: "aflevering_1".to_string(),
+        title: "Aflevering 1".to_string(),
+        recording_date: None,
+        player_names: (1..=players).map(|seat| format!("Speler {}", seat)).collect(),
+        rule_set: None,
+        rounds: (1..=rules.rounds).map(|round_number| EpisodeRound {
+            round_number,
+            questions: (1..=rules.questions_per_round).map(|number| EpisodeQuestion {
+                question: Question {
+                    id: 0,
+                    text: format!("Vraag {}.{}", round_number, number),
+                    answer: "Ja".to_string(),
+                    accepted_variants: Vec::new(),
+                    category: None,
+                    difficulty: None,
+                    source: None,
+                },
+                notes: None,
+            }).collect(),
+        }).collect(),
+    }
+}
+
+#[test]
+fn loading_an_episode_takes_no_undo_step_and_leaves_the_timeline_alone() {
+    let mut engine = start_default(3);
+    let timeline = game(&engine).balance_timeline.clone();
+    act(&mut engine, GameAction::LoadEpisode { episode: episode(3) });
+
+    assert_eq!(round(&engine).questions[0].text, "Vraag 1.1");
+    assert_eq!(game(&engine).balance_timeline, timeline);
+    // Het draaiboek hoort bij het starten van het spel; undo haalt het er niet los van af
+    assert_eq!(reject(&mut engine, GameAction::Undo), ErrorCode::NothingToUndo);
+    assert_eq!(reject(&mut engine, GameAction::LoadEpisode { episode: episode(2) }), ErrorCode::InvalidInput);
+}
+
+// --- Saldoverloop ---
+
+fn timeline(engine: &Engine, player: usize) -> Vec<(BalanceReason, i32, i32)> {
+    game(engine).balance_timelines(Some(&p(player))).unwrap()[0].changes.iter()
+        .map(|c| (c.reason, c.amount, c.balance))
+        .collect()
+}
+
+#[test]
+fn every_balance_change_is_on_the_timeline_with_its_reason() {
+    let mut engine = start_default(3);
+    let start = RuleSet::default().starting_balance;
+    let ante = RuleSet::default().min_bet_for_round(1);
+    open_first_betting(&mut engine);
+    act(&mut engine, raise(0, 10));
+    act(&mut engine, fold(1));
+    act(&mut engine, fold(2));
+    act(&mut engine, complete(Some(0)));
+
+    let pot = game(&engine).round_history[0].pot_amount;
+    assert_eq!(timeline(&engine, 0), vec![
+        (BalanceReason::Start, start, start),
+        (BalanceReason::Ante, -ante, start - ante),
+        (BalanceReason::Bet, -10, start - ante - 10),
+        (BalanceReason::PotWin, pot, start - ante - 10 + pot),
+    ]);
+    assert_eq!(timeline(&engine, 1), vec![
+        (BalanceReason::Start, start, start),
+        (BalanceReason::Ante, -ante, start - ante),
+    ]);
+    let change = &game(&engine).balance_timeline.last().unwrap();
+    assert_eq!((change.round_number, change.timestamp.as_str()), (1, TIMESTAMP));
+}
+
+#[test]
+fn undo_and_reset_show_up_on_the_timeline_instead_of_erasing_it() {
+    let mut engine = start_default(2);
+    let start = RuleSet::default().starting_balance;
+    open_first_betting(&mut engine);
+    act(&mut engine, raise(0, 10));
+    act(&mut engine, GameAction::Undo);
+
+    let reasons: Vec<_> = timeline(&engine, 0).iter().map(|(reason, amount, _)| (*reason, *amount)).collect();
+    assert_eq!(&reasons[2..], &[(BalanceReason::Bet, -10), (BalanceReason::Correction, 10)]);
+
+    act(&mut engine, GameAction::ResetGame);
+    let (reason, _, balance) = *timeline(&engine, 0).last().unwrap();
+    assert_eq!((reason, balance), (BalanceReason::Reset, start));
+    assert_eq!(game(&engine).balance_timelines(None).unwrap().len(), 2);
+    assert_eq!(game(&engine).balance_timelines(Some("player_9")).unwrap_err().code, ErrorCode::PlayerNotFound);
+}
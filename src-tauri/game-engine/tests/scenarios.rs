@@ -0,0 +1,120 @@
+//! Opgenomen spellen als fixtures: spelers, regels en acties in, verwachte eindstand uit.
+//! Elk bestand in `tests/fixtures/` wordt via de `Engine` afgespeeld, precies zoals de app dat doet.
+//! Acties staan in hetzelfde formaat als in het event log (`{ "type": "place_bet", ... }`).
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use game_engine::test_support::*;
+use game_engine::{GameAction, RuleSet};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Scenario {
+    description: String,
+    players: Vec<String>,
+    #[serde(default)]
+    rules: RuleSet,
+    actions: Vec<GameAction>,
+    expected: Expected,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expected {
+    balances: BTreeMap<String, i32>, // Eindsaldo per spelersnaam
+    round_number: i32,
+    is_finished: bool,
+    #[serde(default)]
+    eliminated: Vec<String>, // Spelers die niet meer actief zijn
+}
+
+fn fixtures() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let mut paths: Vec<PathBuf> = fs::read_dir(&dir)
+        .unwrap_or_else(|e| panic!("Kan {} niet lezen: {}", dir.display(), e))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+/// Speel één scenario af; geeft een beschrijving van wat er afwijkt terug
+fn run(scenario: &Scenario) -> Result<(), String> {
+    let names: Vec<&str> = scenario.players.iter().map(String::as_str).collect();
+    let mut engine = start(&names, scenario.rules.clone());
+    for (index, action) in scenario.actions.iter().enumerate() {
+        engine.apply(action, TIMESTAMP)
+            .map_err(|e| format!("actie {} ({:?}) mislukt: {:?}: {}", index + 1, action, e.code, e))?;
+    }
+
+    let game = game(&engine);
+    let balances: BTreeMap<String, i32> = game.players.iter()
+        .map(|p| (p.name.clone(), p.balance))
+        .collect();
+    let eliminated: Vec<String> = game.players.iter()
+        .filter(|p| !p.is_active)
+        .map(|p| p.name.clone())
+        .collect();
+
+    let expected = &scenario.expected;
+    let mut problems = Vec::new();
+    if balances != expected.balances {
+        problems.push(format!("saldo's {:?}, verwacht {:?}", balances, expected.balances));
+    }
+    if game.round_number != expected.round_number {
+        problems.push(format!("ronde {}, verwacht {}", game.round_number, expected.round_number));
+    }
+    if game.is_finished != expected.is_finished {
+        problems.push(format!("is_finished {}, verwacht {}", game.is_finished, expected.is_finished));
+    }
+    if eliminated != expected.eliminated {
+        problems.push(format!("uitgeschakeld {:?}, verwacht {:?}", eliminated, expected.eliminated));
+    }
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(problems.join("; "))
+    }
+}
+
+#[test]
+fn recorded_games_end_with_the_expected_balances() {
+    let paths = fixtures();
+    assert!(!paths.is_empty(), "Geen fixtures gevonden");
+
+    let mut failures = Vec::new();
+    for path in &paths {
+        let data = fs::read_to_string(path).unwrap();
+        let scenario: Scenario = serde_json::from_str(&data)
+            .unwrap_or_else(|e| panic!("{} is geen geldig scenario: {}", path.display(), e));
+        if let Err(problem) = run(&scenario) {
+            failures.push(format!("{} ({}): {}", path.display(), scenario.description, problem));
+        }
+    }
+
+    assert!(failures.is_empty(), "Scenario's wijken af:\n{}", failures.join("\n"));
+}
+
+#[test]
+fn recorded_games_keep_all_money_on_the_table() {
+    for path in fixtures() {
+        let scenario: Scenario = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        let total = scenario.rules.starting_balance * scenario.players.len() as i32;
+        let names: Vec<&str> = scenario.players.iter().map(String::as_str).collect();
+        let mut engine = start(&names, scenario.rules.clone());
+
+        // Na elke stap, niet alleen aan het eind: ook een tussentijds verkeerde pot moet opvallen
+        for (index, action) in scenario.actions.iter().enumerate() {
+            if let Err(e) = engine.apply(action, TIMESTAMP) {
+                panic!("{}: actie {} ({:?}) mislukt: {}", path.display(), index + 1, action, e);
+            }
+            assert_eq!(game(&engine).money_on_table(), total, "{}: na actie {} ({:?})", path.display(), index + 1, action);
+        }
+        assert_eq!(scenario.expected.balances.values().sum::<i32>(), total, "{}: verwachte eindsaldo's tellen niet op tot het startgeld", path.display());
+    }
+}
@@ -0,0 +1,294 @@
+//! Spel besturen vanaf de terminal, zonder GUI: voor repetities op machines zonder scherm,
+//! het naspelen van bugreports en gescripte regressiescenario's.
+//!
+//! Gebruik:
+//!   mhmot-cli                        interactief, commando's via stdin (`help` voor de lijst)
+//!   mhmot-cli scenario.txt           script uitvoeren; stopt met exit code 1 bij de eerste fout
+//!   mhmot-cli --replay game_1.jsonl  event log uit de app afspelen en de eindstand tonen
+
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+
+use game_engine::{Engine, GameAction, GameError, GameEvent, GameState, RuleSet};
+
+const HELP: &str = "\
+Commando's (spelers via id, naam of stoelnummer 1..n):
+  rules <bestand.json>            regelset voor het volgende spel
+  start <naam> <naam> ...         nieuw spel
+  collect                         inleg verzamelen
+  pot                             inzetten bij de pot
+  advance                         naar de volgende fase
+  bet <speler> <bedrag>           verhogen met bedrag
+  check <speler> | call <speler> | allin <speler> | fold <speler>
+  answer <speler> <vraag> [pad]   antwoord invullen (pad van de tekening)
+  approve <speler> <vraag> goed|fout
+  complete [speler]               ronde afronden (zonder speler: op goede antwoorden)
+  next                            volgende ronde
+  round <nummer>                  naar ronde springen
+  undo | redo
+  status | leaderboard | json
+  quit";
+
+/// Wat één regel invoer oplevert
+enum Command {
+    Action(GameAction),
+    Rules(String),
+    Status,
+    Leaderboard,
+    Json,
+    Help,
+    Quit,
+}
+
+struct Cli {
+    engine: Engine,
+    rules: RuleSet,
+}
+
+impl Cli {
+    fn new() -> Self {
+        Self {
+            engine: Engine::new(),
+            rules: RuleSet::default(),
+        }
+    }
+
+    fn game(&self) -> Result<&GameState, GameError> {
+        self.engine.game().ok_or_else(GameError::no_active_game)
+    }
+
+    /// Speler opzoeken op id, naam (zonder hoofdletters) of stoelnummer
+    fn player_id(&self, arg: &str) -> Result<String, GameError> {
+        let players = &self.game()?.players;
+        let by_seat = arg.parse::<usize>().ok()
+            .and_then(|seat| seat.checked_sub(1))
+            .and_then(|index| players.get(index));
+        by_seat
+            .or_else(|| players.iter().find(|p| p.id == arg || p.name.eq_ignore_ascii_case(arg)))
+            .map(|p| p.id.clone())
+            .ok_or_else(|| GameError::player_not_found(arg))
+    }
+
+    fn parse(&self, line: &str) -> Result<Command, GameError> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let arg = |index: usize| -> Result<&str, GameError> {
+            words.get(index).copied()
+                .ok_or_else(|| GameError::invalid_input(format!("'{}' mist een argument, zie help", words[0])))
+        };
+        let number = |index: usize| -> Result<i32, GameError> {
+            let value = arg(index)?;
+            value.parse().map_err(|_| GameError::invalid_input(format!("'{}' is geen getal", value)))
+        };
+
+        let action = match words[0] {
+            "rules" => return Ok(Command::Rules(arg(1)?.to_string())),
+            "status" => return Ok(Command::Status),
+            "leaderboard" | "stand" => return Ok(Command::Leaderboard),
+            "json" => return Ok(Command::Json),
+            "help" => return Ok(Command::Help),
+            "quit" | "exit" => return Ok(Command::Quit),
+            "start" => {
+                if words.len() < 2 {
+                    return Err(GameError::invalid_input("Geef minstens één spelersnaam op"));
+                }
+                let now = chrono::Utc::now();
+                GameAction::StartGame {
+                    game_id: format!("cli_{}", now.timestamp()),
+                    created_at: now.to_rfc3339(),
+                    player_names: words[1..].iter().map(|w| w.to_string()).collect(),
+                    rules: self.rules.clone(),
+                }
+            }
+            "collect" => GameAction::CollectInitialBets,
+            "pot" => GameAction::AddBetsToPot,
+            "advance" => GameAction::AdvancePhase,
+            "bet" => GameAction::PlaceBet { player_id: self.player_id(arg(1)?)?, amount: number(2)? },
+            "check" => GameAction::Check { player_id: self.player_id(arg(1)?)? },
+            "call" => GameAction::Call { player_id: self.player_id(arg(1)?)? },
+            "allin" => GameAction::AllIn { player_id: self.player_id(arg(1)?)? },
+            "fold" => GameAction::PlayerFold { player_id: self.player_id(arg(1)?)? },
+            "answer" => GameAction::UpdateAnswer {
+                player_id: self.player_id(arg(1)?)?,
+                question_number: number(2)?,
+                image_path: words.get(3).map(|path| path.to_string()).unwrap_or_default(),
+                drawing_path: String::new(),
+            },
+            "approve" => GameAction::ApproveAnswer {
+                player_id: self.player_id(arg(1)?)?,
+                question_number: number(2)?,
+                is_correct: match arg(3)? {
+                    "goed" | "ja" | "true" => true,
+                    "fout" | "nee" | "false" => false,
+                    other => return Err(GameError::invalid_input(format!("Gebruik goed of fout, niet '{}'", other))),
+                },
+            },
+            "complete" => GameAction::CompleteRound {
+                winner_id: words.get(1).map(|w| self.player_id(w)).transpose()?,
+            },
+            "next" => GameAction::StartNextRound,
+            "round" => GameAction::SetRoundNumber { round_num: number(1)? },
+            "undo" => GameAction::Undo,
+            "redo" => GameAction::Redo,
+            other => return Err(GameError::invalid_input(format!("Onbekend commando '{}', zie help", other))),
+        };
+        Ok(Command::Action(action))
+    }
+
+    /// Voer één regel uit. `Ok(false)` betekent stoppen.
+    fn execute(&mut self, line: &str) -> Result<bool, GameError> {
+        match self.parse(line)? {
+            Command::Action(action) => {
+                let applied = self.engine.apply(&action, &chrono::Utc::now().to_rfc3339())?;
+                if let Some(label) = applied.label {
+                    println!("{}: {}", if action == GameAction::Undo { "Teruggedraaid" } else { "Opnieuw gedaan" }, label);
+                }
+                print_status(self.game()?);
+            }
+            Command::Rules(path) => {
+                let data = fs::read(&path)
+                    .map_err(|e| GameError::storage(format!("Kan {} niet lezen: {}", path, e)))?;
+                let rules: RuleSet = serde_json::from_slice(&data)
+                    .map_err(|e| GameError::invalid_input(format!("{} is geen geldige regelset: {}", path, e)))?;
+                rules.validate()?;
+                println!("Regelset '{}' geladen", rules.name);
+                self.rules = rules;
+            }
+            Command::Status => print_status(self.game()?),
+            Command::Leaderboard => print_leaderboard(self.game()?),
+            Command::Json => println!("{}", serde_json::to_string_pretty(self.game()?).unwrap_or_default()),
+            Command::Help => println!("{}", HELP),
+            Command::Quit => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+fn print_status(game: &GameState) {
+    match &game.current_round {
+        Some(round) => println!(
+            "Ronde {}/{} · {:?} · pot €{} · hoogste inzet €{}",
+            game.round_number, game.rules.rounds, round.phase, round.pot, round.highest_bet
+        ),
+        None if game.is_finished => println!("Ronde {}/{} afgerond", game.round_number, game.rules.rounds),
+        None => println!("Ronde {}/{} afgerond, volgende ronde met 'next'", game.round_number, game.rules.rounds),
+    }
+    let current = game.current_round.as_ref()
+        .filter(|r| r.is_betting_phase())
+        .map(|r| r.current_player_index);
+    for (index, player) in game.players.iter().enumerate() {
+        let mut flags = Vec::new();
+        if current == Some(index) { flags.push("aan de beurt"); }
+        if player.has_folded { flags.push("gepast"); }
+        if player.is_all_in { flags.push("all-in"); }
+        if !player.is_active { flags.push("uitgeschakeld"); }
+        println!(
+            "  {}. {:<12} €{:>5}  inzet €{:<4} ✓{}  {}",
+            index + 1, player.name, player.balance, player.current_bet, player.count_correct_answers(), flags.join(", ")
+        );
+    }
+    if game.is_finished {
+        println!("Spel is afgelopen");
+    }
+}
+
+fn print_leaderboard(game: &GameState) {
+    println!("Stand na ronde {}:", game.round_number);
+    for (rank, (player, balance)) in game.get_leaderboard().into_iter().enumerate() {
+        println!("  {}. {:<12} €{}", rank + 1, player.name, balance);
+    }
+}
+
+/// Event log (`events/<game_id>.jsonl` uit de app data map) afspelen
+fn replay(path: &str) -> Result<GameState, GameError> {
+    let data = fs::read_to_string(path)
+        .map_err(|e| GameError::storage(format!("Kan {} niet lezen: {}", path, e)))?;
+    let events = data.lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(index, line)| serde_json::from_str::<GameEvent>(line)
+            .map_err(|e| GameError::invalid_input(format!("Regel {}: {}", index + 1, e))))
+        .collect::<Result<Vec<_>, _>>()?;
+    game_engine::events::replay(&events)
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        ["--replay", path] => match replay(path) {
+            Ok(game) => {
+                print_status(&game);
+                print_leaderboard(&game);
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("❌ {:?}: {}", e.code, e);
+                ExitCode::FAILURE
+            }
+        },
+        [script] if !script.starts_with('-') => run_script(script),
+        [] => run_interactive(),
+        _ => {
+            eprintln!("Gebruik: mhmot-cli [script.txt | --replay events.jsonl]");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn is_command(line: &str) -> bool {
+    let line = line.trim();
+    !line.is_empty() && !line.starts_with('#')
+}
+
+/// Script: één commando per regel, `#` voor commentaar. De eerste fout stopt het script.
+fn run_script(path: &str) -> ExitCode {
+    let script = match fs::read_to_string(path) {
+        Ok(script) => script,
+        Err(e) => {
+            eprintln!("❌ Kan {} niet lezen: {}", path, e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut cli = Cli::new();
+    for (line_number, line) in script.lines().enumerate() {
+        if !is_command(line) {
+            continue;
+        }
+        println!("> {}", line.trim());
+        match cli.execute(line) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(e) => {
+                eprintln!("❌ {}:{}: {:?}: {}", path, line_number + 1, e.code, e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn run_interactive() -> ExitCode {
+    println!("Met het Mes op Tafel - terminal. Typ 'help' voor de commando's.");
+    let mut cli = Cli::new();
+    let stdin = io::stdin();
+    loop {
+        print!("mhmot> ");
+        let _ = io::stdout().flush();
+
+        let mut line = String::new();
+        match stdin.lock().read_line(&mut line) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {}
+        }
+        if !is_command(&line) {
+            continue;
+        }
+        match cli.execute(&line) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(e) => println!("❌ {:?}: {}", e.code, e),
+        }
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,225 @@
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
+use std::fs;
+use std::io::{ErrorKind, Write};
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::game::{Answer, Drawing, ErrorCode, GameError};
+
+const PNG_DATA_URL_PREFIX: &str = "data:image/png;base64,";
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// Tekeningen van de tablets als PNG bestanden in `answers/<game_id>/round-<n>/<player_id>/q<n>.png`.
+/// De pennenstreken staan als JSON ernaast (`q<n>.json`). Bij `CompleteRound` krijgt de afgesloten ronde
+/// een vaste versie (`q<n>-<versie>.png`), zie `Player::freeze_answers`. De `GameState` bewaart alleen de paden
+/// daarbinnen (`Answer.image_path`, `Answer.drawing_path`); clients halen de afbeelding op via
+/// `/api/answers/<pad>` en de streken via `/api/drawings/<pad>`. Zo blijft elke state update klein.
+pub struct AnswerImageStore {
+    dir: PathBuf,
+}
+
+impl AnswerImageStore {
+    pub fn new(data_dir: PathBuf) -> Self {
+        Self { dir: data_dir.join("answers") }
+    }
+
+    /// Pad van de tekening voor één vraag zolang er geschreven wordt; elke sync overschrijft hetzelfde bestand
+    pub fn path_for(game_id: &str, round_number: i32, player_id: &str, question_number: i32) -> String {
+        format!("{}/round-{}/{}/q{}.png", game_id, round_number, player_id, question_number)
+    }
+
+    /// Pad van de pennenstreken bij een tekening: hetzelfde pad met `.json`
+    pub fn drawing_path_for(image_path: &str) -> String {
+        Path::new(image_path).with_extension("json").to_string_lossy().to_string()
+    }
+
+    pub fn save(&self, image_path: &str, png: &[u8]) -> Result<(), GameError> {
+        self.stage_image(image_path, png)?.commit()
+    }
+
+    /// Tekening naast zijn pad wegschrijven zonder het huidige bestand te vervangen, zie `StagedFile`
+    pub fn stage_image(&self, image_path: &str, png: &[u8]) -> Result<StagedFile, GameError> {
+        self.stage(self.resolve(image_path, "png")?, png)
+    }
+
+    pub fn read(&self, image_path: &str) -> Result<Vec<u8>, GameError> {
+        self.read_file(self.resolve(image_path, "png")?, image_path)
+    }
+
+    pub fn stage_drawing(&self, drawing_path: &str, drawing: &Drawing) -> Result<StagedFile, GameError> {
+        let json = serde_json::to_vec(drawing)
+            .map_err(|e| GameError::internal(format!("Kan pennenstreken niet serialiseren: {}", e)))?;
+        self.stage(self.resolve(drawing_path, "json")?, &json)
+    }
+
+    /// Tekening en streken van een antwoord kopiëren naar het pad dat de engine ze bij het
+    /// afsluiten van de ronde gaf. Oude versies blijven staan, een undo kan er weer naar verwijzen.
+    pub fn freeze(&self, live: &Answer, frozen: &Answer) -> Result<(), GameError> {
+        for (from, to, extension) in [(&live.image_path, &frozen.image_path, "png"), (&live.drawing_path, &frozen.drawing_path, "json")] {
+            if from.is_empty() || from == to {
+                continue;
+            }
+            let data = self.read_file(self.resolve(from, extension)?, from)?;
+            self.stage(self.resolve(to, extension)?, &data)?.commit()?;
+        }
+        Ok(())
+    }
+
+    pub fn read_drawing(&self, drawing_path: &str) -> Result<Drawing, GameError> {
+        let json = self.read_file(self.resolve(drawing_path, "json")?, drawing_path)?;
+        serde_json::from_slice(&json)
+            .map_err(|e| GameError::storage(format!("Pennenstreken {} zijn beschadigd: {}", drawing_path, e)))
+    }
+
+    fn stage(&self, target: PathBuf, data: &[u8]) -> Result<StagedFile, GameError> {
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| GameError::storage(format!("Kan map voor tekeningen niet aanmaken: {}", e)))?;
+        }
+        // Eigen tijdelijke naam per keer: twee syncs van hetzelfde antwoord mogen tegelijk schrijven
+        let sequence = STAGED_FILES.fetch_add(1, Ordering::Relaxed);
+        let mut tmp = target.clone().into_os_string();
+        tmp.push(format!(".{}.tmp", sequence));
+        let staged = StagedFile { tmp: PathBuf::from(tmp), target };
+
+        let mut file = fs::File::create(&staged.tmp)
+            .map_err(|e| GameError::storage(format!("Kan {} niet schrijven: {}", staged.tmp.display(), e)))?;
+        file.write_all(data)
+            .and_then(|_| file.sync_all())
+            .map_err(|e| GameError::storage(format!("Kan {} niet schrijven: {}", staged.tmp.display(), e)))?;
+        Ok(staged)
+    }
+
+    fn read_file(&self, file: PathBuf, path: &str) -> Result<Vec<u8>, GameError> {
+        fs::read(&file).map_err(|e| match e.kind() {
+            ErrorKind::NotFound => GameError::new(ErrorCode::NotFound, format!("Tekening {} niet gevonden", path)),
+            _ => GameError::storage(format!("Kan tekening {} niet lezen: {}", path, e)),
+        })
+    }
+
+    /// Pad binnen de antwoordenmap. Het pad komt ook uit HTTP requests, dus `..`, absolute
+    /// paden en alles zonder de verwachte extensie (.png of .json) wordt geweigerd.
+    fn resolve(&self, path: &str, extension: &str) -> Result<PathBuf, GameError> {
+        let relative = Path::new(path);
+        let inside_store = relative.components().all(|c| matches!(c, Component::Normal(_)));
+        let has_extension = relative.extension().and_then(|e| e.to_str()) == Some(extension);
+        if path.is_empty() || !inside_store || !has_extension {
+            return Err(GameError::invalid_input(format!("Ongeldig pad voor tekening: {}", path)));
+        }
+        Ok(self.dir.join(relative))
+    }
+}
+
+static STAGED_FILES: AtomicU64 = AtomicU64::new(0);
+
+/// Weggeschreven bestand dat nog niet op zijn plek staat. Zo kan het renderen en schrijven buiten
+/// de engine lock, en zet `commit` het pas neer als de engine het antwoord aanneemt. Zonder commit
+/// wordt het tijdelijke bestand weer opgeruimd.
+pub struct StagedFile {
+    tmp: PathBuf,
+    target: PathBuf,
+}
+
+impl StagedFile {
+    pub fn commit(self) -> Result<(), GameError> {
+        fs::rename(&self.tmp, &self.target)
+            .map_err(|e| GameError::storage(format!("Kan {} niet vervangen: {}", self.target.display(), e)))
+    }
+}
+
+impl Drop for StagedFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.tmp);
+    }
+}
+
+/// PNG bytes uit een data URL, zoals `canvas.toDataURL('image/png')` die maakt.
+/// Alleen nog nodig voor snapshots van vóór de pennenstreken.
+pub fn decode_png_data_url(data_url: &str) -> Result<Vec<u8>, GameError> {
+    let encoded = data_url.strip_prefix(PNG_DATA_URL_PREFIX)
+        .ok_or_else(|| GameError::invalid_input("Tekening moet een PNG data URL zijn"))?;
+    let png = BASE64.decode(encoded)
+        .map_err(|e| GameError::invalid_input(format!("Tekening is geen geldige base64: {}", e)))?;
+    if !png.starts_with(PNG_SIGNATURE) {
+        return Err(GameError::invalid_input("Tekening is geen PNG"));
+    }
+    Ok(png)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Kleinste geldige PNG: 1x1 transparante pixel
+    const PIXEL: &str = "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==";
+
+    fn store(name: &str) -> AnswerImageStore {
+        let dir = std::env::temp_dir().join(format!("mhmot_answer_images_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        AnswerImageStore::new(dir)
+    }
+
+    #[test]
+    fn test_save_and_read_back() {
+        let store = store("roundtrip");
+        let pixel = decode_png_data_url(PIXEL).unwrap();
+        let image_path = AnswerImageStore::path_for("game_1", 2, "player_0", 3);
+        assert_eq!(image_path, "game_1/round-2/player_0/q3.png");
+
+        store.save(&image_path, &pixel).unwrap();
+        let png = store.read(&image_path).unwrap();
+        assert!(png.starts_with(PNG_SIGNATURE));
+        assert_eq!(store.read("game_1/round-2/player_0/q4.png").unwrap_err().code, ErrorCode::NotFound);
+    }
+
+    #[test]
+    fn test_frozen_version_survives_the_next_sync() {
+        let store = store("freeze");
+        let pixel = decode_png_data_url(PIXEL).unwrap();
+        let live = Answer {
+            question_number: 1,
+            image_path: AnswerImageStore::path_for("game_1", 1, "player_0", 1),
+            drawing_path: String::new(),
+            is_correct: None,
+            timestamp: "2026-01-01T20:00:00+00:00".to_string(),
+        };
+        store.save(&live.image_path, &pixel).unwrap();
+        let frozen = Answer { image_path: "game_1/round-1/player_0/q1-20260101200000.png".to_string(), ..live.clone() };
+        store.freeze(&live, &frozen).unwrap();
+
+        // Zelfde spel, ronde en vraag na een reset: het live bestand wordt overschreven, de versie niet
+        store.save(&live.image_path, b"\x89PNG\r\n\x1a\nnieuw").unwrap();
+        assert_eq!(store.read(&frozen.image_path).unwrap(), pixel);
+    }
+
+    #[test]
+    fn test_pen_strokes_next_to_the_image() {
+        let store = store("drawing");
+        let drawing_path = AnswerImageStore::drawing_path_for("game_1/round-2/player_0/q3.png");
+        assert_eq!(drawing_path, "game_1/round-2/player_0/q3.json");
+
+        let drawing: Drawing = serde_json::from_str(r#"{"width":600,"height":150,"strokes":[{"points":[{"x":1,"y":2,"pressure":0.5,"t":0}]}]}"#).unwrap();
+        store.stage_drawing(&drawing_path, &drawing).unwrap().commit().unwrap();
+        assert_eq!(store.read_drawing(&drawing_path).unwrap().point_count(), 1);
+        // Streken en afbeelding hebben elk hun eigen extensie
+        assert_eq!(store.read_drawing("game_1/round-2/player_0/q3.png").unwrap_err().code, ErrorCode::InvalidInput);
+        assert_eq!(store.read(&drawing_path).unwrap_err().code, ErrorCode::InvalidInput);
+    }
+
+    #[test]
+    fn test_rejects_paths_outside_the_store() {
+        let store = store("paths");
+        for image_path in ["../current_game.json", "../../q1.png", "/etc/q1.png", "game_1/q1.txt", ""] {
+            assert_eq!(store.read(image_path).unwrap_err().code, ErrorCode::InvalidInput, "{}", image_path);
+        }
+    }
+
+    #[test]
+    fn test_only_png_data_urls() {
+        assert!(decode_png_data_url(PIXEL).is_ok());
+        assert!(decode_png_data_url("data:image/jpeg;base64,/9j/").is_err());
+        assert!(decode_png_data_url("data:image/png;base64,niet-base64!").is_err());
+        assert!(decode_png_data_url("data:image/png;base64,aGFsbG8=").is_err()); // "hallo", geen PNG
+    }
+}
@@ -1,113 +1,327 @@
 use tauri::State;
 use std::sync::{Arc, Mutex};
-use crate::game::{GameState, Round, RoundResult};
+use std::time::Instant;
+use tokio::sync::broadcast;
+use crate::answer_images::AnswerImageStore;
+use crate::game::{Drawing, Engine, Episode, ErrorCode, GameAction, GameError, GameEvent, GameState, HistoryOverview, PlayerTimeline, Question, RuleSet, TimerClock, TimerMode};
 use crate::display::{DisplayController, DisplayConfig};
-
+use crate::episode_archive::{ArchiveReport, ArchiveStore};
+use crate::episode_report::{self, ReportStore};
+use crate::episode_store::{EpisodeStore, EpisodeSummary};
+use crate::event_log::EventLog;
+use crate::handwriting;
+use crate::persistence::SnapshotStore;
+use crate::question_bank::{NewQuestion, QuestionBank, QuestionFilter};
+use crate::question_io::{self, ImportReport, QuestionFormat};
+use crate::rule_store::RuleSetStore;
+
+/// Actor in het event log voor acties vanuit de quizmaster app
+pub const QUIZMASTER: &str = "quizmaster";
+
+#[derive(Clone)]
 pub struct AppState {
-    pub game: Arc<Mutex<Option<GameState>>>,
-    pub previous_game: Arc<Mutex<Option<GameState>>>,
+    /// Actieve spel met undo historie; alle spelacties gaan via `dispatch`
+    pub engine: Arc<Mutex<Engine>>,
+    /// Klok van de timer; `timer::spawn` zet de stand in de state. Lock altijd na `engine`.
+    pub timer: Arc<Mutex<TimerClock>>,
+    pub snapshots: Arc<SnapshotStore>,
+    pub events: Arc<Mutex<EventLog>>,
+    /// Elke nieuwe state gaat hierheen, zodat de HTTP server hem naar graphics en tablets kan pushen
+    pub updates: broadcast::Sender<Arc<GameState>>,
+    /// Onafgerond spel uit de vorige sessie, wacht op hervatten of weggooien
+    pub recovered_game: Arc<Mutex<Option<GameState>>>,
+    pub questions: Arc<QuestionBank>,
+    pub episodes: Arc<EpisodeStore>,
+    pub rule_sets: Arc<RuleSetStore>,
+    /// Tekeningen van de tablets; de state bevat alleen hun pad
+    pub answer_images: Arc<AnswerImageStore>,
+    pub archives: Arc<ArchiveStore>,
+    pub reports: Arc<ReportStore>,
+}
+
+/// Spel-id's komen ook uit HTTP requests en worden een bestandsnaam in `events/`; alleen
+/// letters, cijfers, `_` en `-`, zoals `game_<tijdstip>`
+fn check_game_id(game_id: String) -> Result<String, GameError> {
+    let valid = !game_id.is_empty() && game_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    match valid {
+        true => Ok(game_id),
+        false => Err(GameError::invalid_input(format!("Ongeldig spel-id: '{}'", game_id))),
+    }
 }
 
-#[tauri::command]
-pub fn start_new_game(player_names: Vec<String>, state: State<AppState>) -> Result<GameState, String> {
-    let mut game_state = GameState::new(player_names);
-    
-    // Start eerste ronde (zonder inzetten verzamelen)
-    let round = Round::new(1);
-    game_state.start_new_round(round);
-    
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    *game_lock = Some(game_state.clone());
-    
-    Ok(game_state)
-}
+impl AppState {
+    /// Schrijf een snapshot naar schijf. Een mislukte write mag het spel niet stoppen,
+    /// dus we loggen alleen.
+    pub fn persist(&self, game: &GameState) {
+        if let Err(e) = self.snapshots.save(game) {
+            println!("[persist] ⚠️  Snapshot opslaan mislukt: {}", e);
+        }
+    }
 
-#[tauri::command]
-pub fn collect_initial_bets(state: State<AppState>) -> Result<GameState, String> {
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
-    
-    if let Some(round) = &mut game.current_round {
-        round.collect_initial_bets(&mut game.players)
-            .map_err(|e| format!("Fout bij verzamelen inzetten: {}", e))?;
+    /// Stuur de nieuwe state naar alle live verbonden clients (WebSocket).
+    /// Geen luisteraars is geen fout.
+    pub fn publish(&self, game: &GameState) {
+        let _ = self.updates.send(Arc::new(game.clone()));
     }
-    
-    Ok(game.clone())
-}
 
-#[tauri::command]
-pub fn add_bets_to_pot(state: State<AppState>) -> Result<GameState, String> {
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
-    
-    if let Some(round) = &mut game.current_round {
-        // Voeg alle current_bets toe aan pot
-        let total_bets: i32 = game.players.iter()
-            .map(|p| p.current_bet)
-            .sum();
+    /// Opslaan en live doorsturen: aanroepen na elke wijziging van het spel
+    pub fn commit(&self, game: &GameState) {
+        self.persist(game);
+        self.publish(game);
+    }
+
+    /// Leg een uitgevoerde actie vast in het event log. Net als bij `persist` loggen we
+    /// een mislukte write alleen, de show moet door.
+    fn record_event(&self, actor: &str, action: GameAction, timestamp: String, before: Option<&GameState>, after: &GameState) {
+        let mut log = match self.events.lock() {
+            Ok(log) => log,
+            Err(e) => {
+                println!("[event_log] ⚠️  Event log niet beschikbaar: {}", e);
+                return;
+            }
+        };
+        let event = GameEvent::new(log.next_sequence(), timestamp, actor.to_string(), action, before, after);
+        if let Err(e) = log.append(&event) {
+            println!("[event_log] ⚠️  Event opslaan mislukt: {}", e);
+        }
+    }
+
+    /// Koppel het event log aan een (nieuw of hervat) spel. Lukt dat niet, dan loggen we alleen.
+    fn open_event_log(&self, game_id: &str) {
+        if let Err(e) = self.events.lock().map_err(|e| e.to_string()).and_then(|mut log| log.open(game_id)) {
+            println!("[event_log] ⚠️  Kan event log niet openen: {}", e);
+        }
+    }
+
+    /// Voer een spelactie uit via de engine (inclusief undo/redo), event loggen en
+    /// snapshot opslaan. Mislukt de actie, dan blijft de oude state staan.
+    pub fn dispatch(&self, actor: &str, action: GameAction) -> Result<GameState, GameError> {
+        let mut engine = self.engine.lock()?;
+        self.dispatch_locked(&mut engine, actor, action)
+    }
+
+    /// `dispatch` voor aanroepers die de engine al gelockt hebben
+    fn dispatch_locked(&self, engine: &mut Engine, actor: &str, action: GameAction) -> Result<GameState, GameError> {
+        let timestamp = chrono::Utc::now().to_rfc3339();
+        let applied = engine.apply(&action, &timestamp)?;
+        if let Some(label) = &applied.label {
+            println!("[{}] {}", if action == GameAction::Undo { "undo" } else { "redo" }, label);
+        }
+        let game = engine.game()
+            .ok_or_else(GameError::no_active_game)?;
+        
+        // Nieuw spel = nieuw event log
+        if let GameAction::StartGame { game_id, .. } = &action {
+            self.open_event_log(game_id);
+        }
+        if let (GameAction::CompleteRound { .. }, Some(before)) = (&action, &applied.before) {
+            self.freeze_answers(before, game);
+        }
+        self.record_event(actor, action, timestamp, applied.before.as_ref(), game);
+        
+        self.commit(game);
+        Ok(game.clone())
+    }
+
+    /// Tekening van een tablet opslaan: controleren of er geschreven mag worden, de streken als PNG
+    /// renderen en samen met de streken wegschrijven, en het antwoord via de engine vastleggen. In de
+    /// state komen alleen de paden. Renderen en schrijven gebeurt buiten de engine lock; de bestanden
+    /// komen pas op hun plek als de engine het antwoord aanneemt, zodat een geweigerde tekening
+    /// nooit de opgeslagen tekening overschrijft.
+    pub fn save_answer(&self, player_id: String, question_number: i32, drawing: Drawing) -> Result<GameState, GameError> {
+        drawing.validate()?;
+        let (game_id, round_number) = {
+            let engine = self.engine.lock()?;
+            let game = engine.game()
+                .ok_or_else(GameError::no_active_game)?;
+            game.check_writing_allowed(&player_id, question_number)?;
+            (game.game_id.clone(), game.round_number)
+        };
         
-        round.add_to_pot(total_bets);
+        let (width, height) = handwriting::output_size(&drawing, None, None, handwriting::STORED_SCALE)?;
+        let png = handwriting::render_png(&drawing, width, height)?;
+        let image_path = AnswerImageStore::path_for(&game_id, round_number, &player_id, question_number);
+        let image = self.answer_images.stage_image(&image_path, &png)?;
+        let (drawing_path, strokes) = match drawing.is_empty() {
+            true => (String::new(), None),
+            false => {
+                let drawing_path = AnswerImageStore::drawing_path_for(&image_path);
+                let strokes = self.answer_images.stage_drawing(&drawing_path, &drawing)?;
+                (drawing_path, Some(strokes))
+            }
+        };
         
-        // Reset current_bet naar 0 (zijn nu in pot)
-        for player in &mut game.players {
-            player.current_bet = 0;
+        let mut engine = self.engine.lock()?;
+        let game = engine.game()
+            .ok_or_else(GameError::no_active_game)?;
+        if game.game_id != game_id || game.round_number != round_number {
+            return Err(GameError::new(ErrorCode::WritingLocked, "De ronde is veranderd terwijl de tekening werd opgeslagen"));
+        }
+        game.check_writing_allowed(&player_id, question_number)?;
+        image.commit()?;
+        if let Some(strokes) = strokes {
+            strokes.commit()?;
         }
+        
+        let actor = format!("tablet:{}", player_id);
+        self.dispatch_locked(&mut engine, &actor, GameAction::UpdateAnswer { player_id, question_number, image_path, drawing_path })
     }
-    
-    Ok(game.clone())
+
+    /// Na `CompleteRound` verwijzen de antwoorden naar hun vaste versie (zie `Player::freeze_answers`);
+    /// de live tekeningen worden daarheen gekopieerd voordat de nieuwe state de deur uit gaat
+    fn freeze_answers(&self, before: &GameState, after: &GameState) {
+        for player in &before.players {
+            let Some(frozen_player) = after.players.iter().find(|p| p.id == player.id) else {
+                continue;
+            };
+            for live in &player.answers {
+                let Some(frozen) = frozen_player.answers.iter().find(|a| a.question_number == live.question_number) else {
+                    continue;
+                };
+                if let Err(e) = self.answer_images.freeze(live, frozen) {
+                    println!("[freeze_answers] Kan {} niet vastzetten: {}", live.image_path, e);
+                }
+            }
+        }
+    }
+
+    /// Pennenstreken van het antwoord met deze tekening, voor renderen op een ander formaat
+    pub fn find_drawing(&self, image_path: &str) -> Result<Drawing, GameError> {
+        let drawing_path = {
+            let engine = self.engine.lock()?;
+            engine.game()
+                .and_then(|game| game.find_answer(image_path))
+                .map(|answer| answer.drawing_path.clone())
+                .ok_or_else(|| GameError::new(ErrorCode::NotFound, format!("Geen antwoord met tekening {}", image_path)))?
+        };
+        match drawing_path.is_empty() {
+            true => Ok(Drawing::default()),
+            false => self.answer_images.read_drawing(&drawing_path),
+        }
+    }
+
+    /// Spel met zijn event log. Zonder `game_id` het actieve spel; een eerder spel wordt
+    /// opnieuw opgebouwd uit zijn event log, dus dat kan ook nog nadat de app dicht is geweest.
+    fn game_with_events(&self, game_id: Option<String>) -> Result<(GameState, Vec<GameEvent>), GameError> {
+        let current = self.engine.lock()?.game().cloned();
+        let game_id = match (game_id, &current) {
+            (Some(game_id), _) => check_game_id(game_id)?,
+            (None, Some(game)) => game.game_id.clone(),
+            (None, None) => return Err(GameError::no_active_game()),
+        };
+        
+        let events = self.events.lock()?.read(&game_id).map_err(GameError::storage)?;
+        let game = match current {
+            Some(game) if game.game_id == game_id => game,
+            _ if events.is_empty() => return Err(GameError::new(ErrorCode::NotFound, format!("Geen spel {} gevonden", game_id))),
+            _ => crate::game::events::replay(&events).map_err(|e| e.context(format!("Spel {}", game_id)))?,
+        };
+        Ok((game, events))
+    }
+
+    /// Aflevering als zip archiveren, zie `game_with_events` voor welk spel
+    pub fn export_archive(&self, game_id: Option<String>) -> Result<ArchiveReport, GameError> {
+        let (game, events) = self.game_with_events(game_id)?;
+        let exported_at = chrono::Utc::now().to_rfc3339();
+        let report = self.archives.export(&game, events, &self.answer_images, &exported_at)?;
+        println!("[export_archive] 📦 {} gearchiveerd: {} rondes, {} tekeningen, {} ontbrekend → {}",
+                 report.game_id, report.rounds, report.images, report.missing_images.len(), report.path);
+        Ok(report)
+    }
+
+    /// Rapport van een aflevering als HTML pagina, zie `game_with_events` voor welk spel
+    pub fn episode_report(&self, game_id: Option<String>) -> Result<(GameState, String), GameError> {
+        let (game, _) = self.game_with_events(game_id)?;
+        let html = episode_report::render_report(&game, &self.answer_images, &chrono::Utc::now().to_rfc3339());
+        Ok((game, html))
+    }
+
+    /// Timer bedienen: klok aanpassen en de nieuwe stand opslaan en doorsturen. Geen undo stap of event.
+    pub fn update_timer<F>(&self, update: F) -> Result<GameState, GameError>
+    where
+        F: FnOnce(&mut TimerClock, &GameState, Instant) -> Result<(), GameError>,
+    {
+        let now = Instant::now();
+        let mut engine = self.engine.lock()?;
+        let game = engine.game_mut()
+            .ok_or_else(GameError::no_active_game)?;
+        let mut clock = self.timer.lock()?;
+        
+        update(&mut clock, game, now)?;
+        clock.write_to(game, now);
+        self.commit(game);
+        Ok(game.clone())
+    }
+
+    /// Kopie van het actieve spel
+    pub fn current_game(&self) -> Result<GameState, GameError> {
+        self.engine.lock()?
+            .game()
+            .cloned()
+            .ok_or_else(GameError::no_active_game)
+    }
+
+    /// Start een nieuw spel: state, event log en undo historie beginnen opnieuw
+    /// Nieuw spel, eventueel meteen met een draaiboek. Beide onder één engine lock, zodat er
+    /// geen andere actie tussen kan komen; het laden van het draaiboek krijgt geen eigen undo stap.
+    pub fn start_game(&self, player_names: Vec<String>, rules: RuleSet, episode: Option<Episode>) -> Result<GameState, GameError> {
+        let now = chrono::Utc::now();
+        let game_id = format!("game_{}", now.timestamp());
+        let created_at = now.to_rfc3339();
+        
+        let mut engine = self.engine.lock()?;
+        let game = self.dispatch_locked(&mut engine, QUIZMASTER, GameAction::StartGame { game_id, created_at, player_names, rules })?;
+        match episode {
+            Some(episode) => self.dispatch_locked(&mut engine, QUIZMASTER, GameAction::LoadEpisode { episode }),
+            None => Ok(game),
+        }
+    }
+}
+
+#[tauri::command]
+pub fn start_new_game(
+    player_names: Vec<String>,
+    rule_set: Option<String>,
+    state: State<AppState>,
+) -> Result<GameState, GameError> {
+    let rules = state.rule_sets.load(rule_set.as_deref())?;
+    state.start_game(player_names, rules, None)
+}
+
+#[tauri::command]
+pub fn collect_initial_bets(state: State<AppState>) -> Result<GameState, GameError> {
+    state.dispatch(QUIZMASTER, GameAction::CollectInitialBets)
 }
 
 #[tauri::command]
-pub fn get_game_state(state: State<AppState>) -> Result<GameState, String> {
-    let game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    game_lock.as_ref()
-        .ok_or_else(|| "Geen actief spel".to_string())
-        .cloned()
+pub fn add_bets_to_pot(state: State<AppState>) -> Result<GameState, GameError> {
+    state.dispatch(QUIZMASTER, GameAction::AddBetsToPot)
+}
+
+#[tauri::command]
+pub fn get_game_state(state: State<AppState>) -> Result<GameState, GameError> {
+    state.current_game()
 }
 
 #[tauri::command]
 pub fn update_answer(
     player_id: String,
     question_number: i32,
-    image_data: String,
+    drawing: Drawing,
     state: State<AppState>,
-) -> Result<GameState, String> {
-    println!("[update_answer] Received: player_id={}, question_number={}, image_data_length={}", 
-             player_id, question_number, image_data.len());
-    
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
+) -> Result<GameState, GameError> {
+    println!("[update_answer] Received: player_id={}, question_number={}, points={}",
+             player_id, question_number, drawing.point_count());
     
-    let player = game.players.iter_mut()
-        .find(|p| p.id == player_id)
-        .ok_or_else(|| format!("Speler niet gevonden: {}", player_id))?;
-    
-    println!("[update_answer] Found player: {} ({})", player.name, player.id);
-    player.add_answer(question_number, image_data);
-    println!("[update_answer] Answer added. Player now has {} answers", player.answers.len());
-    
-    Ok(game.clone())
+    state.save_answer(player_id, question_number, drawing)
 }
 
 #[tauri::command]
 pub fn clear_player_answers(
     player_id: String,
     state: State<AppState>,
-) -> Result<GameState, String> {
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
-    
-    let player = game.players.iter_mut()
-        .find(|p| p.id == player_id)
-        .ok_or_else(|| "Speler niet gevonden".to_string())?;
-    
-    player.clear_answers();
-    
-    Ok(game.clone())
+) -> Result<GameState, GameError> {
+    state.dispatch(QUIZMASTER, GameAction::ClearPlayerAnswers { player_id })
 }
 
 #[tauri::command]
@@ -116,18 +330,8 @@ pub fn approve_answer(
     question_number: i32,
     is_correct: bool,
     state: State<AppState>,
-) -> Result<GameState, String> {
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
-    
-    let player = game.players.iter_mut()
-        .find(|p| p.id == player_id)
-        .ok_or_else(|| "Speler niet gevonden".to_string())?;
-    
-    player.approve_answer(question_number, is_correct);
-    
-    Ok(game.clone())
+) -> Result<GameState, GameError> {
+    state.dispatch(QUIZMASTER, GameAction::ApproveAnswer { player_id, question_number, is_correct })
 }
 
 #[tauri::command]
@@ -135,219 +339,273 @@ pub fn place_bet(
     player_id: String,
     amount: i32,
     state: State<AppState>,
-) -> Result<GameState, String> {
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
-    
-    let player = game.players.iter_mut()
-        .find(|p| p.id == player_id)
-        .ok_or_else(|| "Speler niet gevonden".to_string())?;
-    
-    if let Some(round) = &mut game.current_round {
-        round.process_bet(player, amount)?;
-    } else {
-        return Err("Geen actieve ronde".to_string());
-    }
-    
-    Ok(game.clone())
+) -> Result<GameState, GameError> {
+    state.dispatch(QUIZMASTER, GameAction::PlaceBet { player_id, amount })
+}
+
+#[tauri::command]
+pub fn check_bet(
+    player_id: String,
+    state: State<AppState>,
+) -> Result<GameState, GameError> {
+    state.dispatch(QUIZMASTER, GameAction::Check { player_id })
+}
+
+#[tauri::command]
+pub fn call_bet(
+    player_id: String,
+    state: State<AppState>,
+) -> Result<GameState, GameError> {
+    state.dispatch(QUIZMASTER, GameAction::Call { player_id })
+}
+
+#[tauri::command]
+pub fn all_in(
+    player_id: String,
+    state: State<AppState>,
+) -> Result<GameState, GameError> {
+    state.dispatch(QUIZMASTER, GameAction::AllIn { player_id })
 }
 
 #[tauri::command]
 pub fn player_fold(
     player_id: String,
     state: State<AppState>,
-) -> Result<GameState, String> {
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
-    
-    let player = game.players.iter_mut()
-        .find(|p| p.id == player_id)
-        .ok_or_else(|| "Speler niet gevonden".to_string())?;
-    
-    player.fold();
-    
-    Ok(game.clone())
+) -> Result<GameState, GameError> {
+    state.dispatch(QUIZMASTER, GameAction::PlayerFold { player_id })
 }
 
 #[tauri::command]
-pub fn advance_phase(state: State<AppState>) -> Result<GameState, String> {
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
-    
-    if let Some(round) = &mut game.current_round {
-        round.advance_phase();
-    }
-    
-    Ok(game.clone())
+pub fn advance_phase(state: State<AppState>) -> Result<GameState, GameError> {
+    state.dispatch(QUIZMASTER, GameAction::AdvancePhase)
 }
 
 #[tauri::command]
-pub fn complete_round(state: State<AppState>, winner_id: Option<String>) -> Result<GameState, String> {
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
-    
-    if let Some(round) = &game.current_round {
-        let pot_amount = round.pot;
-        let winner_id_clone = winner_id.clone();
-        
-        let result = if let Some(ref id) = winner_id {
-            // Handmatig gekozen winnaar
-            let winner = game.players.iter()
-                .find(|p| p.id == *id)
-                .ok_or_else(|| "Winnaar niet gevonden".to_string())?;
-            
-            println!("Winner: {} (ID: {}), Pot: €{}", winner.name, winner.id, pot_amount);
-            println!("Balance voor: €{}", winner.balance);
-            
-            let correct_count = winner.count_correct_answers();
-            let player_scores: Vec<(String, i32)> = game.players.iter()
-                .filter(|p| p.is_active && !p.has_folded)
-                .map(|p| (p.id.clone(), p.count_correct_answers()))
-                .collect();
-            
-            RoundResult {
-                winner_id: winner.id.clone(),
-                winner_name: winner.name.clone(),
-                pot_amount,
-                correct_answers: correct_count,
-                player_scores,
-            }
-        } else {
-            // Automatisch winnaar bepalen
-            round.determine_winner(&game.players)
-                .ok_or_else(|| "Kan winnaar niet bepalen".to_string())?
-        };
-        
-        game.complete_round(result);
-        
-        // Check balance na
-        if let Some(id) = winner_id_clone {
-            if let Some(winner) = game.players.iter().find(|p| p.id == id) {
-                println!("Balance na: €{}", winner.balance);
-            }
-        }
-    }
-    
-    Ok(game.clone())
+pub fn complete_round(state: State<AppState>, winner_id: Option<String>) -> Result<GameState, GameError> {
+    state.dispatch(QUIZMASTER, GameAction::CompleteRound { winner_id })
 }
 
 #[tauri::command]
-pub fn save_state_for_undo(state: State<AppState>) -> Result<(), String> {
-    let game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    if let Some(current_game) = game_lock.as_ref() {
-        let mut prev_lock = state.previous_game.lock().map_err(|e| e.to_string())?;
-        *prev_lock = Some(current_game.clone());
-    }
-    Ok(())
+pub fn start_next_round(state: State<AppState>) -> Result<GameState, GameError> {
+    state.dispatch(QUIZMASTER, GameAction::StartNextRound)
 }
 
 #[tauri::command]
-pub fn start_next_round(state: State<AppState>) -> Result<GameState, String> {
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
-    
-    if game.is_finished {
-        return Err("Spel is afgelopen".to_string());
-    }
-    
-    let next_round_num = game.round_number + 1;
-    
-    if next_round_num > 7 {
-        return Err("Maximum aantal rondes bereikt".to_string());
-    }
-    
-    // Start nieuwe ronde ZONDER inzetten te verzamelen
-    // Quizmaster moet handmatig op "Verzamel Inzetten" klikken
-    let round = Round::new(next_round_num);
-    game.start_new_round(round);
-    
-    Ok(game.clone())
+pub fn undo_last_action(state: State<AppState>) -> Result<GameState, GameError> {
+    state.dispatch(QUIZMASTER, GameAction::Undo)
 }
 
 #[tauri::command]
-pub fn undo_last_action(state: State<AppState>) -> Result<GameState, String> {
-    // Herstel de vorige game state
-    let mut prev_lock = state.previous_game.lock().map_err(|e| e.to_string())?;
-    let previous_game = prev_lock.take()
-        .ok_or_else(|| "Geen vorige state beschikbaar om te herstellen".to_string())?;
-    
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    *game_lock = Some(previous_game.clone());
-    
-    Ok(previous_game)
+pub fn redo_last_action(state: State<AppState>) -> Result<GameState, GameError> {
+    state.dispatch(QUIZMASTER, GameAction::Redo)
 }
 
 #[tauri::command]
-pub fn set_round_number(round_num: i32, state: State<AppState>) -> Result<GameState, String> {
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
-    
-    if round_num < 1 || round_num > 7 {
-        return Err("Rondenummer moet tussen 1 en 7 zijn".to_string());
-    }
-    
-    // Update round number
-    game.round_number = round_num;
+pub fn get_undo_history(state: State<AppState>) -> Result<HistoryOverview, GameError> {
+    let engine = state.engine.lock()?;
+    Ok(engine.history().overview())
+}
+
+// ========== EVENT LOG COMMANDS ==========
+
+#[tauri::command]
+pub fn get_event_log(state: State<AppState>) -> Result<Vec<GameEvent>, GameError> {
+    let log = state.events.lock()?;
+    let game_id = log.game_id()
+        .ok_or_else(GameError::no_active_game)?;
+    log.read(game_id).map_err(GameError::storage)
+}
+
+/// Bouw het huidige spel opnieuw op uit het event log (ter controle; de actieve state blijft staan)
+#[tauri::command]
+pub fn replay_event_log(state: State<AppState>) -> Result<GameState, GameError> {
+    let events = get_event_log(state)?;
+    crate::game::events::replay(&events)
+}
+
+/// Saldoverloop per speler (startgeld, inleg, inzetten, gewonnen potten, correcties), voor de
+/// "geld over de avond" grafiek en om discussies na te lopen. Met `player_id` alleen die speler.
+#[tauri::command]
+pub fn get_balance_timeline(player_id: Option<String>, state: State<AppState>) -> Result<Vec<PlayerTimeline>, GameError> {
+    state.current_game()?.balance_timelines(player_id.as_deref())
+}
+
+// ========== ARCHIVE COMMANDS ==========
+
+/// Exporteer een aflevering als zip (manifest met alle rondes en acties, plus de tekeningen)
+/// naar de archiefmap van de app. Zonder `game_id` het actieve spel.
+#[tauri::command]
+pub fn export_episode_archive(game_id: Option<String>, state: State<AppState>) -> Result<ArchiveReport, GameError> {
+    state.export_archive(game_id)
+}
+
+/// Schrijf het rapport van een aflevering (eindstand, saldoverloop, per ronde pot, inzet, passen,
+/// aantal goed en tekeningen) als HTML naar de rapportenmap en geef het pad terug
+#[tauri::command]
+pub fn export_episode_report(game_id: Option<String>, state: State<AppState>) -> Result<String, GameError> {
+    let (game, html) = state.episode_report(game_id)?;
+    let path = state.reports.save(&game.game_id, &chrono::Utc::now().to_rfc3339(), &html)?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+// ========== CRASH RECOVERY COMMANDS ==========
+
+#[tauri::command]
+pub fn get_recoverable_game(state: State<AppState>) -> Result<Option<GameState>, GameError> {
+    let recovered_lock = state.recovered_game.lock()?;
+    Ok(recovered_lock.clone())
+}
+
+#[tauri::command]
+pub fn resume_recovered_game(state: State<AppState>) -> Result<GameState, GameError> {
+    let mut recovered_lock = state.recovered_game.lock()?;
+    let recovered = recovered_lock.take()
+        .ok_or_else(|| GameError::new(ErrorCode::NotFound, "Geen onafgerond spel om te hervatten"))?;
     
-    // Als er een actieve ronde is, update deze ook
-    if let Some(ref mut current_round) = game.current_round {
-        current_round.round_number = round_num;
-        
-        // Update minimale inzet op basis van rondenummer
-        current_round.min_bet = match round_num {
-            1 => 10,
-            2 => 20,
-            3 => 40,
-            _ => 80,
-        };
-    }
+    let mut engine = state.engine.lock()?;
+    *engine = Engine::with_game(recovered.clone());
+    state.publish(&recovered);
     
-    println!("[set_round_number] Round number set to: {}", round_num);
+    // Verder loggen in het bestaande event log van dit spel
+    state.open_event_log(&recovered.game_id);
+    println!("[resume_recovered_game] Spel {} hervat in ronde {}", recovered.game_id, recovered.round_number);
     
-    Ok(game.clone())
+    Ok(recovered)
+}
+
+#[tauri::command]
+pub fn discard_recovered_game(state: State<AppState>) -> Result<(), GameError> {
+    // Snapshot op schijf blijft staan tot het volgende spel hem overschrijft
+    let mut recovered_lock = state.recovered_game.lock()?;
+    *recovered_lock = None;
+    Ok(())
 }
 
 #[tauri::command]
-pub fn reset_game(state: State<AppState>) -> Result<GameState, String> {
+pub fn set_round_number(round_num: i32, state: State<AppState>) -> Result<GameState, GameError> {
+    state.dispatch(QUIZMASTER, GameAction::SetRoundNumber { round_num })
+}
+
+#[tauri::command]
+pub fn reset_game(state: State<AppState>) -> Result<GameState, GameError> {
     println!("[reset_game] Starting game reset...");
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
-    
-    println!("[reset_game] Resetting {} players...", game.players.len());
+    state.dispatch(QUIZMASTER, GameAction::ResetGame)
+}
+
+// ========== QUESTION BANK COMMANDS ==========
+
+#[tauri::command]
+pub fn search_questions(filter: Option<QuestionFilter>, state: State<AppState>) -> Result<Vec<Question>, GameError> {
+    state.questions.search(&filter.unwrap_or_default())
+}
+
+#[tauri::command]
+pub fn get_question_categories(state: State<AppState>) -> Result<Vec<String>, GameError> {
+    state.questions.categories()
+}
+
+#[tauri::command]
+pub fn add_question(question: NewQuestion, state: State<AppState>) -> Result<Question, GameError> {
+    state.questions.add(question)
+}
+
+#[tauri::command]
+pub fn update_question(id: i64, question: NewQuestion, state: State<AppState>) -> Result<Question, GameError> {
+    state.questions.update(id, question)
+}
+
+#[tauri::command]
+pub fn delete_question(id: i64, state: State<AppState>) -> Result<(), GameError> {
+    state.questions.delete(id)
+}
+
+/// Importeer een vragenset (CSV, JSON of XLSX) die de frontend als bytes meestuurt.
+/// Met `dry_run` krijgt de redactie eerst het rapport te zien zonder dat er iets opgeslagen wordt.
+#[tauri::command]
+pub fn import_questions(
+    file_name: String,
+    data: Vec<u8>,
+    dry_run: Option<bool>,
+    state: State<AppState>,
+) -> Result<ImportReport, GameError> {
+    let format = QuestionFormat::from_file_name(&file_name)?;
+    question_io::import_questions(&state.questions, format, &data, dry_run.unwrap_or(false))
+}
+
+/// Exporteer (een selectie uit) de vragenbank; de frontend slaat de bytes op als bestand
+#[tauri::command]
+pub fn export_questions(
+    format: QuestionFormat,
+    filter: Option<QuestionFilter>,
+    state: State<AppState>,
+) -> Result<Vec<u8>, GameError> {
+    let questions = state.questions.search(&filter.unwrap_or_default())?;
+    question_io::export_questions(format, &questions)
+}
+
+/// Koppel vragen uit de bank aan de huidige ronde (één id per vraagnummer, in volgorde)
+#[tauri::command]
+pub fn assign_round_questions(question_ids: Vec<i64>, state: State<AppState>) -> Result<GameState, GameError> {
+    let questions = state.questions.get_many(&question_ids)?;
+    let game = state.dispatch(QUIZMASTER, GameAction::AssignQuestions { questions })?;
     
-    // Reset alle spelers naar startgeld en clear data
-    for player in &mut game.players {
-        player.balance = 750; // Start met €750
-        player.current_bet = 0;
-        player.has_folded = false;
-        player.is_active = true;
-        player.clear_answers();
-        println!("[reset_game] Reset player: {} ({}), balance: €{}, answers: {}", 
-                 player.name, player.id, player.balance, player.answers.len());
+    if let Err(e) = state.questions.mark_used(&question_ids) {
+        println!("[assign_round_questions] ⚠️  Kan vragen niet als gebruikt markeren: {}", e);
     }
     
-    // Start nieuwe ronde 1
-    game.round_number = 0;
-    game.is_finished = false;
-    let round = Round::new(1);
-    game.start_new_round(round);
-    
-    println!("[reset_game] Game reset complete. Round: {}", game.round_number);
+    Ok(game)
+}
+
+// ========== RULE SET COMMANDS ==========
+
+#[tauri::command]
+pub fn list_rule_sets(state: State<AppState>) -> Result<Vec<RuleSet>, GameError> {
+    state.rule_sets.list()
+}
+
+#[tauri::command]
+pub fn save_rule_set(rules: RuleSet, state: State<AppState>) -> Result<(), GameError> {
+    state.rule_sets.save(&rules)
+}
+
+// ========== EPISODE COMMANDS ==========
+
+#[tauri::command]
+pub fn list_episodes(state: State<AppState>) -> Result<Vec<EpisodeSummary>, GameError> {
+    state.episodes.list()
+}
+
+#[tauri::command]
+pub fn get_episode(episode_id: String, state: State<AppState>) -> Result<Episode, GameError> {
+    state.episodes.load(&episode_id)
+}
+
+#[tauri::command]
+pub fn save_episode(episode: Episode, state: State<AppState>) -> Result<(), GameError> {
+    let rules = state.rule_sets.load(episode.rule_set.as_deref())?;
+    state.episodes.save(&episode, &rules)
+}
+
+#[tauri::command]
+pub fn delete_episode(episode_id: String, state: State<AppState>) -> Result<(), GameError> {
+    state.episodes.delete(&episode_id)
+}
+
+/// Start een nieuw spel volgens een voorbereid draaiboek: spelers uit het draaiboek,
+/// en elke ronde krijgt automatisch de vragen die erbij horen
+#[tauri::command]
+pub fn start_episode(episode_id: String, state: State<AppState>) -> Result<GameState, GameError> {
+    let episode = state.episodes.load(&episode_id)?;
+    let rules = state.rule_sets.load(episode.rule_set.as_deref())?;
+    episode.validate(&rules)?;
     
-    Ok(game.clone())
+    state.start_game(episode.player_names.clone(), rules, Some(episode))
 }
 
 // ========== DISPLAY COMMANDS ==========
 
 #[tauri::command]
-pub fn list_serial_ports() -> Result<Vec<String>, String> {
+pub fn list_serial_ports() -> Result<Vec<String>, GameError> {
     DisplayController::list_ports()
 }
 
@@ -355,13 +613,13 @@ pub fn list_serial_ports() -> Result<Vec<String>, String> {
 pub fn configure_display(
     config: DisplayConfig,
     display: State<DisplayController>,
-) -> Result<DisplayConfig, String> {
+) -> Result<DisplayConfig, GameError> {
     display.configure(config)?;
     display.get_config()
 }
 
 #[tauri::command]
-pub fn get_display_config(display: State<DisplayController>) -> Result<DisplayConfig, String> {
+pub fn get_display_config(display: State<DisplayController>) -> Result<DisplayConfig, GameError> {
     display.get_config()
 }
 
@@ -369,15 +627,13 @@ pub fn get_display_config(display: State<DisplayController>) -> Result<DisplayCo
 pub fn update_display_values(
     state: State<AppState>,
     display: State<DisplayController>,
-) -> Result<(), String> {
-    let game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_ref()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
+) -> Result<(), GameError> {
+    let game = state.current_game()?;
     
     let pot = game.current_round.as_ref().map(|r| r.pot).unwrap_or(0);
     
     // Zorg dat we 3 spelers hebben, vul aan met 0 als nodig
-    let p1 = game.players.get(0).map(|p| p.balance).unwrap_or(0);
+    let p1 = game.players.first().map(|p| p.balance).unwrap_or(0);
     let p2 = game.players.get(1).map(|p| p.balance).unwrap_or(0);
     let p3 = game.players.get(2).map(|p| p.balance).unwrap_or(0);
     
@@ -385,209 +641,118 @@ pub fn update_display_values(
 }
 
 #[tauri::command]
-pub fn test_displays(display: State<DisplayController>) -> Result<(), String> {
+pub fn test_displays(display: State<DisplayController>) -> Result<(), GameError> {
     display.test_displays()
 }
 
 #[tauri::command]
-pub fn clear_displays(display: State<DisplayController>) -> Result<(), String> {
+pub fn clear_displays(display: State<DisplayController>) -> Result<(), GameError> {
     display.clear_displays()
 }
 
 #[tauri::command]
-pub fn toggle_player_active(player_id: String, is_active: bool, state: State<AppState>) -> Result<GameState, String> {
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
-    
-    // Als we een speler willen elimineren (is_active = false), controleer eerst of er al een geëlimineerde speler is
-    if !is_active && game.round_number > 4 {
-        // Tel hoeveel spelers al geëlimineerd zijn (handmatig, niet door balance)
-        let manually_eliminated_count = game.players.iter()
-            .filter(|p| !p.is_active && p.balance > 0) // Geëlimineerd maar nog geld
-            .count();
-        
-        // Als er al een speler handmatig is geëlimineerd, activeer die eerst
-        if manually_eliminated_count >= 1 {
-            for player in game.players.iter_mut() {
-                if !player.is_active && player.balance > 0 && player.id != player_id {
-                    player.is_active = true;
-                    println!("[toggle_player_active] Re-activating player {} to allow only one elimination", player.name);
-                    break;
-                }
-            }
-        }
-    }
-    
-    if let Some(player) = game.players.iter_mut().find(|p| p.id == player_id) {
-        player.is_active = is_active;
-        println!("[toggle_player_active] Player {} is now {}", player.name, if is_active { "ACTIVE" } else { "ELIMINATED" });
-    } else {
-        return Err("Speler niet gevonden".to_string());
-    }
-    
-    Ok(game.clone())
+pub fn toggle_player_active(player_id: String, is_active: bool, state: State<AppState>) -> Result<GameState, GameError> {
+    state.dispatch(QUIZMASTER, GameAction::TogglePlayerActive { player_id, is_active })
 }
 
 #[tauri::command]
-pub fn reveal_question(question_number: i32, state: State<AppState>) -> Result<GameState, String> {
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
-    
-    if let Some(round) = &mut game.current_round {
-        // Toggle: als revealed, dan unrevealen, anders revealen
-        if let Some(pos) = round.revealed_questions.iter().position(|&q| q == question_number) {
-            round.revealed_questions.remove(pos);
-            println!("[reveal_question] Vraag {} is nu VERBORGEN (unrevealed)", question_number);
-        } else {
-            round.revealed_questions.push(question_number);
-            println!("[reveal_question] Vraag {} is nu ZICHTBAAR (revealed)", question_number);
-        }
-    } else {
-        return Err("Geen actieve ronde".to_string());
-    }
-    
-    Ok(game.clone())
+pub fn reveal_question(question_number: i32, state: State<AppState>) -> Result<GameState, GameError> {
+    state.dispatch(QUIZMASTER, GameAction::RevealQuestion { question_number })
 }
 
 #[tauri::command]
-pub fn toggle_video_mode(state: State<AppState>) -> Result<GameState, String> {
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
-    
-    game.video_mode_active = !game.video_mode_active;
-    
-    // Automatisch schrijven blokkeren tijdens video mode, inschakelen bij uitschakelen video
-    if game.video_mode_active {
-        game.writing_enabled = false;
-        println!("[toggle_video_mode] Video mode ACTIEF - schrijven geblokkeerd");
-    } else {
-        game.writing_enabled = true;
-        println!("[toggle_video_mode] Video mode INACTIEF - schrijven weer toegestaan");
-    }
-    
-    Ok(game.clone())
+pub fn toggle_video_mode(state: State<AppState>) -> Result<GameState, GameError> {
+    state.dispatch(QUIZMASTER, GameAction::ToggleVideoMode)
 }
 
+// Timer commands zijn geen spelacties: geen undo stap en geen event, de timer loopt los van het spelverloop.
+// De klok loopt in `timer::spawn`; deze commands stellen hem alleen in.
+
 #[tauri::command]
-pub fn set_timer(seconds: i32, state: State<AppState>) -> Result<GameState, String> {
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
-    
-    game.timer_seconds = seconds;
-    println!("[set_timer] Timer set to: {}s", seconds);
-    
-    Ok(game.clone())
+pub fn set_timer(seconds: i32, mode: Option<TimerMode>, state: State<AppState>) -> Result<GameState, GameError> {
+    if seconds < 0 {
+        return Err(GameError::invalid_input("Timertijd kan niet negatief zijn"));
+    }
+    state.update_timer(|clock, _, _| {
+        clock.set(mode.unwrap_or_default(), seconds);
+        println!("[set_timer] Timer set to: {}s ({:?})", seconds, clock.mode());
+        Ok(())
+    })
 }
 
+/// Start of hervat de timer. Nogmaals starten terwijl hij loopt doet niets.
 #[tauri::command]
-pub fn start_timer(state: State<AppState>) -> Result<GameState, String> {
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
-    
-    game.timer_running = true;
-    println!("[start_timer] Timer started");
-    
-    // Start background timer task
-    let game_state = state.game.clone();
-    tauri::async_runtime::spawn(async move {
-        loop {
-            tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
-            
-            let mut lock = match game_state.lock() {
-                Ok(l) => l,
-                Err(_) => break,
-            };
-            
-            if let Some(game) = lock.as_mut() {
-                if !game.timer_running {
-                    break; // Stop de timer task als de timer is gestopt
-                }
-                game.timer_seconds += 1;
-            } else {
-                break;
-            }
+pub fn start_timer(state: State<AppState>) -> Result<GameState, GameError> {
+    state.update_timer(|clock, game, now| {
+        if clock.mode() == TimerMode::Countdown && game.timer_duration == 0 {
+            return Err(GameError::invalid_input("Stel eerst een tijd in om af te tellen"));
         }
-    });
-    
-    Ok(game.clone())
+        clock.start(now);
+        println!("[start_timer] Timer started");
+        Ok(())
+    })
 }
 
+/// Timer op de standaardtijd van de huidige fase zetten en starten (schrijven of inzetten)
 #[tauri::command]
-pub fn stop_timer(state: State<AppState>) -> Result<GameState, String> {
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
-    
-    game.timer_running = false;
-    println!("[stop_timer] Timer stopped");
-    
-    Ok(game.clone())
+pub fn start_phase_timer(state: State<AppState>) -> Result<GameState, GameError> {
+    state.update_timer(|clock, game, now| {
+        let phase = &game.current_round.as_ref()
+            .ok_or_else(GameError::no_active_round)?
+            .phase;
+        let seconds = game.rules.timer.for_phase(phase)
+            .ok_or_else(|| GameError::new(ErrorCode::IllegalPhase, format!("Geen standaardtijd voor fase {:?}", phase)))?;
+        
+        clock.set(TimerMode::Countdown, seconds);
+        clock.start(now);
+        println!("[start_phase_timer] {:?}: {}s", phase, seconds);
+        Ok(())
+    })
 }
 
 #[tauri::command]
-pub fn reset_timer(state: State<AppState>) -> Result<GameState, String> {
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
-    
-    game.timer_seconds = 0;
-    game.timer_running = false;
-    println!("[reset_timer] Timer reset");
-    
-    Ok(game.clone())
+pub fn pause_timer(state: State<AppState>) -> Result<GameState, GameError> {
+    state.update_timer(|clock, _, now| {
+        clock.pause(now);
+        println!("[pause_timer] Timer paused");
+        Ok(())
+    })
 }
 
 #[tauri::command]
-pub fn tick_timer(state: State<AppState>) -> Result<GameState, String> {
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
-    
-    if game.timer_running {
-        game.timer_seconds += 1;
-    }
-    
-    Ok(game.clone())
+pub fn reset_timer(state: State<AppState>) -> Result<GameState, GameError> {
+    state.update_timer(|clock, _, _| {
+        clock.reset();
+        println!("[reset_timer] Timer reset");
+        Ok(())
+    })
 }
 
 #[tauri::command]
-pub fn toggle_writing(enabled: bool, state: State<AppState>) -> Result<GameState, String> {
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
-    
-    // Schrijven mag niet worden ingeschakeld tijdens video mode
-    if enabled && game.video_mode_active {
-        return Err("Schrijven kan niet worden ingeschakeld tijdens video mode".to_string());
-    }
-    
-    game.writing_enabled = enabled;
-    println!("[toggle_writing] Schrijven is nu {}", if enabled { "TOEGESTAAN" } else { "GEBLOKKEERD" });
-    
-    Ok(game.clone())
+pub fn toggle_writing(enabled: bool, state: State<AppState>) -> Result<GameState, GameError> {
+    state.dispatch(QUIZMASTER, GameAction::ToggleWriting { enabled })
 }
 
 #[tauri::command]
-pub async fn check_for_updates() -> Result<crate::updater::UpdateInfo, String> {
-    crate::updater::check_for_updates().await
+pub async fn check_for_updates() -> Result<crate::updater::UpdateInfo, GameError> {
+    crate::updater::check_for_updates().await.map_err(GameError::internal)
 }
 
 #[tauri::command]
-pub fn update_player_name(player_id: String, new_name: String, state: State<AppState>) -> Result<GameState, String> {
-    let mut game_lock = state.game.lock().map_err(|e| e.to_string())?;
-    let game = game_lock.as_mut()
-        .ok_or_else(|| "Geen actief spel".to_string())?;
-    
-    // Zoek de speler en update de naam
-    if let Some(player) = game.players.iter_mut().find(|p| p.id == player_id) {
-        player.name = new_name;
-        Ok(game.clone())
-    } else {
-        Err(format!("Speler {} niet gevonden", player_id))
+pub fn update_player_name(player_id: String, new_name: String, state: State<AppState>) -> Result<GameState, GameError> {
+    state.dispatch(QUIZMASTER, GameAction::UpdatePlayerName { player_id, new_name })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_game_ids_cannot_leave_the_event_log() {
+        assert_eq!(check_game_id("game_1736000000".to_string()).unwrap(), "game_1736000000");
+        assert!(check_game_id("game-test_2".to_string()).is_ok());
+        for game_id in ["", "../current_game", "..", "game/1", "game\\1", "game.1", "game 1", "spel_ü"] {
+            assert_eq!(check_game_id(game_id.to_string()).unwrap_err().code, ErrorCode::InvalidInput, "{}", game_id);
+        }
     }
 }
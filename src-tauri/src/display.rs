@@ -3,6 +3,8 @@ use std::time::Duration;
 use serialport::SerialPort;
 use serde::{Deserialize, Serialize};
 
+use crate::game::{ErrorCode, GameError};
+
 const STX: u8 = 0x02; // Start of text
 const ETX: u8 = 0x03; // End of text
 const LF: u8 = 0x0A;  // Line feed (clear and start new line)
@@ -24,6 +26,10 @@ impl Default for DisplayConfig {
     }
 }
 
+fn serial_error(message: impl Into<String>) -> GameError {
+    GameError::new(ErrorCode::SerialPortError, message)
+}
+
 pub struct DisplayController {
     port: Mutex<Option<Box<dyn SerialPort>>>,
     config: Mutex<DisplayConfig>,
@@ -37,9 +43,9 @@ impl DisplayController {
         }
     }
 
-    pub fn configure(&self, config: DisplayConfig) -> Result<(), String> {
-        let mut port_lock = self.port.lock().map_err(|e| e.to_string())?;
-        let mut config_lock = self.config.lock().map_err(|e| e.to_string())?;
+    pub fn configure(&self, config: DisplayConfig) -> Result<(), GameError> {
+        let mut port_lock = self.port.lock()?;
+        let mut config_lock = self.config.lock()?;
 
         // Close existing connection
         *port_lock = None;
@@ -52,7 +58,7 @@ impl DisplayController {
                 .stop_bits(serialport::StopBits::One)
                 .parity(serialport::Parity::None)
                 .open()
-                .map_err(|e| format!("Failed to open serial port: {}", e))?;
+                .map_err(|e| serial_error(format!("Failed to open serial port: {}", e)))?;
 
             *port_lock = Some(port);
         }
@@ -61,14 +67,14 @@ impl DisplayController {
         Ok(())
     }
 
-    pub fn get_config(&self) -> Result<DisplayConfig, String> {
-        let config = self.config.lock().map_err(|e| e.to_string())?;
+    pub fn get_config(&self) -> Result<DisplayConfig, GameError> {
+        let config = self.config.lock()?;
         Ok(config.clone())
     }
 
-    pub fn list_ports() -> Result<Vec<String>, String> {
+    pub fn list_ports() -> Result<Vec<String>, GameError> {
         let ports = serialport::available_ports()
-            .map_err(|e| format!("Failed to list serial ports: {}", e))?;
+            .map_err(|e| serial_error(format!("Failed to list serial ports: {}", e)))?;
         
         Ok(ports.into_iter().map(|p| p.port_name).collect())
     }
@@ -96,14 +102,14 @@ impl DisplayController {
         player2_balance: i32,
         player3_balance: i32,
         pot: i32,
-    ) -> Result<(), String> {
-        let config = self.config.lock().map_err(|e| e.to_string())?;
+    ) -> Result<(), GameError> {
+        let config = self.config.lock()?;
         
         if !config.enabled {
             return Ok(()); // Display disabled, skip silently
         }
 
-        let mut port_lock = self.port.lock().map_err(|e| e.to_string())?;
+        let mut port_lock = self.port.lock()?;
         
         if let Some(port) = port_lock.as_mut() {
             // Format alle waarden
@@ -122,31 +128,31 @@ impl DisplayController {
             data.push(ETX);
 
             port.write_all(&data)
-                .map_err(|e| format!("Failed to write to serial port: {}", e))?;
+                .map_err(|e| serial_error(format!("Failed to write to serial port: {}", e)))?;
             
             port.flush()
-                .map_err(|e| format!("Failed to flush serial port: {}", e))?;
+                .map_err(|e| serial_error(format!("Failed to flush serial port: {}", e)))?;
 
             Ok(())
         } else {
-            Err("Serial port not connected".to_string())
+            Err(serial_error("Serial port not connected"))
         }
     }
 
     /// Test functie om de displays te testen
-    pub fn test_displays(&self) -> Result<(), String> {
+    pub fn test_displays(&self) -> Result<(), GameError> {
         self.update_displays(8888, 7777, 6666, 5555)
     }
 
     /// Clear alle displays (toon "    " = 4 spaties per display)
-    pub fn clear_displays(&self) -> Result<(), String> {
-        let config = self.config.lock().map_err(|e| e.to_string())?;
+    pub fn clear_displays(&self) -> Result<(), GameError> {
+        let config = self.config.lock()?;
         
         if !config.enabled {
             return Ok(());
         }
 
-        let mut port_lock = self.port.lock().map_err(|e| e.to_string())?;
+        let mut port_lock = self.port.lock()?;
         
         if let Some(port) = port_lock.as_mut() {
             // 16 spaties
@@ -157,14 +163,14 @@ impl DisplayController {
             data.push(ETX);
 
             port.write_all(&data)
-                .map_err(|e| format!("Failed to write to serial port: {}", e))?;
+                .map_err(|e| serial_error(format!("Failed to write to serial port: {}", e)))?;
             
             port.flush()
-                .map_err(|e| format!("Failed to flush serial port: {}", e))?;
+                .map_err(|e| serial_error(format!("Failed to flush serial port: {}", e)))?;
 
             Ok(())
         } else {
-            Err("Serial port not connected".to_string())
+            Err(serial_error("Serial port not connected"))
         }
     }
 }
@@ -0,0 +1,239 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fs;
+use std::io::{Cursor, Write};
+use std::path::PathBuf;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::answer_images::AnswerImageStore;
+use crate::game::{Answer, ErrorCode, GameError, GameEvent, GameState};
+use crate::persistence::write_atomic;
+
+/// Versie van de indeling van `manifest.json`; ophogen als velden wijzigen of verdwijnen
+pub const ARCHIVE_FORMAT_VERSION: u32 = 1;
+
+const MANIFEST_FILE: &str = "manifest.json";
+const IMAGES_DIR: &str = "images";
+const DRAWINGS_DIR: &str = "drawings";
+
+/// Inhoud van `manifest.json` in het archief van een aflevering
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveManifest {
+    pub format_version: u32,
+    pub exported_at: String,
+    pub app_version: String,
+    /// Eindstand: spelers, regels, draaiboek en per ronde de uitslag, vragen en antwoorden
+    pub game: GameState,
+    /// Elke actie met tijdstip en wie hem deed: inzetten, passen, oordelen, undo's.
+    /// Met saldo's en pot voor en na, zodat elk bedrag te herleiden is.
+    pub events: Vec<GameEvent>,
+    pub images: Vec<ArchivedImage>,
+}
+
+/// Eén tekening in het archief
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ArchivedImage {
+    pub image_path: String, // Zoals in `Answer.image_path`
+    pub file: Option<String>, // Pad in de zip; `None` als het bestand niet (meer) op schijf stond
+    #[serde(default)]
+    pub drawing_path: String, // Zoals in `Answer.drawing_path`; leeg bij antwoorden zonder streken
+    #[serde(default)]
+    pub drawing_file: Option<String>, // Pad van de pennenstreken in de zip, net als `file`
+    pub player_id: String,
+    pub question_number: i32,
+    pub is_correct: Option<bool>,
+    pub timestamp: String,
+}
+
+/// Wat de quizmaster na het exporteren te zien krijgt
+#[derive(Debug, Clone, Serialize)]
+pub struct ArchiveReport {
+    pub path: String,
+    pub game_id: String,
+    pub rounds: usize,
+    pub events: usize,
+    pub images: usize,
+    pub missing_images: Vec<String>,
+}
+
+/// Afgeronde afleveringen als zip in `archives/<game_id>_<tijdstip>.zip`, voor montage en
+/// compliance. Een archief staat op zichzelf: manifest plus alle tekeningen als PNG en hun pennenstreken.
+pub struct ArchiveStore {
+    dir: PathBuf,
+}
+
+impl ArchiveStore {
+    pub fn new(data_dir: PathBuf) -> Self {
+        Self { dir: data_dir.join("archives") }
+    }
+
+    /// Bouw het archief en schrijf het weg. Een ontbrekende tekening of ontbrekende streken stoppen
+    /// de export niet; die staan in het manifest zonder bestand en in `missing_images`.
+    pub fn export(&self, game: &GameState, events: Vec<GameEvent>, images: &AnswerImageStore, exported_at: &str) -> Result<ArchiveReport, GameError> {
+        let (zip, manifest) = build_archive(game, events, images, exported_at)?;
+
+        fs::create_dir_all(&self.dir)
+            .map_err(|e| GameError::storage(format!("Kan map voor archieven niet aanmaken: {}", e)))?;
+        let stamp: String = exported_at.chars().take(19).filter(|c| c.is_ascii_digit()).collect();
+        let path = self.dir.join(format!("{}_{}.zip", game.game_id, stamp));
+        write_atomic(&path, &zip).map_err(GameError::storage)?;
+
+        Ok(ArchiveReport {
+            path: path.to_string_lossy().to_string(),
+            game_id: game.game_id.clone(),
+            rounds: game.round_history.len(),
+            events: manifest.events.len(),
+            images: manifest.images.iter().filter(|i| i.file.is_some()).count(),
+            missing_images: manifest.images.iter()
+                .flat_map(|i| [(&i.image_path, &i.file), (&i.drawing_path, &i.drawing_file)])
+                .filter(|(path, file)| !path.is_empty() && file.is_none())
+                .map(|(path, _)| path.clone())
+                .collect(),
+        })
+    }
+}
+
+/// Zip met `manifest.json`, `images/<image_path>` en `drawings/<drawing_path>` voor elk antwoord
+/// uit de geschiedenis en de lopende ronde
+pub fn build_archive(game: &GameState, events: Vec<GameEvent>, images: &AnswerImageStore, exported_at: &str) -> Result<(Vec<u8>, ArchiveManifest), GameError> {
+    let error = |e: zip::result::ZipError| GameError::internal(format!("Kan archief niet maken: {}", e));
+    let io_error = |e: std::io::Error| GameError::internal(format!("Kan archief niet maken: {}", e));
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    // PNG is al gecomprimeerd, het manifest en de streken niet
+    let stored = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+    let deflated = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    // Bestand in de zip zetten; `None` als het niet (meer) op schijf staat
+    let mut add = |dir: &str, path: &str, options: SimpleFileOptions, data: Result<Vec<u8>, GameError>| -> Result<Option<String>, GameError> {
+        let data = match data {
+            Ok(data) => data,
+            Err(e) if e.code == ErrorCode::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let file = format!("{}/{}", dir, path);
+        zip.start_file(file.as_str(), options).map_err(error)?;
+        zip.write_all(&data).map_err(io_error)?;
+        Ok(Some(file))
+    };
+
+    let mut archived = Vec::new();
+    for (player_id, answer) in archived_answers(game) {
+        let file = add(IMAGES_DIR, &answer.image_path, stored, images.read(&answer.image_path))?;
+        let drawing_file = match answer.drawing_path.is_empty() {
+            true => None,
+            false => {
+                let json = images.read_drawing(&answer.drawing_path).and_then(|drawing| serde_json::to_vec(&drawing)
+                    .map_err(|e| GameError::internal(format!("Kan pennenstreken niet serialiseren: {}", e))));
+                add(DRAWINGS_DIR, &answer.drawing_path, deflated, json)?
+            }
+        };
+        archived.push(ArchivedImage {
+            image_path: answer.image_path.clone(),
+            file,
+            drawing_path: answer.drawing_path.clone(),
+            drawing_file,
+            player_id: player_id.to_string(),
+            question_number: answer.question_number,
+            is_correct: answer.is_correct,
+            timestamp: answer.timestamp.clone(),
+        });
+    }
+
+    let manifest = ArchiveManifest {
+        format_version: ARCHIVE_FORMAT_VERSION,
+        exported_at: exported_at.to_string(),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        game: game.clone(),
+        events,
+        images: archived,
+    };
+    let json = serde_json::to_vec_pretty(&manifest)
+        .map_err(|e| GameError::internal(format!("Kan manifest niet serialiseren: {}", e)))?;
+    zip.start_file(MANIFEST_FILE, deflated).map_err(error)?;
+    zip.write_all(&json).map_err(io_error)?;
+
+    let zip = zip.finish().map_err(error)?.into_inner();
+    Ok((zip, manifest))
+}
+
+/// Alle antwoorden met een tekening, eerst uit de afgeronde rondes en dan uit de lopende.
+/// Na `CompleteRound` staan de antwoorden zowel in de geschiedenis als live; elk pad telt één keer.
+fn archived_answers(game: &GameState) -> Vec<(&str, &Answer)> {
+    let history = game.round_history.iter()
+        .flat_map(|r| &r.answers)
+        .flat_map(|p| p.answers.iter().map(move |a| (p.player_id.as_str(), a)));
+    let live = game.players.iter()
+        .flat_map(|p| p.answers.iter().map(move |a| (p.id.as_str(), a)));
+
+    let mut seen = BTreeSet::new();
+    history.chain(live)
+        .filter(|(_, a)| !a.image_path.is_empty() && seen.insert(a.image_path.as_str()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{Drawing, Engine, GameAction, RuleSet};
+    use crate::game::test_support::{act, complete, game, p, start, TIMESTAMP};
+    use std::io::Read;
+
+    fn answer(engine: &mut Engine, seat: usize, question_number: i32, with_strokes: bool) {
+        let image_path = AnswerImageStore::path_for("game_test", 1, &p(seat), question_number);
+        let drawing_path = match with_strokes {
+            true => AnswerImageStore::drawing_path_for(&image_path),
+            false => String::new(),
+        };
+        act(engine, GameAction::UpdateAnswer { player_id: p(seat), question_number, image_path, drawing_path });
+    }
+
+    /// Paden waar het antwoord na de uitslag naar verwijst: de vastgezette versie
+    fn frozen(engine: &Engine, seat: usize) -> (String, String) {
+        let answer = &game(engine).players[seat].answers[0];
+        (answer.image_path.clone(), answer.drawing_path.clone())
+    }
+
+    #[test]
+    fn test_archive_holds_manifest_and_images() {
+        let dir = std::env::temp_dir().join(format!("mhmot_archive_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let images = AnswerImageStore::new(dir.clone());
+
+        let mut engine = start(&["Anna", "Bert"], RuleSet::default());
+        let png = b"\x89PNG\r\n\x1a\nrest".to_vec();
+        let drawing: Drawing = serde_json::from_str(r#"{"width":600,"height":150,"strokes":[{"points":[{"x":1,"y":2,"pressure":0.5,"t":0}]}]}"#).unwrap();
+        answer(&mut engine, 0, 1, true);
+        answer(&mut engine, 1, 1, false);
+        act(&mut engine, GameAction::CollectInitialBets);
+        act(&mut engine, complete(Some(0)));
+        let (saved, strokes) = frozen(&engine, 0);
+        let (missing, _) = frozen(&engine, 1); // Bestand ontbreekt
+        images.save(&saved, &png).unwrap();
+        images.stage_drawing(&strokes, &drawing).unwrap().commit().unwrap();
+
+        let report = ArchiveStore::new(dir.clone()).export(game(&engine), Vec::new(), &images, "2025-01-01T21:30:00+00:00").unwrap();
+        assert_eq!(report.images, 1);
+        assert_eq!(report.missing_images, vec![missing]);
+        assert!(report.path.ends_with("game_test_20250101213000.zip"));
+
+        let mut zip = zip::ZipArchive::new(fs::File::open(&report.path).unwrap()).unwrap();
+        let mut stored = Vec::new();
+        zip.by_name(&format!("images/{}", saved)).unwrap().read_to_end(&mut stored).unwrap();
+        assert_eq!(stored, png);
+        let archived: Drawing = serde_json::from_reader(zip.by_name(&format!("drawings/{}", strokes)).unwrap()).unwrap();
+        assert_eq!(archived, drawing);
+
+        let manifest: ArchiveManifest = serde_json::from_reader(zip.by_name(MANIFEST_FILE).unwrap()).unwrap();
+        assert_eq!(manifest.format_version, ARCHIVE_FORMAT_VERSION);
+        assert_eq!(manifest.exported_at, "2025-01-01T21:30:00+00:00");
+        assert_eq!(manifest.game.created_at, TIMESTAMP);
+        assert_eq!(manifest.game.round_history.len(), 1);
+        // Antwoorden staan na de uitslag in de geschiedenis én live, maar komen er één keer in
+        assert_eq!(manifest.images.len(), 2);
+        assert_eq!(manifest.images[0].file, Some(format!("images/{}", saved)));
+        assert_eq!(manifest.images[0].drawing_file, Some(format!("drawings/{}", strokes)));
+        assert_eq!(manifest.images[1].file, None);
+        assert_eq!(manifest.images[1].drawing_file, None); // Geen streken, dus ook niet ontbrekend
+    }
+}
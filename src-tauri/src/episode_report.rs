@@ -0,0 +1,304 @@
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::answer_images::AnswerImageStore;
+use crate::game::{Answer, GameError, GameState, RoundResult};
+use crate::handwriting;
+use crate::persistence::write_atomic;
+
+/// Breedte van de tekeningen in het rapport; de hoogte volgt uit het canvas
+const THUMBNAIL_WIDTH: u32 = 240;
+
+/// Afmetingen van de grafiek met het verloop van de saldo's
+const CHART_WIDTH: f32 = 720.0;
+const CHART_HEIGHT: f32 = 260.0;
+const CHART_MARGIN: f32 = 40.0;
+const CHART_COLORS: [&str; 6] = ["#e67e22", "#2980b9", "#27ae60", "#8e44ad", "#c0392b", "#16a085"];
+
+const STYLE: &str = r#"
+body { font-family: -apple-system, "Segoe UI", Roboto, sans-serif; color: #2c3e50; margin: 24px; }
+h1 { color: #e67e22; margin-bottom: 4px; }
+h2 { border-bottom: 2px solid #e67e22; padding-bottom: 4px; margin-top: 32px; }
+.meta { color: #7f8c8d; margin: 0 0 16px; }
+table { border-collapse: collapse; width: 100%; margin: 8px 0; }
+th, td { border: 1px solid #bdc3c7; padding: 4px 8px; text-align: left; vertical-align: middle; }
+th { background: #ecf0f1; }
+td.amount { text-align: right; white-space: nowrap; }
+.round { page-break-inside: avoid; }
+.round-facts span { margin-right: 24px; }
+.answer { display: inline-block; margin: 2px 6px 2px 0; text-align: center; font-size: 11px; }
+.answer img { display: block; height: 40px; border: 2px solid #bdc3c7; border-radius: 4px; }
+.answer.correct img { border-color: #27ae60; }
+.answer.wrong img { border-color: #e74c3c; }
+.missing { color: #c0392b; }
+.legend span { margin-right: 16px; }
+@media print { body { margin: 0; } h2 { page-break-after: avoid; } }
+"#;
+
+/// Rapporten als HTML in `reports/<game_id>_<tijdstip>.html`. Zelfstandige pagina (tekeningen
+/// ingebed), af te drukken of als PDF op te slaan vanuit de browser.
+pub struct ReportStore {
+    dir: PathBuf,
+}
+
+impl ReportStore {
+    pub fn new(data_dir: PathBuf) -> Self {
+        Self { dir: data_dir.join("reports") }
+    }
+
+    pub fn save(&self, game_id: &str, generated_at: &str, html: &str) -> Result<PathBuf, GameError> {
+        fs::create_dir_all(&self.dir)
+            .map_err(|e| GameError::storage(format!("Kan map voor rapporten niet aanmaken: {}", e)))?;
+        let stamp: String = generated_at.chars().take(19).filter(|c| c.is_ascii_digit()).collect();
+        let path = self.dir.join(format!("{}_{}.html", game_id, stamp));
+        write_atomic(&path, html.as_bytes()).map_err(GameError::storage)?;
+        Ok(path)
+    }
+}
+
+/// Rapport van een spel voor de redactie en de uitbetaling van het prijzengeld: eindstand,
+/// verloop van de saldo's en per ronde pot, minimale inzet, wie er paste, aantal goed en de tekeningen
+pub fn render_report(game: &GameState, images: &AnswerImageStore, generated_at: &str) -> String {
+    let title = game.episode.as_ref()
+        .map(|e| e.title.clone())
+        .unwrap_or_else(|| format!("Spel {}", game.game_id));
+
+    let mut html = String::new();
+    html.push_str(&format!(
+        "<!DOCTYPE html>\n<html lang=\"nl\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n",
+        escape(&title), STYLE
+    ));
+    html.push_str(&format!("<h1>{}</h1>\n", escape(&title)));
+    html.push_str(&format!(
+        "<p class=\"meta\">{} · regels: {} · gestart {} · {} · rapport gemaakt {}</p>\n",
+        escape(&game.game_id),
+        escape(&game.rules.name),
+        escape(&game.created_at),
+        if game.is_finished { "afgelopen" } else { "nog bezig" },
+        escape(generated_at),
+    ));
+
+    html.push_str(&ranking(game));
+    html.push_str(&balance_chart(game));
+    html.push_str("<h2>Rondes</h2>\n");
+    for result in &game.round_history {
+        html.push_str(&round_section(game, result, images));
+    }
+    if game.round_history.is_empty() {
+        html.push_str("<p>Nog geen afgeronde rondes.</p>\n");
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+fn ranking(game: &GameState) -> String {
+    let mut html = String::from("<h2>Eindstand</h2>\n<table>\n<tr><th>#</th><th>Speler</th><th>Saldo</th><th>Status</th></tr>\n");
+    for (rank, (player, balance)) in game.get_leaderboard().into_iter().enumerate() {
+        html.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td class=\"amount\">€{}</td><td>{}</td></tr>\n",
+            rank + 1,
+            escape(&player.name),
+            balance,
+            if player.is_active { "" } else { "afgevallen" },
+        ));
+    }
+    html.push_str("</table>\n");
+    html
+}
+
+/// Lijngrafiek (SVG) van het saldo per speler: startsaldo en daarna de stand na elke ronde
+fn balance_chart(game: &GameState) -> String {
+    let rounds = &game.round_history;
+    if rounds.is_empty() {
+        return String::new();
+    }
+
+    let series: Vec<Vec<i32>> = game.players.iter()
+        .map(|player| {
+            let after_rounds = rounds.iter().map(|r| {
+                r.balances.iter().find(|(id, _)| *id == player.id).map(|(_, b)| *b).unwrap_or(0)
+            });
+            std::iter::once(game.rules.starting_balance).chain(after_rounds).collect()
+        })
+        .collect();
+    let max = series.iter().flatten().copied().max().unwrap_or(0).max(1) as f32;
+    let plot_width = CHART_WIDTH - 2.0 * CHART_MARGIN;
+    let plot_height = CHART_HEIGHT - 2.0 * CHART_MARGIN;
+    let x = |step: usize| CHART_MARGIN + plot_width * step as f32 / rounds.len() as f32;
+    let y = |balance: i32| CHART_MARGIN + plot_height * (1.0 - balance.max(0) as f32 / max);
+
+    let mut svg = format!(
+        "<svg width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" xmlns=\"http://www.w3.org/2000/svg\" font-size=\"11\">\n",
+        w = CHART_WIDTH, h = CHART_HEIGHT
+    );
+    // Assen met het hoogste saldo en een label per ronde
+    svg.push_str(&format!(
+        "<line x1=\"{m}\" y1=\"{top}\" x2=\"{m}\" y2=\"{bottom}\" stroke=\"#7f8c8d\"/>\n<line x1=\"{m}\" y1=\"{bottom}\" x2=\"{right}\" y2=\"{bottom}\" stroke=\"#7f8c8d\"/>\n",
+        m = CHART_MARGIN, top = CHART_MARGIN, bottom = CHART_HEIGHT - CHART_MARGIN, right = CHART_WIDTH - CHART_MARGIN
+    ));
+    svg.push_str(&format!("<text x=\"2\" y=\"{}\">€{}</text>\n", CHART_MARGIN + 4.0, max));
+    svg.push_str(&format!("<text x=\"2\" y=\"{}\">€0</text>\n", CHART_HEIGHT - CHART_MARGIN + 4.0));
+    svg.push_str(&format!("<text x=\"{}\" y=\"{}\" text-anchor=\"middle\">Start</text>\n", x(0), CHART_HEIGHT - CHART_MARGIN + 16.0));
+    for (step, result) in rounds.iter().enumerate() {
+        svg.push_str(&format!(
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\">R{}</text>\n",
+            x(step + 1), CHART_HEIGHT - CHART_MARGIN + 16.0, result.round_number
+        ));
+    }
+
+    let mut legend = String::from("<p class=\"legend\">");
+    for (index, (player, balances)) in game.players.iter().zip(&series).enumerate() {
+        let color = CHART_COLORS[index % CHART_COLORS.len()];
+        let points: Vec<String> = balances.iter()
+            .enumerate()
+            .map(|(step, &balance)| format!("{:.1},{:.1}", x(step), y(balance)))
+            .collect();
+        svg.push_str(&format!(
+            "<polyline fill=\"none\" stroke=\"{}\" stroke-width=\"2\" points=\"{}\"/>\n",
+            color, points.join(" ")
+        ));
+        legend.push_str(&format!("<span style=\"color: {}\">■ {}</span>", color, escape(&player.name)));
+    }
+    svg.push_str("</svg>\n");
+    legend.push_str("</p>\n");
+
+    format!("<h2>Verloop van de saldo's</h2>\n{}{}", svg, legend)
+}
+
+fn round_section(game: &GameState, result: &RoundResult, images: &AnswerImageStore) -> String {
+    let player_name = |id: &str| game.players.iter()
+        .find(|p| p.id == id)
+        .map(|p| p.name.clone())
+        .unwrap_or_else(|| id.to_string());
+
+    let winners = if result.winners.is_empty() {
+        vec![format!("{} (€{})", escape(&result.winner_name), result.pot_amount)]
+    } else {
+        result.winners.iter().map(|w| format!("{} (€{})", escape(&w.player_name), w.amount)).collect()
+    };
+    let folded: Vec<String> = result.answers.iter()
+        .filter(|a| a.has_folded)
+        .map(|a| escape(&a.player_name))
+        .collect();
+
+    let mut html = format!("<div class=\"round\">\n<h3>Ronde {}</h3>\n<p class=\"round-facts\">", result.round_number);
+    html.push_str(&format!("<span>Pot: <b>€{}</b></span>", result.pot_amount));
+    if result.min_bet > 0 {
+        html.push_str(&format!("<span>Minimale inzet: €{}</span>", result.min_bet));
+    }
+    html.push_str(&format!("<span>Winnaar: {}</span>", winners.join(", ")));
+    html.push_str(&format!("<span>Gepast: {}</span>", if folded.is_empty() { "niemand".to_string() } else { folded.join(", ") }));
+    html.push_str("</p>\n");
+
+    html.push_str("<table>\n<tr><th>Speler</th><th>Goed</th><th>Saldo na ronde</th><th>Antwoorden</th></tr>\n");
+    for player in &game.players {
+        let round_answers = result.answers.iter().find(|a| a.player_id == player.id);
+        let score = result.player_scores.iter().find(|(id, _)| *id == player.id).map(|(_, s)| *s);
+        if round_answers.is_none() && score.is_none() {
+            continue; // Zat deze ronde niet meer aan tafel
+        }
+        let correct = match (score, round_answers) {
+            (Some(score), _) => score.to_string(),
+            (None, Some(a)) if a.has_folded => "gepast".to_string(),
+            (None, _) => "—".to_string(),
+        };
+        let balance = result.balances.iter()
+            .find(|(id, _)| *id == player.id)
+            .map(|(_, b)| format!("€{}", b))
+            .unwrap_or_default();
+        let answers: String = round_answers
+            .map(|a| a.answers.iter().map(|answer| answer_cell(result, answer, images)).collect())
+            .unwrap_or_default();
+        html.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td class=\"amount\">{}</td><td>{}</td></tr>\n",
+            escape(&player_name(&player.id)), correct, balance, answers
+        ));
+    }
+    html.push_str("</table>\n</div>\n");
+    html
+}
+
+/// Tekening met vraagnummer en oordeel; de vraag en het goede antwoord staan in de tooltip
+fn answer_cell(result: &RoundResult, answer: &Answer, images: &AnswerImageStore) -> String {
+    let (class, verdict) = match answer.is_correct {
+        Some(true) => ("correct", "✓"),
+        Some(false) => ("wrong", "✗"),
+        None => ("open", "?"),
+    };
+    let question = usize::try_from(answer.question_number - 1).ok()
+        .and_then(|i| result.questions.get(i))
+        .map(|q| format!("{} — {}", q.text, q.answer))
+        .unwrap_or_default();
+    let image = match thumbnail(answer, images) {
+        Some(data_url) => format!("<img src=\"{}\" alt=\"Antwoord {}\">", data_url, answer.question_number),
+        None => "<span class=\"missing\">geen tekening</span>".to_string(),
+    };
+    format!(
+        "<span class=\"answer {}\" title=\"{}\">{}V{} {}</span>",
+        class, escape(&question), image, answer.question_number, verdict
+    )
+}
+
+/// Kleine PNG als data URL: uit de pennenstreken, of het opgeslagen bestand bij antwoorden zonder streken
+fn thumbnail(answer: &Answer, images: &AnswerImageStore) -> Option<String> {
+    let png = if answer.drawing_path.is_empty() {
+        if answer.image_path.is_empty() {
+            return None;
+        }
+        images.read(&answer.image_path)
+    } else {
+        images.read_drawing(&answer.drawing_path).and_then(|drawing| {
+            let (width, height) = handwriting::output_size(&drawing, Some(THUMBNAIL_WIDTH), None, 1.0)?;
+            handwriting::render_png(&drawing, width, height)
+        })
+    };
+    match png {
+        Ok(png) => Some(format!("data:image/png;base64,{}", BASE64.encode(png))),
+        Err(e) => {
+            println!("[render_report] ⚠️  Tekening {} niet in rapport: {}", answer.image_path, e);
+            None
+        }
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::RuleSet;
+    use crate::game::test_support::{act, answer, complete, fold, game, open_first_betting, start, TIMESTAMP};
+
+    #[test]
+    fn test_report_covers_every_round() {
+        let images = AnswerImageStore::new(std::env::temp_dir().join(format!("mhmot_report_{}", std::process::id())));
+        let mut engine = start(&["Anna", "<Bert>", "Carla"], RuleSet::default());
+        for action in answer(0, 1, true) {
+            act(&mut engine, action); // Tekening staat niet op schijf
+        }
+        open_first_betting(&mut engine);
+        act(&mut engine, fold(0));
+        act(&mut engine, complete(Some(1)));
+
+        let game = game(&engine);
+        let html = render_report(game, &images, TIMESTAMP);
+        let result = &game.round_history[0];
+
+        assert!(html.contains("<h3>Ronde 1</h3>"));
+        assert!(html.contains(&format!("Pot: <b>€{}</b>", result.pot_amount)));
+        assert!(html.contains(&format!("Minimale inzet: €{}", result.min_bet)));
+        assert!(html.contains("Gepast: Anna"));
+        assert!(html.contains("&lt;Bert&gt;") && !html.contains("<Bert>"));
+        assert!(html.contains("geen tekening"));
+        // Eén lijn per speler in de grafiek
+        assert_eq!(html.matches("<polyline").count(), 3);
+    }
+}
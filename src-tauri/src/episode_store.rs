@@ -0,0 +1,111 @@
+use serde::Serialize;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::game::{Episode, ErrorCode, GameError, RuleSet};
+use crate::persistence::write_atomic;
+
+/// Kort overzicht van een voorbereide aflevering voor de keuzelijst
+#[derive(Debug, Clone, Serialize)]
+pub struct EpisodeSummary {
+    pub id: String,
+    pub title: String,
+    pub recording_date: Option<String>,
+    pub player_names: Vec<String>,
+}
+
+/// Voorbereide draaiboeken, één JSON bestand per aflevering in `episodes/<id>.json`
+pub struct EpisodeStore {
+    dir: PathBuf,
+}
+
+impl EpisodeStore {
+    pub fn new(data_dir: PathBuf) -> Self {
+        Self { dir: data_dir.join("episodes") }
+    }
+
+    fn path_for(&self, id: &str) -> Result<PathBuf, GameError> {
+        Episode::check_id(id)?;
+        Ok(self.dir.join(format!("{}.json", id)))
+    }
+
+    pub fn save(&self, episode: &Episode, rules: &RuleSet) -> Result<(), GameError> {
+        episode.validate(rules)?;
+        fs::create_dir_all(&self.dir)
+            .map_err(|e| GameError::storage(format!("Kan map voor draaiboeken niet aanmaken: {}", e)))?;
+
+        let json = serde_json::to_vec_pretty(episode)
+            .map_err(|e| GameError::internal(format!("Kan draaiboek niet serialiseren: {}", e)))?;
+        write_atomic(&self.path_for(&episode.id)?, &json).map_err(GameError::storage)?;
+
+        println!("[episode_store] Draaiboek '{}' opgeslagen ({})", episode.title, episode.id);
+        Ok(())
+    }
+
+    pub fn load(&self, id: &str) -> Result<Episode, GameError> {
+        let data = fs::read(self.path_for(id)?)
+            .map_err(|e| GameError::new(ErrorCode::NotFound, format!("Draaiboek {} niet gevonden: {}", id, e)))?;
+        serde_json::from_slice(&data)
+            .map_err(|e| GameError::storage(format!("Draaiboek {} is beschadigd: {}", id, e)))
+    }
+
+    pub fn delete(&self, id: &str) -> Result<(), GameError> {
+        fs::remove_file(self.path_for(id)?)
+            .map_err(|e| GameError::storage(format!("Kan draaiboek {} niet verwijderen: {}", id, e)))
+    }
+
+    /// Alle draaiboeken, op opnamedatum. Onleesbare bestanden worden overgeslagen.
+    pub fn list(&self) -> Result<Vec<EpisodeSummary>, GameError> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let entries = fs::read_dir(&self.dir)
+            .map_err(|e| GameError::storage(format!("Kan draaiboeken niet lezen: {}", e)))?;
+
+        let mut episodes = Vec::new();
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            let Some(id) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            match self.load(id) {
+                Ok(episode) => episodes.push(EpisodeSummary {
+                    id: episode.id,
+                    title: episode.title,
+                    recording_date: episode.recording_date,
+                    player_names: episode.player_names,
+                }),
+                Err(e) => println!("[episode_store] ⚠️  {}", e),
+            }
+        }
+
+        episodes.sort_by(|a, b| a.recording_date.cmp(&b.recording_date).then_with(|| a.title.cmp(&b.title)));
+        Ok(episodes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ids_never_point_outside_the_episodes_dir() {
+        let data_dir = std::env::temp_dir().join(format!("mhmot_episode_store_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&data_dir);
+        fs::create_dir_all(&data_dir).unwrap();
+        let outside = data_dir.join("current_game.json");
+        fs::write(&outside, "{}").unwrap();
+
+        let store = EpisodeStore::new(data_dir.clone());
+        for id in ["../current_game", "..\\current_game", "sub/aflevering", "current_game.json", ""] {
+            assert_eq!(store.delete(id).unwrap_err().code, ErrorCode::InvalidInput, "{}", id);
+            assert_eq!(store.load(id).unwrap_err().code, ErrorCode::InvalidInput, "{}", id);
+        }
+        assert!(outside.exists());
+        assert_eq!(store.load("aflevering_1").unwrap_err().code, ErrorCode::NotFound);
+    }
+}
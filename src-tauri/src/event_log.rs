@@ -0,0 +1,94 @@
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+use crate::game::GameEvent;
+
+/// Append-only log van alle spelacties, één JSON regel per event in `events/<game_id>.jsonl`.
+/// Bedoeld voor audits bij discussies over inzetten en om een spel opnieuw af te spelen.
+pub struct EventLog {
+    dir: PathBuf,
+    game_id: Option<String>,
+    next_sequence: u64,
+}
+
+impl EventLog {
+    pub fn new(data_dir: PathBuf) -> Self {
+        Self {
+            dir: data_dir.join("events"),
+            game_id: None,
+            next_sequence: 0,
+        }
+    }
+
+    fn path_for(&self, game_id: &str) -> PathBuf {
+        self.dir.join(format!("{}.jsonl", game_id))
+    }
+
+    /// Koppel het log aan een spel. Bestaat er al een log (hervat spel),
+    /// dan gaat de nummering verder waar die gebleven was.
+    pub fn open(&mut self, game_id: &str) -> Result<(), String> {
+        let existing = self.read(game_id)?;
+        self.next_sequence = existing.last().map(|e| e.sequence + 1).unwrap_or(0);
+        self.game_id = Some(game_id.to_string());
+        Ok(())
+    }
+
+    pub fn next_sequence(&self) -> u64 {
+        self.next_sequence
+    }
+
+    pub fn game_id(&self) -> Option<&str> {
+        self.game_id.as_deref()
+    }
+
+    pub fn append(&mut self, event: &GameEvent) -> Result<(), String> {
+        let game_id = self.game_id.clone()
+            .ok_or_else(|| "Event log is niet aan een spel gekoppeld".to_string())?;
+
+        fs::create_dir_all(&self.dir)
+            .map_err(|e| format!("Kan event map niet aanmaken: {}", e))?;
+
+        let mut line = serde_json::to_string(event)
+            .map_err(|e| format!("Kan event niet serialiseren: {}", e))?;
+        line.push('\n');
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.path_for(&game_id))
+            .map_err(|e| format!("Kan event log niet openen: {}", e))?;
+        file.write_all(line.as_bytes())
+            .map_err(|e| format!("Kan event niet wegschrijven: {}", e))?;
+        file.sync_data()
+            .map_err(|e| format!("Kan event niet wegschrijven: {}", e))?;
+
+        self.next_sequence = event.sequence + 1;
+        Ok(())
+    }
+
+    /// Lees alle events van een spel in volgorde. Een half geschreven laatste regel
+    /// (crash tijdens schrijven) wordt overgeslagen.
+    pub fn read(&self, game_id: &str) -> Result<Vec<GameEvent>, String> {
+        let path = self.path_for(game_id);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let file = fs::File::open(&path)
+            .map_err(|e| format!("Kan event log niet lezen: {}", e))?;
+
+        let mut events = Vec::new();
+        for (line_number, line) in BufReader::new(file).lines().enumerate() {
+            let line = line.map_err(|e| format!("Kan event log niet lezen: {}", e))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str::<GameEvent>(&line) {
+                Ok(event) => events.push(event),
+                Err(e) => println!("[event_log] ⚠️  Regel {} overgeslagen: {}", line_number + 1, e),
+            }
+        }
+        Ok(events)
+    }
+}
@@ -1,7 +0,0 @@
-pub mod player;
-pub mod round;
-pub mod state;
-
-pub use player::{Player, Answer};
-pub use round::{Round, BettingPhase, RoundResult};
-pub use state::GameState;
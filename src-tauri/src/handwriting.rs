@@ -0,0 +1,237 @@
+//! Tekeningen van de tablets renderen vanuit de pennenstreken: een PNG op elk formaat
+//! (scherp op broadcast resolutie) en een geanimeerde PNG van het schrijven voor replays.
+
+use tiny_skia::{Color, LineCap, Paint, PathBuilder, Pixmap, Stroke as Pen, Transform};
+
+use crate::game::{Drawing, GameError};
+
+/// Zelfde kleur en lijndikte als het canvas op de tablet
+const INK: (u8, u8, u8) = (0x2c, 0x3e, 0x50);
+const LINE_WIDTH: f32 = 5.0;
+
+/// Opgeslagen PNG is twee keer het tablet canvas, scherp genoeg voor de graphics
+pub const STORED_SCALE: f32 = 2.0;
+
+/// Grootste afbeelding die we op verzoek renderen
+pub const MAX_RENDER_SIZE: u32 = 4096;
+
+/// Instellingen voor de animatie van het schrijven
+const ANIMATION_FPS: u32 = 25;
+const ANIMATION_MAX_PAUSE_MS: u32 = 400; // Nadenken tussen streken wordt ingekort tot dit
+const ANIMATION_MAX_FRAMES: u32 = 150;   // Langer schrijven wordt versneld afgespeeld
+const ANIMATION_HOLD_MS: u32 = 2_000;    // Eindbeeld blijft zo lang staan
+
+/// Grootste animatie die we op verzoek renderen: broadcast breedte is genoeg voor een replay
+pub const MAX_ANIMATION_WIDTH: u32 = 1920;
+pub const MAX_ANIMATION_HEIGHT: u32 = 1080;
+
+/// Afmetingen in pixels voor een gevraagde breedte en/of hoogte. Ontbreekt er één,
+/// dan volgt die uit de verhouding van het canvas; zonder beide de canvasgrootte × `scale`.
+pub fn output_size(drawing: &Drawing, width: Option<u32>, height: Option<u32>, scale: f32) -> Result<(u32, u32), GameError> {
+    let (canvas_width, canvas_height) = if drawing.width > 0.0 && drawing.height > 0.0 {
+        (drawing.width, drawing.height)
+    } else {
+        (600.0, 150.0) // Leeg antwoord zonder afmetingen: formaat van het tablet canvas
+    };
+    let ratio = canvas_height / canvas_width;
+    let (width, height) = match (width, height) {
+        (Some(w), Some(h)) => (w, h),
+        (Some(w), None) => (w, (w as f32 * ratio).round() as u32),
+        (None, Some(h)) => ((h as f32 / ratio).round() as u32, h),
+        (None, None) => ((canvas_width * scale).round() as u32, (canvas_height * scale).round() as u32),
+    };
+    if width == 0 || height == 0 || width > MAX_RENDER_SIZE || height > MAX_RENDER_SIZE {
+        return Err(GameError::invalid_input(format!(
+            "Formaat {}x{} kan niet, maximaal {}x{}", width, height, MAX_RENDER_SIZE, MAX_RENDER_SIZE
+        )));
+    }
+    Ok((width, height))
+}
+
+/// Tekening als PNG met transparante achtergrond, passend en gecentreerd in `width` x `height`
+pub fn render_png(drawing: &Drawing, width: u32, height: u32) -> Result<Vec<u8>, GameError> {
+    let mut canvas = Canvas::new(drawing, width, height)?;
+    for (stroke_index, stroke) in drawing.strokes.iter().enumerate() {
+        canvas.draw(drawing, stroke_index, 0..stroke.points.len());
+    }
+    canvas.pixmap.encode_png()
+        .map_err(|e| GameError::internal(format!("Kan tekening niet als PNG opslaan: {}", e)))
+}
+
+/// Past een animatie van dit formaat binnen de grenzen? Elk frame wordt volledig gerenderd,
+/// dus de grens ligt veel lager dan bij een stilstaande PNG.
+pub fn check_animation_size(width: u32, height: u32) -> Result<(), GameError> {
+    if width > MAX_ANIMATION_WIDTH || height > MAX_ANIMATION_HEIGHT {
+        return Err(GameError::invalid_input(format!(
+            "Animatie van {}x{} kan niet, maximaal {}x{}", width, height, MAX_ANIMATION_WIDTH, MAX_ANIMATION_HEIGHT
+        )));
+    }
+    Ok(())
+}
+
+/// Het schrijven als geanimeerde PNG (APNG), in de volgorde en het tempo van de speler.
+/// Wordt één keer afgespeeld en blijft op de volledige tekening staan. Elk frame gaat direct
+/// naar de encoder, zodat er maar één frame tegelijk in het geheugen staat.
+pub fn render_animation(drawing: &Drawing, width: u32, height: u32) -> Result<Vec<u8>, GameError> {
+    check_animation_size(width, height)?;
+    let times = drawing.replay_times(ANIMATION_MAX_PAUSE_MS);
+    let duration = times.iter().flatten().copied().max().unwrap_or(0);
+    let frame_count = (duration / (1000 / ANIMATION_FPS) + 1).min(ANIMATION_MAX_FRAMES);
+    let frame_ms = (duration / frame_count).max(1);
+
+    let mut canvas = Canvas::new(drawing, width, height)?;
+    let error = |e: png::EncodingError| GameError::internal(format!("Kan animatie niet opslaan: {}", e));
+    let mut out = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut out, width, height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_animated(frame_count, 1).map_err(error)?;
+        let mut writer = encoder.write_header().map_err(error)?;
+
+        let mut drawn = vec![0usize; drawing.strokes.len()]; // Aantal getekende punten per streek
+        for frame in 1..=frame_count {
+            let last = frame == frame_count;
+            let until = if last { u32::MAX } else { frame * frame_ms };
+            for (stroke_index, stroke_times) in times.iter().enumerate() {
+                let visible = stroke_times.iter().take_while(|&&t| t <= until).count();
+                if visible > drawn[stroke_index] {
+                    canvas.draw(drawing, stroke_index, drawn[stroke_index]..visible);
+                    drawn[stroke_index] = visible;
+                }
+            }
+            let delay = if last { ANIMATION_HOLD_MS } else { frame_ms };
+            writer.set_frame_delay(delay.min(u16::MAX as u32) as u16, 1000).map_err(error)?;
+            writer.write_image_data(&canvas.rgba()).map_err(error)?;
+        }
+        writer.finish().map_err(error)?;
+    }
+    Ok(out)
+}
+
+/// Pixmap met de transformatie van canvas coördinaten naar pixels
+struct Canvas {
+    pixmap: Pixmap,
+    transform: Transform,
+}
+
+impl Canvas {
+    fn new(drawing: &Drawing, width: u32, height: u32) -> Result<Self, GameError> {
+        drawing.validate()?;
+        let pixmap = Pixmap::new(width, height)
+            .ok_or_else(|| GameError::invalid_input(format!("Formaat {}x{} kan niet", width, height)))?;
+        if drawing.is_empty() {
+            return Ok(Self { pixmap, transform: Transform::identity() });
+        }
+
+        let scale = (width as f32 / drawing.width).min(height as f32 / drawing.height);
+        let offset_x = (width as f32 - drawing.width * scale) / 2.0;
+        let offset_y = (height as f32 - drawing.height * scale) / 2.0;
+        let transform = Transform::from_row(scale, 0.0, 0.0, scale, offset_x, offset_y);
+        Ok(Self { pixmap, transform })
+    }
+
+    /// Teken de punten `range` van een streek. Elk stukje krijgt de dikte van de druk op dat
+    /// moment; losse tikken (één punt) worden een stip, net als op de tablet.
+    fn draw(&mut self, drawing: &Drawing, stroke_index: usize, range: std::ops::Range<usize>) {
+        let points = &drawing.strokes[stroke_index].points;
+        let mut paint = Paint::default();
+        paint.set_color(Color::from_rgba8(INK.0, INK.1, INK.2, 255));
+        paint.anti_alias = true;
+
+        for index in range {
+            let point = points[index];
+            let previous = if index == 0 { point } else { points[index - 1] };
+            let mut path = PathBuilder::new();
+            path.move_to(previous.x, previous.y);
+            if index == 0 {
+                path.line_to(point.x + 0.5, point.y + 0.5);
+            } else {
+                path.line_to(point.x, point.y);
+            }
+            let Some(path) = path.finish() else {
+                continue;
+            };
+
+            let pressure = (previous.pressure + point.pressure) / 2.0;
+            let pen = Pen {
+                width: LINE_WIDTH * (0.5 + pressure),
+                line_cap: LineCap::Round,
+                ..Pen::default()
+            };
+            self.pixmap.stroke_path(&path, &paint, &pen, self.transform, None);
+        }
+    }
+
+    /// Pixels voor een PNG frame: tiny-skia werkt met voorvermenigvuldigde alpha, PNG niet
+    fn rgba(&self) -> Vec<u8> {
+        self.pixmap.pixels().iter()
+            .flat_map(|pixel| {
+                let color = pixel.demultiply();
+                [color.red(), color.green(), color.blue(), color.alpha()]
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{Stroke, StrokePoint};
+
+    fn line(t0: u32) -> Stroke {
+        let points = (0..=10)
+            .map(|i| StrokePoint { x: 100.0 + i as f32 * 40.0, y: 75.0, pressure: 0.5, t: t0 + i * 50 })
+            .collect();
+        Stroke { points }
+    }
+
+    fn drawing() -> Drawing {
+        Drawing { width: 600.0, height: 150.0, strokes: vec![line(0), line(5_000)] }
+    }
+
+    fn alpha_at(png: &[u8], x: u32, y: u32) -> u8 {
+        let pixmap = Pixmap::decode_png(png).unwrap();
+        pixmap.pixel(x, y).unwrap().alpha()
+    }
+
+    #[test]
+    fn test_size_follows_the_canvas_ratio() {
+        assert_eq!(output_size(&drawing(), None, None, 2.0).unwrap(), (1200, 300));
+        assert_eq!(output_size(&drawing(), Some(1920), None, 1.0).unwrap(), (1920, 480));
+        assert_eq!(output_size(&drawing(), None, Some(75), 1.0).unwrap(), (300, 75));
+        assert!(output_size(&drawing(), Some(100_000), None, 1.0).is_err());
+    }
+
+    #[test]
+    fn test_renders_at_any_size() {
+        let small = render_png(&drawing(), 600, 150).unwrap();
+        let large = render_png(&drawing(), 1920, 480).unwrap();
+        assert!(alpha_at(&small, 300, 75) > 0);
+        assert!(alpha_at(&large, 960, 240) > 0);
+        assert_eq!(alpha_at(&large, 960, 20), 0);
+
+        // Ander formaat dan het canvas: gecentreerd, niet uitgerekt
+        let square = render_png(&drawing(), 600, 600).unwrap();
+        assert!(alpha_at(&square, 300, 300) > 0);
+        assert_eq!(alpha_at(&square, 300, 75), 0);
+    }
+
+    #[test]
+    fn test_animation_has_one_frame_per_step_and_ends_complete() {
+        let apng = render_animation(&drawing(), 300, 75).unwrap();
+        let decoder = png::Decoder::new(apng.as_slice());
+        let reader = decoder.read_info().unwrap();
+        let control = reader.info().animation_control.unwrap();
+        // 500 ms per streek plus 400 ms ingekorte pauze = 1,4 s bij 25 fps
+        assert_eq!(control.num_frames, 36);
+        assert_eq!(control.num_plays, 1);
+
+        let empty = render_animation(&Drawing::default(), 300, 75).unwrap();
+        assert!(!empty.is_empty());
+
+        // Elk frame wordt volledig gerenderd: animaties hebben een eigen, lagere grens
+        assert!(render_animation(&drawing(), 4096, 4096).is_err());
+        assert!(check_animation_size(MAX_ANIMATION_WIDTH, MAX_ANIMATION_HEIGHT).is_ok());
+    }
+}
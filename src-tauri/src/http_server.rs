@@ -1,26 +1,172 @@
 use axum::{
-    extract::State,
-    http::{Method, StatusCode},
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Path, Query, State,
+    },
+    http::{header, Method, StatusCode},
     response::{Html, IntoResponse, Response},
     routing::{get, post},
     Json, Router,
 };
-use chrono;
 use serde::Deserialize;
-use std::sync::{Arc, Mutex};
 use std::path::PathBuf;
+use tokio::sync::broadcast::error::RecvError;
 use tower_http::cors::{Any, CorsLayer};
 use tower_http::services::ServeDir;
 
-use crate::game::GameState;
+use crate::commands::AppState;
+use crate::game::{Answer, Drawing, ErrorCode, GameError, GameState, PlayerTimeline, Question};
+use crate::handwriting;
 
-pub type SharedGameState = Arc<Mutex<Option<GameState>>>;
+#[derive(Deserialize)]
+struct LiveQuery {
+    client: Option<String>,    // "fill", "key", "player" of "full" (standaard)
+    player_id: Option<String>, // Verplicht voor "player"
+}
+
+/// Welk deel van de state een live client nodig heeft.
+/// Tekeningen staan als bestand op schijf; een client krijgt alleen de paden van de tekeningen die hij toont,
+/// en de pennenstreken alleen als hij verder moet kunnen tekenen. De goede antwoorden en het draaiboek
+/// zijn alleen voor de quizmaster: tablets en graphics zien de vragen zonder antwoord.
+#[derive(Debug, Clone, PartialEq)]
+enum ClientScope {
+    Full,             // Alles (quizmaster, debug)
+    Fill,             // Fill graphics: alle tekeningen, zonder streken
+    Key,              // Key graphics: alleen standen, geen tekeningen
+    Player(String),   // Tablet: alleen eigen tekeningen, met streken
+}
+
+impl ClientScope {
+    fn from_query(query: &LiveQuery) -> Result<Self, GameError> {
+        match query.client.as_deref().unwrap_or("full") {
+            "full" => Ok(ClientScope::Full),
+            "fill" => Ok(ClientScope::Fill),
+            "key" => Ok(ClientScope::Key),
+            "player" => query.player_id.clone()
+                .map(ClientScope::Player)
+                .ok_or_else(|| GameError::invalid_input("player_id is verplicht voor client=player")),
+            other => Err(GameError::invalid_input(format!("Onbekend client type: {}", other))),
+        }
+    }
+
+    /// Kopie van de state met alleen wat deze client nodig heeft
+    fn scoped_state(&self, game: &GameState) -> GameState {
+        let mut scoped = game.clone();
+        for player in &mut scoped.players {
+            let (keep_images, keep_strokes) = self.visible_answers(&player.id);
+            scrub_answers(&mut player.answers, keep_images, keep_strokes);
+        }
+        // Tablets en fill graphics tonen geen saldoverloop; die staat ook op /api/balance_timeline
+        if matches!(self, ClientScope::Fill | ClientScope::Player(_)) {
+            scoped.balance_timeline.clear();
+        }
+        // Afgeronde rondes: dezelfde tekeningen; verder tekenen kan daar niet, dus streken alleen in de volledige state
+        for result in &mut scoped.round_history {
+            for round_answers in &mut result.answers {
+                let (keep_images, _) = self.visible_answers(&round_answers.player_id);
+                let keep_strokes = *self == ClientScope::Full;
+                scrub_answers(&mut round_answers.answers, keep_images, keep_strokes);
+            }
+        }
+        if *self != ClientScope::Full {
+            scoped.episode = None;
+            if let Some(round) = &mut scoped.current_round {
+                hide_solutions(&mut round.questions);
+                round.question_notes.clear();
+            }
+            for result in &mut scoped.round_history {
+                hide_solutions(&mut result.questions);
+            }
+        }
+        scoped
+    }
+
+    /// (tekeningen, streken) die deze client van een speler mag zien
+    fn visible_answers(&self, player_id: &str) -> (bool, bool) {
+        match self {
+            ClientScope::Full => (true, true),
+            ClientScope::Fill => (true, false),
+            ClientScope::Key => (false, false),
+            ClientScope::Player(id) => (player_id == id, player_id == id),
+        }
+    }
+}
+
+fn scrub_answers(answers: &mut [Answer], keep_images: bool, keep_strokes: bool) {
+    for answer in answers {
+        if !keep_images {
+            answer.image_path.clear();
+        }
+        if !keep_strokes {
+            answer.drawing_path.clear();
+        }
+    }
+}
+
+/// Vragen zonder antwoord en goedgekeurde varianten
+fn hide_solutions(questions: &mut [Question]) {
+    for question in questions {
+        question.answer.clear();
+        question.accepted_variants.clear();
+    }
+}
+
+/// Fouten gaan als `{ "code", "message" }` JSON naar de client, met een passende HTTP status
+struct ApiError(GameError);
+
+impl From<GameError> for ApiError {
+    fn from(e: GameError) -> Self {
+        Self(e)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match self.0.code {
+            ErrorCode::PlayerNotFound | ErrorCode::NotFound => StatusCode::NOT_FOUND,
+            ErrorCode::InvalidInput | ErrorCode::InvalidBet => StatusCode::BAD_REQUEST,
+            ErrorCode::NoActiveGame
+            | ErrorCode::NoActiveRound
+            | ErrorCode::GameFinished
+            | ErrorCode::PlayerNotActive
+            | ErrorCode::NotPlayersTurn
+            | ErrorCode::IllegalPhase
+            | ErrorCode::InsufficientBalance
+            | ErrorCode::TieUnresolved
+            | ErrorCode::NothingToUndo
+            | ErrorCode::WritingLocked => StatusCode::CONFLICT,
+            ErrorCode::StorageError
+            | ErrorCode::SerialPortError
+            | ErrorCode::Internal
+            | ErrorCode::MoneyMismatch => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        (status, Json(self.0)).into_response()
+    }
+}
 
 #[derive(Deserialize)]
 struct UpdateAnswerRequest {
     player_id: String,
     question_number: i32,
-    image_data: String,
+    drawing: Drawing,
+}
+
+#[derive(Deserialize)]
+struct AnswerImageQuery {
+    width: Option<u32>,
+    height: Option<u32>,
+    #[serde(default)]
+    animate: bool, // Geanimeerde PNG van het schrijven, voor replays
+}
+
+#[derive(Deserialize)]
+struct BalanceTimelineQuery {
+    player_id: Option<String>, // Leeg = alle spelers
+}
+
+#[derive(Deserialize)]
+struct ReportQuery {
+    game_id: Option<String>, // Leeg = het actieve spel
 }
 
 // SPA fallback handler - serves index.html for all non-API routes
@@ -34,7 +180,7 @@ async fn spa_fallback() -> Response {
     }
 }
 
-pub async fn start_http_server(game_state: SharedGameState) {
+pub async fn start_http_server(app_state: AppState) {
     let cors = CorsLayer::new()
         .allow_methods([Method::GET, Method::POST])
         .allow_origin(Any)
@@ -47,7 +193,12 @@ pub async fn start_http_server(game_state: SharedGameState) {
         // API routes
         .route("/api/gamestate", get(get_game_state))
         .route("/api/update_answer", post(update_answer))
-        .with_state(game_state.clone())
+        .route("/api/answers/*image_path", get(answer_image))
+        .route("/api/drawings/*drawing_path", get(answer_drawing))
+        .route("/api/balance_timeline", get(balance_timeline))
+        .route("/api/report", get(episode_report))
+        .route("/api/ws", get(live_updates))
+        .with_state(app_state)
         .layer(cors.clone())
         // Serve static files from dist directory
         .nest_service("/assets", ServeDir::new(assets_dir.join("assets")))
@@ -75,6 +226,8 @@ pub async fn start_http_server(game_state: SharedGameState) {
     println!("      - http://localhost:3001/player2 (of http://{}:3001/player2)", local_ip);
     println!("      - http://localhost:3001/player3 (of http://{}:3001/player3)", local_ip);
     println!("   📊 API: http://localhost:3001/api/gamestate");
+    println!("   ✏️  Tekeningen: http://localhost:3001/api/answers/<image_path>");
+    println!("   ⚡ Live: ws://localhost:3001/api/ws?client=fill|key|player&player_id=...");
     
     axum::serve(listener, app)
         .await
@@ -153,47 +306,227 @@ fn get_assets_dir() -> PathBuf {
     dev_path
 }
 
+/// Huidige state, net als bij `/api/ws` geschaald naar `?client=` (standaard alles)
 async fn get_game_state(
-    State(game_state): State<SharedGameState>,
-) -> Json<Option<GameState>> {
-    let state = game_state.lock().unwrap();
-    Json(state.clone())
+    State(app_state): State<AppState>,
+    Query(query): Query<LiveQuery>,
+) -> Result<Json<Option<GameState>>, ApiError> {
+    let scope = ClientScope::from_query(&query)?;
+    let engine = app_state.engine.lock().map_err(GameError::from)?;
+    Ok(Json(engine.game().map(|game| scope.scoped_state(game))))
 }
 
 async fn update_answer(
-    State(game_state): State<SharedGameState>,
+    State(app_state): State<AppState>,
     Json(payload): Json<UpdateAnswerRequest>,
-) -> Json<bool> {
-    println!("[HTTP update_answer] Received: player_id={}, question_number={}, image_data_length={}", 
-        payload.player_id, payload.question_number, payload.image_data.len());
-    
-    let mut state_guard = game_state.lock().unwrap();
+) -> Result<Json<bool>, ApiError> {
+    println!("[HTTP update_answer] Received: player_id={}, question_number={}, points={}", 
+        payload.player_id, payload.question_number, payload.drawing.point_count());
     
-    if let Some(state) = state_guard.as_mut() {
-        if let Some(player) = state.players.iter_mut().find(|p| p.id == payload.player_id) {
-            println!("[HTTP update_answer] Found player: {} ({})", player.name, player.id);
-            
-            // Remove old answer if exists
-            player.answers.retain(|a| a.question_number != payload.question_number);
-            
-            // Add new answer
-            let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
-            player.answers.push(crate::game::Answer {
-                question_number: payload.question_number,
-                image_data: payload.image_data,
-                is_correct: None,
-                timestamp,
+    app_state.save_answer(payload.player_id, payload.question_number, payload.drawing)
+        .map(|_| Json(true))
+        .map_err(|e| {
+            println!("[HTTP update_answer] {:?}: {}", e.code, e);
+            ApiError(e)
+        })
+}
+
+/// Tekening van een antwoord als PNG; het pad komt uit `Answer.image_path`.
+/// Clients zetten `?v=<timestamp van het antwoord>` erachter zodat een nieuwe versie niet uit de cache komt.
+/// Met `width` en/of `height` wordt de tekening uit de pennenstreken op dat formaat gerenderd,
+/// met `animate=true` als animatie van het schrijven (tot `MAX_ANIMATION_WIDTH` x `MAX_ANIMATION_HEIGHT`).
+async fn answer_image(
+    State(app_state): State<AppState>,
+    Path(image_path): Path<String>,
+    Query(query): Query<AnswerImageQuery>,
+) -> Result<Response, ApiError> {
+    let rerender = query.width.is_some() || query.height.is_some() || query.animate;
+    if query.animate {
+        // Te grote animaties meteen weigeren, nog voor we de tekening zoeken
+        handwriting::check_animation_size(query.width.unwrap_or(0), query.height.unwrap_or(0))?;
+    }
+    let drawing = match rerender {
+        true => Some(app_state.find_drawing(&image_path)?),
+        false => None,
+    };
+
+    let png = match drawing {
+        // Antwoorden van vóór de pennenstreken hebben alleen het opgeslagen bestand
+        Some(drawing) if !drawing.is_empty() => {
+            let (width, height) = handwriting::output_size(&drawing, query.width, query.height, handwriting::STORED_SCALE)?;
+            if query.animate {
+                handwriting::check_animation_size(width, height)?;
+            }
+            let render = tokio::task::spawn_blocking(move || match query.animate {
+                true => handwriting::render_animation(&drawing, width, height),
+                false => handwriting::render_png(&drawing, width, height),
             });
-            
-            println!("[HTTP update_answer] Answer updated. Player now has {} answers", player.answers.len());
-            return Json(true);
-        } else {
-            println!("[HTTP update_answer] Player not found: {}", payload.player_id);
+            render.await.map_err(|e| GameError::internal(format!("Renderen mislukt: {}", e)))??
         }
-    } else {
-        println!("[HTTP update_answer] No game state available");
+        _ => app_state.answer_images.read(&image_path)?,
+    };
+    Ok(([(header::CONTENT_TYPE, "image/png"), (header::CACHE_CONTROL, "no-cache")], png).into_response())
+}
+
+/// Pennenstreken van een antwoord, op het pad uit `Answer.drawing_path`. Zodat een tablet na
+/// een herstart verder kan schrijven op het eigen antwoord.
+async fn answer_drawing(
+    State(app_state): State<AppState>,
+    Path(drawing_path): Path<String>,
+) -> Result<Json<Drawing>, ApiError> {
+    Ok(Json(app_state.answer_images.read_drawing(&drawing_path)?))
+}
+
+/// Saldoverloop per speler, voor de "geld over de avond" grafiek in de graphics
+async fn balance_timeline(
+    State(app_state): State<AppState>,
+    Query(query): Query<BalanceTimelineQuery>,
+) -> Result<Json<Vec<PlayerTimeline>>, ApiError> {
+    let game = app_state.current_game()?;
+    Ok(Json(game.balance_timelines(query.player_id.as_deref())?))
+}
+
+/// Rapport van de aflevering als HTML pagina, om vanuit de browser af te drukken of als PDF op te slaan
+async fn episode_report(
+    State(app_state): State<AppState>,
+    Query(query): Query<ReportQuery>,
+) -> Result<Response, ApiError> {
+    // Tekeningen renderen kost even; niet op de async runtime
+    let render = tokio::task::spawn_blocking(move || app_state.episode_report(query.game_id));
+    let (_, html) = render.await.map_err(|e| GameError::internal(format!("Rapport maken mislukt: {}", e)))??;
+    Ok(([(header::CACHE_CONTROL, "no-cache")], Html(html)).into_response())
+}
+
+/// WebSocket endpoint: stuurt bij verbinden de huidige state en daarna elke wijziging,
+/// geschaald naar het client type. Ongewijzigde payloads worden niet opnieuw verstuurd.
+async fn live_updates(
+    ws: WebSocketUpgrade,
+    Query(query): Query<LiveQuery>,
+    State(app_state): State<AppState>,
+) -> Response {
+    match ClientScope::from_query(&query) {
+        Ok(scope) => ws.on_upgrade(move |socket| push_updates(socket, app_state, scope)),
+        Err(e) => ApiError(e).into_response(),
     }
-    
-    Json(false)
 }
 
+async fn push_updates(mut socket: WebSocket, app_state: AppState, scope: ClientScope) {
+    println!("[HTTP live] Client verbonden: {:?}", scope);
+    let mut updates = app_state.updates.subscribe();
+    let mut last_sent = String::new();
+
+    let current = app_state.current_game().ok();
+    if let Some(game) = current {
+        if send_scoped(&mut socket, &scope, &game, &mut last_sent).await.is_err() {
+            return;
+        }
+    }
+
+    loop {
+        tokio::select! {
+            update = updates.recv() => {
+                let game = match update {
+                    Ok(game) => (*game).clone(),
+                    // Te traag gelezen: tussenliggende states overslaan en de actuele sturen
+                    Err(RecvError::Lagged(_)) => match app_state.current_game().ok() {
+                        Some(game) => game,
+                        None => continue,
+                    },
+                    Err(RecvError::Closed) => break,
+                };
+                if send_scoped(&mut socket, &scope, &game, &mut last_sent).await.is_err() {
+                    break;
+                }
+            }
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {} // Clients sturen niets; pings handelt axum zelf af
+            },
+        }
+    }
+
+    println!("[HTTP live] Client verbroken: {:?}", scope);
+}
+
+async fn send_scoped(
+    socket: &mut WebSocket,
+    scope: &ClientScope,
+    game: &GameState,
+    last_sent: &mut String,
+) -> Result<(), axum::Error> {
+    let payload = match serde_json::to_string(&scope.scoped_state(game)) {
+        Ok(payload) => payload,
+        Err(e) => {
+            println!("[HTTP live] Kan state niet serialiseren: {}", e);
+            return Ok(());
+        }
+    };
+    if payload == *last_sent {
+        return Ok(());
+    }
+    socket.send(Message::Text(payload.clone())).await?;
+    *last_sent = payload;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{Episode, EpisodeQuestion, EpisodeRound, GameAction, RuleSet};
+    use crate::game::test_support::{act, complete, game, start};
+
+    fn question(id: i64, answer: &str) -> Question {
+        Question {
+            id,
+            text: format!("Vraag {}", id),
+            answer: answer.to_string(),
+            accepted_variants: vec![format!("{} (variant)", answer)],
+            category: None,
+            difficulty: None,
+            source: None,
+        }
+    }
+
+    fn assign(answers: [&str; 4]) -> GameAction {
+        GameAction::AssignQuestions {
+            questions: answers.iter().enumerate().map(|(i, a)| question(i as i64 + 1, a)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_only_the_quizmaster_sees_the_answers() {
+        let mut engine = start(&["Anna", "Bert"], RuleSet::default());
+        act(&mut engine, assign(["Parijs", "Berlijn", "Rome", "Madrid"]));
+        act(&mut engine, GameAction::CollectInitialBets);
+        act(&mut engine, complete(Some(0)));
+        act(&mut engine, GameAction::StartNextRound);
+        act(&mut engine, assign(["Lissabon", "Wenen", "Praag", "Oslo"]));
+        let mut game = game(&engine).clone();
+        game.episode = Some(Episode {
+            id: "aflevering_1".to_string(),
+            title: "Aflevering 1".to_string(),
+            recording_date: None,
+            player_names: vec!["Anna".to_string(), "Bert".to_string()],
+            rule_set: None,
+            rounds: vec![EpisodeRound {
+                round_number: 3,
+                questions: vec![EpisodeQuestion { question: question(9, "Helsinki"), notes: None }],
+            }],
+        });
+
+        let answers = ["Parijs", "Lissabon", "Helsinki", "variant"];
+        let full = serde_json::to_string(&ClientScope::Full.scoped_state(&game)).unwrap();
+        assert!(answers.iter().all(|a| full.contains(a)));
+
+        for scope in [ClientScope::Player("player_0".to_string()), ClientScope::Fill, ClientScope::Key] {
+            let scoped = scope.scoped_state(&game);
+            let json = serde_json::to_string(&scoped).unwrap();
+            for answer in answers {
+                assert!(!json.contains(answer), "{:?} ziet {}", scope, answer);
+            }
+            // De vragen zelf blijven zichtbaar
+            assert_eq!(scoped.current_round.unwrap().questions[0].text, "Vraag 1");
+            assert!(scoped.episode.is_none());
+        }
+    }
+}
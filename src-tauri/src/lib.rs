@@ -1,39 +1,98 @@
-mod game;
+mod answer_images;
 mod commands;
 mod display;
+mod episode_archive;
+mod episode_report;
+mod episode_store;
+mod event_log;
+mod handwriting;
 mod http_server;
+mod persistence;
+mod question_bank;
+mod question_io;
+mod rule_store;
+mod timer;
 mod updater;
 
+/// Spelregels komen uit de `game-engine` crate; de rest van de app kent ze als `game`
+pub use game_engine as game;
+
 use std::sync::{Arc, Mutex};
+use tauri::Manager;
+use tokio::sync::broadcast;
+use answer_images::AnswerImageStore;
 use commands::AppState;
 use display::DisplayController;
+use episode_archive::ArchiveStore;
+use episode_report::ReportStore;
+use episode_store::EpisodeStore;
+use event_log::EventLog;
+use game::{Engine, TimerClock};
+use persistence::SnapshotStore;
+use question_bank::QuestionBank;
+use rule_store::RuleSetStore;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    // Shared game state voor zowel Tauri commands als HTTP API
-    let game_state = Arc::new(Mutex::new(None));
-    let previous_game_state = Arc::new(Mutex::new(None));
-    
-    // Start HTTP server in background via Tauri setup
-    let http_game_state = game_state.clone();
-    
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
-        .setup(move |app| {
+        .setup(|app| {
+            // Snapshots en event log komen in de app data map, zodat een crash tijdens een opname herstelbaar is
+            let data_dir = app.path().app_data_dir()?;
+            let snapshots = SnapshotStore::new(data_dir.clone());
+            let answer_images = AnswerImageStore::new(data_dir.clone());
+            let archives = ArchiveStore::new(data_dir.clone());
+            let reports = ReportStore::new(data_dir.clone());
+            let events = EventLog::new(data_dir.clone());
+            let episodes = EpisodeStore::new(data_dir.clone());
+            let rule_sets = RuleSetStore::new(data_dir.clone());
+            let questions = QuestionBank::open(&data_dir.join("questions.sqlite"))?;
+
+            // Onafgerond spel uit de vorige sessie? Dan bieden we het aan om te hervatten.
+            let recovered_game = match snapshots.load(&answer_images) {
+                Ok(Some(snapshot)) if !snapshot.game.is_finished => {
+                    println!("💾 Onafgerond spel gevonden: {} (ronde {}, opgeslagen {})",
+                             snapshot.game.game_id, snapshot.game.round_number, snapshot.saved_at);
+                    Some(snapshot.game)
+                }
+                Ok(_) => None,
+                Err(e) => {
+                    println!("⚠️  Kon snapshot niet laden: {}", e);
+                    None
+                }
+            };
+
+            // Shared game state voor zowel Tauri commands als HTTP API
+            let app_state = AppState {
+                engine: Arc::new(Mutex::new(Engine::new())),
+                timer: Arc::new(Mutex::new(TimerClock::default())),
+                snapshots: Arc::new(snapshots),
+                events: Arc::new(Mutex::new(events)),
+                updates: broadcast::channel(64).0,
+                recovered_game: Arc::new(Mutex::new(recovered_game)),
+                questions: Arc::new(questions),
+                episodes: Arc::new(episodes),
+                rule_sets: Arc::new(rule_sets),
+                answer_images: Arc::new(answer_images),
+                archives: Arc::new(archives),
+                reports: Arc::new(reports),
+            };
+
             // Start HTTP server in Tauri's async context (for external displays only)
-            let http_state = http_game_state.clone();
+            let http_state = app_state.clone();
             tauri::async_runtime::spawn(async move {
                 http_server::start_http_server(http_state).await;
             });
-            
+
+            // Eén timer taak voor de hele sessie
+            timer::spawn(app_state.clone());
+
+            app.manage(app_state);
+
             // Tauri window uses built-in asset handler - no redirect needed!
-            
+
             Ok(())
         })
-        .manage(AppState {
-            game: game_state,
-            previous_game: previous_game_state,
-        })
         .manage(DisplayController::new())
         .invoke_handler(tauri::generate_handler![
             commands::start_new_game,
@@ -44,13 +103,37 @@ pub fn run() {
             commands::collect_initial_bets,
             commands::add_bets_to_pot,
             commands::place_bet,
+            commands::check_bet,
+            commands::call_bet,
+            commands::all_in,
             commands::player_fold,
             commands::advance_phase,
             commands::complete_round,
             commands::start_next_round,
-            commands::save_state_for_undo,
             commands::undo_last_action,
+            commands::redo_last_action,
+            commands::get_undo_history,
+            commands::get_event_log,
+            commands::replay_event_log,
+            commands::get_balance_timeline,
+            commands::export_episode_archive,
+            commands::export_episode_report,
             commands::set_round_number,
+            commands::search_questions,
+            commands::get_question_categories,
+            commands::add_question,
+            commands::update_question,
+            commands::delete_question,
+            commands::import_questions,
+            commands::export_questions,
+            commands::assign_round_questions,
+            commands::list_rule_sets,
+            commands::save_rule_set,
+            commands::list_episodes,
+            commands::get_episode,
+            commands::save_episode,
+            commands::delete_episode,
+            commands::start_episode,
             commands::reset_game,
             commands::toggle_player_active,
             commands::reveal_question,
@@ -66,9 +149,12 @@ pub fn run() {
             commands::update_player_name,
             commands::set_timer,
             commands::start_timer,
-            commands::stop_timer,
+            commands::start_phase_timer,
+            commands::pause_timer,
             commands::reset_timer,
-            commands::tick_timer,
+            commands::get_recoverable_game,
+            commands::resume_recovered_game,
+            commands::discard_recovered_game,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
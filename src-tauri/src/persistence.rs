@@ -0,0 +1,142 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::answer_images::{decode_png_data_url, AnswerImageStore};
+use crate::game::GameState;
+
+/// Versie van het snapshot-formaat op schijf.
+/// Ophogen (en een migratie toevoegen in `SnapshotStore::load`) bij brekende wijzigingen in `GameState`.
+///
+/// - 1: tekeningen als base64 in `Answer.image_data`
+/// - 2: tekeningen en pennenstreken als bestand, `Answer.image_path` en `Answer.drawing_path` verwijzen ernaar
+pub const SNAPSHOT_SCHEMA_VERSION: u32 = 2;
+
+const SNAPSHOT_FILE: &str = "current_game.json";
+
+/// Atomisch wegschrijven: eerst naar een tijdelijk bestand, fsync, dan rename.
+/// Zo staat er op schijf altijd óf de oude óf de nieuwe versie, nooit een halve.
+pub fn write_atomic(path: &Path, data: &[u8]) -> Result<(), String> {
+    let tmp_path = path.with_extension("tmp");
+    {
+        let mut file = fs::File::create(&tmp_path)
+            .map_err(|e| format!("Kan {} niet schrijven: {}", tmp_path.display(), e))?;
+        file.write_all(data)
+            .map_err(|e| format!("Kan {} niet schrijven: {}", tmp_path.display(), e))?;
+        file.sync_all()
+            .map_err(|e| format!("Kan {} niet wegschrijven naar schijf: {}", tmp_path.display(), e))?;
+    }
+    fs::rename(&tmp_path, path)
+        .map_err(|e| format!("Kan {} niet vervangen: {}", path.display(), e))
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GameSnapshot {
+    pub schema_version: u32,
+    pub saved_at: String,
+    pub game: GameState,
+}
+
+/// Schrijft na elke wijziging een snapshot van de `GameState` naar schijf,
+/// zodat een crash tijdens een opname niet alle saldi, pot en antwoorden kost.
+pub struct SnapshotStore {
+    dir: PathBuf,
+}
+
+impl SnapshotStore {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    pub fn path(&self) -> PathBuf {
+        self.dir.join(SNAPSHOT_FILE)
+    }
+
+    /// Atomisch wegschrijven, zie `write_atomic`
+    pub fn save(&self, game: &GameState) -> Result<(), String> {
+        fs::create_dir_all(&self.dir)
+            .map_err(|e| format!("Kan data map niet aanmaken: {}", e))?;
+
+        let snapshot = GameSnapshot {
+            schema_version: SNAPSHOT_SCHEMA_VERSION,
+            saved_at: chrono::Utc::now().to_rfc3339(),
+            game: game.clone(),
+        };
+        let json = serde_json::to_vec(&snapshot)
+            .map_err(|e| format!("Kan snapshot niet serialiseren: {}", e))?;
+
+        write_atomic(&self.path(), &json)
+            .map_err(|e| format!("Kan snapshot niet opslaan: {}", e))
+    }
+
+    /// Laad de laatst opgeslagen snapshot, of `None` als er nog geen is.
+    /// Tekeningen uit een oude snapshot worden daarbij naar `images` verhuisd.
+    pub fn load(&self, images: &AnswerImageStore) -> Result<Option<GameSnapshot>, String> {
+        let path = self.path();
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let data = fs::read(&path)
+            .map_err(|e| format!("Kan snapshot niet lezen: {}", e))?;
+        let mut value: serde_json::Value = serde_json::from_slice(&data)
+            .map_err(|e| format!("Snapshot is beschadigd: {}", e))?;
+
+        let version = value.get("schema_version")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| "Snapshot heeft geen schema_version".to_string())?;
+
+        if version == 1 {
+            migrate_v1_answers(&mut value["game"], images);
+        }
+
+        match version as u32 {
+            1 | SNAPSHOT_SCHEMA_VERSION => serde_json::from_value(value)
+                .map(Some)
+                .map_err(|e| format!("Snapshot kan niet worden ingelezen: {}", e)),
+            other => Err(format!(
+                "Onbekende snapshot versie {} (verwacht {})",
+                other, SNAPSHOT_SCHEMA_VERSION
+            )),
+        }
+    }
+}
+
+/// Versie 1 → 2: base64 tekeningen als bestand wegschrijven en vervangen door hun pad.
+/// Antwoorden in een snapshot horen altijd bij de lopende ronde. Een tekening die niet
+/// te redden is wordt een leeg antwoord; saldi en pot gaan voor.
+fn migrate_v1_answers(game: &mut serde_json::Value, images: &AnswerImageStore) {
+    let game_id = game["game_id"].as_str().unwrap_or_default().to_string();
+    let round_number = game["round_number"].as_i64().unwrap_or_default() as i32;
+    let Some(players) = game["players"].as_array_mut() else {
+        return;
+    };
+
+    for player in players {
+        let player_id = player["id"].as_str().unwrap_or_default().to_string();
+        let Some(answers) = player["answers"].as_array_mut() else {
+            continue;
+        };
+        for answer in answers {
+            let question_number = answer["question_number"].as_i64().unwrap_or_default() as i32;
+            let image_data = answer.as_object_mut()
+                .and_then(|a| a.remove("image_data"))
+                .and_then(|v| v.as_str().map(str::to_string))
+                .unwrap_or_default();
+
+            let saved = decode_png_data_url(&image_data).and_then(|png| {
+                let image_path = AnswerImageStore::path_for(&game_id, round_number, &player_id, question_number);
+                images.save(&image_path, &png).map(|()| image_path)
+            });
+            let image_path = match saved {
+                Ok(image_path) => image_path,
+                Err(e) => {
+                    println!("[persistence] ⚠️  Tekening {} vraag {} niet overgezet: {}", player_id, question_number, e);
+                    String::new()
+                }
+            };
+            answer["image_path"] = serde_json::Value::String(image_path);
+        }
+    }
+}
@@ -0,0 +1,387 @@
+use std::path::Path;
+use std::sync::Mutex;
+
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension, Row};
+use serde::Deserialize;
+
+use crate::game::{ErrorCode, GameError, Question};
+
+/// Huidige versie van het database schema (opgeslagen in `PRAGMA user_version`)
+const SCHEMA_VERSION: i32 = 1;
+
+/// Vraag zoals de quizmaster hem invoert of aanpast, zonder id
+#[derive(Debug, Clone, Deserialize)]
+pub struct NewQuestion {
+    pub text: String,
+    pub answer: String,
+    #[serde(default)]
+    pub accepted_variants: Vec<String>,
+    pub category: Option<String>,
+    pub difficulty: Option<i32>,
+    pub source: Option<String>,
+}
+
+impl NewQuestion {
+    pub fn validate(&self) -> Result<(), GameError> {
+        if self.text.trim().is_empty() {
+            return Err(GameError::invalid_input("Vraag mag niet leeg zijn"));
+        }
+        if self.answer.trim().is_empty() {
+            return Err(GameError::invalid_input("Antwoord mag niet leeg zijn"));
+        }
+        if let Some(difficulty) = self.difficulty {
+            if !(1..=5).contains(&difficulty) {
+                return Err(GameError::invalid_input(format!("Moeilijkheid moet tussen 1 en 5 liggen, niet {}", difficulty)));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Zoekfilter voor de vragenbank. Alle velden zijn optioneel.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct QuestionFilter {
+    pub text: Option<String>,     // Zoekt in vraag en antwoord
+    pub category: Option<String>,
+    pub difficulty: Option<i32>,
+    #[serde(default)]
+    pub unused_only: bool,        // Alleen vragen die nog niet in een ronde zijn gebruikt
+    #[serde(default)]
+    pub random: bool,             // Willekeurige volgorde, handig om een ronde te vullen
+    pub limit: Option<u32>,
+}
+
+/// Vragenbank in een SQLite database in de app data map
+pub struct QuestionBank {
+    conn: Mutex<Connection>,
+}
+
+const QUESTION_COLUMNS: &str = "id, text, answer, accepted_variants, category, difficulty, source";
+
+const INSERT_QUESTION: &str =
+    "INSERT INTO questions (text, answer, accepted_variants, category, difficulty, source, created_at)
+     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)";
+
+fn question_from_row(row: &Row) -> rusqlite::Result<Question> {
+    let variants: String = row.get(3)?;
+    Ok(Question {
+        id: row.get(0)?,
+        text: row.get(1)?,
+        answer: row.get(2)?,
+        accepted_variants: serde_json::from_str(&variants).unwrap_or_default(),
+        category: row.get(4)?,
+        difficulty: row.get(5)?,
+        source: row.get(6)?,
+    })
+}
+
+fn db_error(e: rusqlite::Error) -> GameError {
+    GameError::storage(format!("Vragenbank fout: {}", e))
+}
+
+fn not_found(id: i64) -> GameError {
+    GameError::new(ErrorCode::NotFound, format!("Vraag {} niet gevonden", id))
+}
+
+impl QuestionBank {
+    pub fn open(path: &Path) -> Result<Self, GameError> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| GameError::storage(format!("Kan map voor vragenbank niet aanmaken: {}", e)))?;
+        }
+        let conn = Connection::open(path).map_err(db_error)?;
+        Self::migrate(&conn)?;
+        println!("[question_bank] Vragenbank geopend: {}", path.display());
+        Ok(Self { conn: Mutex::new(conn) })
+    }
+
+    fn migrate(conn: &Connection) -> Result<(), GameError> {
+        let version: i32 = conn
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .map_err(db_error)?;
+
+        if version < 1 {
+            conn.execute_batch(
+                "CREATE TABLE IF NOT EXISTS questions (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    text TEXT NOT NULL,
+                    answer TEXT NOT NULL,
+                    accepted_variants TEXT NOT NULL DEFAULT '[]',
+                    category TEXT,
+                    difficulty INTEGER,
+                    source TEXT,
+                    created_at TEXT NOT NULL,
+                    last_used_at TEXT
+                );
+                CREATE INDEX IF NOT EXISTS idx_questions_category ON questions(category);",
+            ).map_err(db_error)?;
+        }
+
+        if version < SCHEMA_VERSION {
+            conn.pragma_update(None, "user_version", SCHEMA_VERSION).map_err(db_error)?;
+        }
+        Ok(())
+    }
+
+    fn variants_json(question: &NewQuestion) -> Result<String, GameError> {
+        let variants: Vec<&str> = question.accepted_variants.iter()
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty())
+            .collect();
+        serde_json::to_string(&variants)
+            .map_err(|e| GameError::storage(format!("Kan varianten niet opslaan: {}", e)))
+    }
+
+    pub fn add(&self, question: NewQuestion) -> Result<Question, GameError> {
+        question.validate()?;
+        let variants = Self::variants_json(&question)?;
+
+        let id = {
+            let conn = self.conn.lock()?;
+            conn.execute(
+                INSERT_QUESTION,
+                params![
+                    question.text.trim(),
+                    question.answer.trim(),
+                    variants,
+                    question.category,
+                    question.difficulty,
+                    question.source,
+                    chrono::Utc::now().to_rfc3339(),
+                ],
+            ).map_err(db_error)?;
+            conn.last_insert_rowid()
+        };
+
+        self.get(id)
+    }
+
+    /// Voeg een hele set vragen toe in één transactie (alles of niets)
+    pub fn add_many(&self, questions: &[NewQuestion]) -> Result<usize, GameError> {
+        for question in questions {
+            question.validate()?;
+        }
+        let now = chrono::Utc::now().to_rfc3339();
+
+        let mut conn = self.conn.lock()?;
+        let tx = conn.transaction().map_err(db_error)?;
+        for question in questions {
+            tx.execute(
+                INSERT_QUESTION,
+                params![
+                    question.text.trim(),
+                    question.answer.trim(),
+                    Self::variants_json(question)?,
+                    question.category,
+                    question.difficulty,
+                    question.source,
+                    now,
+                ],
+            ).map_err(db_error)?;
+        }
+        tx.commit().map_err(db_error)?;
+        Ok(questions.len())
+    }
+
+    pub fn update(&self, id: i64, question: NewQuestion) -> Result<Question, GameError> {
+        question.validate()?;
+        let variants = Self::variants_json(&question)?;
+
+        let changed = self.conn.lock()?.execute(
+            "UPDATE questions
+             SET text = ?1, answer = ?2, accepted_variants = ?3, category = ?4, difficulty = ?5, source = ?6
+             WHERE id = ?7",
+            params![
+                question.text.trim(),
+                question.answer.trim(),
+                variants,
+                question.category,
+                question.difficulty,
+                question.source,
+                id,
+            ],
+        ).map_err(db_error)?;
+
+        if changed == 0 {
+            return Err(not_found(id));
+        }
+        self.get(id)
+    }
+
+    pub fn delete(&self, id: i64) -> Result<(), GameError> {
+        let changed = self.conn.lock()?
+            .execute("DELETE FROM questions WHERE id = ?1", params![id])
+            .map_err(db_error)?;
+        if changed == 0 {
+            return Err(not_found(id));
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, id: i64) -> Result<Question, GameError> {
+        self.conn.lock()?
+            .query_row(
+                &format!("SELECT {} FROM questions WHERE id = ?1", QUESTION_COLUMNS),
+                params![id],
+                question_from_row,
+            )
+            .optional()
+            .map_err(db_error)?
+            .ok_or_else(|| not_found(id))
+    }
+
+    /// Haal meerdere vragen op in de opgegeven volgorde
+    pub fn get_many(&self, ids: &[i64]) -> Result<Vec<Question>, GameError> {
+        ids.iter().map(|id| self.get(*id)).collect()
+    }
+
+    pub fn search(&self, filter: &QuestionFilter) -> Result<Vec<Question>, GameError> {
+        let mut conditions = Vec::new();
+        let mut values: Vec<rusqlite::types::Value> = Vec::new();
+
+        if let Some(text) = filter.text.as_ref().filter(|t| !t.trim().is_empty()) {
+            conditions.push("(text LIKE ? OR answer LIKE ?)");
+            let pattern = format!("%{}%", text.trim());
+            values.push(pattern.clone().into());
+            values.push(pattern.into());
+        }
+        if let Some(category) = &filter.category {
+            conditions.push("category = ?");
+            values.push(category.clone().into());
+        }
+        if let Some(difficulty) = filter.difficulty {
+            conditions.push("difficulty = ?");
+            values.push(i64::from(difficulty).into());
+        }
+        if filter.unused_only {
+            conditions.push("last_used_at IS NULL");
+        }
+
+        let mut sql = format!("SELECT {} FROM questions", QUESTION_COLUMNS);
+        if !conditions.is_empty() {
+            sql.push_str(" WHERE ");
+            sql.push_str(&conditions.join(" AND "));
+        }
+        sql.push_str(if filter.random { " ORDER BY RANDOM()" } else { " ORDER BY category, id" });
+        if let Some(limit) = filter.limit {
+            sql.push_str(&format!(" LIMIT {}", limit));
+        }
+
+        let conn = self.conn.lock()?;
+        let mut statement = conn.prepare(&sql).map_err(db_error)?;
+        let questions = statement
+            .query_map(params_from_iter(values), question_from_row)
+            .map_err(db_error)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(db_error)?;
+        Ok(questions)
+    }
+
+    pub fn categories(&self) -> Result<Vec<String>, GameError> {
+        let conn = self.conn.lock()?;
+        let mut statement = conn
+            .prepare("SELECT DISTINCT category FROM questions WHERE category IS NOT NULL ORDER BY category")
+            .map_err(db_error)?;
+        let categories = statement
+            .query_map([], |row| row.get(0))
+            .map_err(db_error)?
+            .collect::<Result<Vec<String>, _>>()
+            .map_err(db_error)?;
+        Ok(categories)
+    }
+
+    /// Markeer vragen als gebruikt, zodat ze niet per ongeluk in een volgende aflevering terugkomen
+    pub fn mark_used(&self, ids: &[i64]) -> Result<(), GameError> {
+        let now = chrono::Utc::now().to_rfc3339();
+        let conn = self.conn.lock()?;
+        for id in ids {
+            conn.execute("UPDATE questions SET last_used_at = ?1 WHERE id = ?2", params![now, id])
+                .map_err(db_error)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bank(name: &str) -> QuestionBank {
+        let path = std::env::temp_dir().join(format!("mhmot_question_bank_{}_{}.db", name, std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        QuestionBank::open(&path).unwrap()
+    }
+
+    fn question(text: &str, answer: &str, category: Option<&str>, difficulty: Option<i32>) -> NewQuestion {
+        NewQuestion {
+            text: text.to_string(),
+            answer: answer.to_string(),
+            accepted_variants: Vec::new(),
+            category: category.map(str::to_string),
+            difficulty,
+            source: None,
+        }
+    }
+
+    fn texts(questions: &[Question]) -> Vec<&str> {
+        questions.iter().map(|q| q.text.as_str()).collect()
+    }
+
+    #[test]
+    fn test_search_filters() {
+        let bank = bank("search");
+        bank.add_many(&[
+            question("Hoofdstad van Frankrijk?", "Parijs", Some("Aardrijkskunde"), Some(1)),
+            question("Hoogste berg van Europa?", "Mont Blanc", Some("Aardrijkskunde"), Some(3)),
+            question("Wie schreef Max Havelaar?", "Multatuli", Some("Literatuur"), Some(3)),
+            question("Wat is 2 + 2?", "4", None, None),
+        ]).unwrap();
+        let find = |filter: QuestionFilter| texts(&bank.search(&filter).unwrap()).join(" | ");
+
+        // Tekst zoekt in vraag én antwoord
+        assert_eq!(find(QuestionFilter { text: Some(" parijs ".to_string()), ..Default::default() }), "Hoofdstad van Frankrijk?");
+        assert_eq!(find(QuestionFilter { text: Some("van".to_string()), ..Default::default() }), "Hoofdstad van Frankrijk? | Hoogste berg van Europa?");
+        assert_eq!(find(QuestionFilter { category: Some("Literatuur".to_string()), ..Default::default() }), "Wie schreef Max Havelaar?");
+        assert_eq!(
+            find(QuestionFilter { category: Some("Aardrijkskunde".to_string()), difficulty: Some(3), ..Default::default() }),
+            "Hoogste berg van Europa?"
+        );
+        assert_eq!(bank.search(&QuestionFilter { limit: Some(2), random: true, ..Default::default() }).unwrap().len(), 2);
+        assert_eq!(bank.categories().unwrap(), vec!["Aardrijkskunde", "Literatuur"]);
+
+        let used = bank.search(&QuestionFilter { category: Some("Literatuur".to_string()), ..Default::default() }).unwrap();
+        bank.mark_used(&[used[0].id]).unwrap();
+        assert!(!find(QuestionFilter { unused_only: true, ..Default::default() }).contains("Max Havelaar"));
+        assert_eq!(bank.search(&QuestionFilter { unused_only: true, ..Default::default() }).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_update_keeps_the_id_and_trims() {
+        let bank = bank("update");
+        let added = bank.add(question("Hoofdstad van Nederland?", "Den Haag", None, None)).unwrap();
+
+        let mut changed = question("  Hoofdstad van Nederland?  ", " Amsterdam ", Some("Aardrijkskunde"), Some(2));
+        changed.accepted_variants = vec!["A'dam".to_string(), "  ".to_string()];
+        let updated = bank.update(added.id, changed).unwrap();
+
+        assert_eq!(updated.id, added.id);
+        assert_eq!((updated.text.as_str(), updated.answer.as_str()), ("Hoofdstad van Nederland?", "Amsterdam"));
+        assert_eq!(updated.accepted_variants, vec!["A'dam"]);
+        assert_eq!(bank.get(added.id).unwrap().category.as_deref(), Some("Aardrijkskunde"));
+        // Ongeldige wijzigingen worden niet opgeslagen
+        assert_eq!(bank.update(added.id, question("", "Amsterdam", None, None)).unwrap_err().code, ErrorCode::InvalidInput);
+        assert_eq!(bank.get(added.id).unwrap().answer, "Amsterdam");
+    }
+
+    #[test]
+    fn test_missing_ids_are_not_found() {
+        let bank = bank("missing");
+        let added = bank.add(question("Wat is 3 + 3?", "6", None, None)).unwrap();
+        bank.delete(added.id).unwrap();
+
+        assert_eq!(bank.delete(added.id).unwrap_err().code, ErrorCode::NotFound);
+        assert_eq!(bank.get(added.id).unwrap_err().code, ErrorCode::NotFound);
+        assert_eq!(bank.update(added.id + 1, question("Wat is 4 + 4?", "8", None, None)).unwrap_err().code, ErrorCode::NotFound);
+        assert!(bank.search(&QuestionFilter::default()).unwrap().is_empty());
+    }
+}
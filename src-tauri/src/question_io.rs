@@ -0,0 +1,465 @@
+use std::collections::{HashMap, HashSet};
+use std::io::Cursor;
+
+use calamine::{Reader, Xlsx};
+use rust_xlsxwriter::{Format, Workbook};
+use serde::{Deserialize, Serialize};
+
+use crate::game::{GameError, Question};
+use crate::question_bank::{NewQuestion, QuestionBank};
+
+/// Kolommen zoals we ze exporteren. Bij import accepteren we ook de Nederlandse namen.
+const EXPORT_COLUMNS: [&str; 6] = ["text", "answer", "accepted_variants", "category", "difficulty", "source"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QuestionFormat {
+    Csv,
+    Json,
+    Xlsx,
+}
+
+impl QuestionFormat {
+    /// Bepaal het formaat aan de hand van de bestandsnaam
+    pub fn from_file_name(file_name: &str) -> Result<Self, GameError> {
+        let extension = file_name.rsplit('.').next().unwrap_or("").to_lowercase();
+        match extension.as_str() {
+            "csv" => Ok(QuestionFormat::Csv),
+            "json" => Ok(QuestionFormat::Json),
+            "xlsx" => Ok(QuestionFormat::Xlsx),
+            _ => Err(GameError::invalid_input(format!("Onbekend bestandsformaat: {} (gebruik .csv, .json of .xlsx)", file_name))),
+        }
+    }
+}
+
+/// Probleem met één regel uit het bestand. `row` is het regelnummer zoals de redactie
+/// het in de spreadsheet ziet (kopregel = 1).
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct RowIssue {
+    pub row: usize,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportReport {
+    pub total_rows: usize,
+    pub imported: usize,
+    pub duplicates: Vec<RowIssue>,
+    pub errors: Vec<RowIssue>,
+    pub dry_run: bool, // Alleen gecontroleerd, niets opgeslagen
+}
+
+/// Eén ingelezen regel: kolomnaam (genormaliseerd) -> waarde
+type RawRow = HashMap<&'static str, String>;
+
+/// Ingelezen regels met hun regelnummer; een regel die al niet te lezen was heeft een foutmelding
+type Rows = Vec<(usize, Result<RawRow, String>)>;
+
+/// Koppel een kolomkop aan een veld van `NewQuestion`
+fn column_key(header: &str) -> Option<&'static str> {
+    match header.trim().to_lowercase().replace([' ', '-'], "_").as_str() {
+        "text" | "question" | "vraag" => Some("text"),
+        "answer" | "antwoord" => Some("answer"),
+        "accepted_variants" | "variants" | "varianten" | "goedgekeurde_varianten" => Some("accepted_variants"),
+        "category" | "categorie" => Some("category"),
+        "difficulty" | "moeilijkheid" | "niveau" => Some("difficulty"),
+        "source" | "bron" => Some("source"),
+        _ => None,
+    }
+}
+
+fn rows_from_table(headers: &[String], records: Vec<Result<Vec<String>, String>>) -> Result<Rows, GameError> {
+    let keys: Vec<Option<&'static str>> = headers.iter().map(|h| column_key(h)).collect();
+    for required in ["text", "answer"] {
+        if !keys.contains(&Some(required)) {
+            return Err(GameError::invalid_input(format!("Kolom '{}' ontbreekt in de kopregel", required)));
+        }
+    }
+
+    Ok(records.into_iter()
+        .enumerate()
+        .map(|(index, record)| {
+            let row = record.map(|record| keys.iter()
+                .zip(record)
+                .filter_map(|(key, value)| key.map(|k| (k, value)))
+                .collect());
+            (index + 2, row)
+        })
+        .collect())
+}
+
+fn read_csv(data: &[u8]) -> Result<Rows, GameError> {
+    // Excel exporteert CSV in NL vaak met puntkomma's
+    let first_line = data.split(|b| *b == b'\n').next().unwrap_or_default();
+    let delimiter = if first_line.contains(&b';') && !first_line.contains(&b',') { b';' } else { b',' };
+
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .flexible(true)
+        .from_reader(data);
+    let headers: Vec<String> = reader.headers()
+        .map_err(|e| GameError::invalid_input(format!("Kan CSV kopregel niet lezen: {}", e)))?
+        .iter()
+        .map(|h| h.trim_start_matches('\u{feff}').to_string())
+        .collect();
+
+    // Een onleesbare regel (bijvoorbeeld geen geldige UTF-8) komt in het rapport, de rest gaat door
+    let records = reader.records()
+        .map(|record| record
+            .map(|record| record.iter().map(|v| v.to_string()).collect())
+            .map_err(|e| format!("Regel kan niet gelezen worden: {}", e)))
+        .collect();
+    rows_from_table(&headers, records)
+}
+
+fn read_xlsx(data: &[u8]) -> Result<Rows, GameError> {
+    let mut workbook: Xlsx<_> = Xlsx::new(Cursor::new(data))
+        .map_err(|e| GameError::invalid_input(format!("Kan Excel bestand niet openen: {}", e)))?;
+    let range = workbook.worksheet_range_at(0)
+        .ok_or_else(|| GameError::invalid_input("Excel bestand bevat geen werkblad"))?
+        .map_err(|e| GameError::invalid_input(format!("Kan werkblad niet lezen: {}", e)))?;
+
+    let mut rows = range.rows().map(|row| row.iter().map(|cell| cell.to_string()).collect::<Vec<_>>());
+    let headers = rows.next()
+        .ok_or_else(|| GameError::invalid_input("Werkblad is leeg"))?;
+    rows_from_table(&headers, rows.map(Ok).collect())
+}
+
+fn read_json(data: &[u8]) -> Result<Rows, GameError> {
+    let items: Vec<serde_json::Map<String, serde_json::Value>> = serde_json::from_slice(data)
+        .map_err(|e| GameError::invalid_input(format!("JSON moet een lijst met vragen zijn: {}", e)))?;
+
+    Ok(items.into_iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let row = item.into_iter()
+                .filter_map(|(key, value)| {
+                    let value = match value {
+                        serde_json::Value::Null => String::new(),
+                        serde_json::Value::String(s) => s,
+                        serde_json::Value::Array(values) => values.iter()
+                            .map(|v| v.as_str().map(str::to_string).unwrap_or_else(|| v.to_string()))
+                            .collect::<Vec<_>>()
+                            .join(";"),
+                        other => other.to_string(),
+                    };
+                    column_key(&key).map(|k| (k, value))
+                })
+                .collect();
+            (index + 1, Ok(row))
+        })
+        .collect())
+}
+
+fn optional(row: &RawRow, key: &str) -> Option<String> {
+    row.get(key).map(|v| v.trim()).filter(|v| !v.is_empty()).map(str::to_string)
+}
+
+/// Zet een ingelezen regel om naar een vraag, met een leesbare fout per regel
+fn question_from_row(row: &RawRow) -> Result<NewQuestion, GameError> {
+    let difficulty = match optional(row, "difficulty") {
+        Some(value) => Some(value.parse::<f64>()
+            .ok()
+            .filter(|d| d.fract() == 0.0)
+            .map(|d| d as i32)
+            .ok_or_else(|| GameError::invalid_input(format!("Moeilijkheid '{}' is geen heel getal", value)))?),
+        None => None,
+    };
+
+    let question = NewQuestion {
+        text: optional(row, "text").unwrap_or_default(),
+        answer: optional(row, "answer").unwrap_or_default(),
+        accepted_variants: optional(row, "accepted_variants")
+            .map(|v| v.split([';', '|']).map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect())
+            .unwrap_or_default(),
+        category: optional(row, "category"),
+        difficulty,
+        source: optional(row, "source"),
+    };
+    question.validate()?;
+    Ok(question)
+}
+
+/// Vergelijk vragen zonder hoofdletters en dubbele spaties
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// Lees een bestand in zonder op te slaan: geldige vragen plus een rapport van fouten en dubbelen
+pub fn parse_questions(
+    format: QuestionFormat,
+    data: &[u8],
+    existing: &[Question],
+) -> Result<(Vec<NewQuestion>, ImportReport), GameError> {
+    let rows = match format {
+        QuestionFormat::Csv => read_csv(data)?,
+        QuestionFormat::Json => read_json(data)?,
+        QuestionFormat::Xlsx => read_xlsx(data)?,
+    };
+
+    let mut seen: HashSet<String> = existing.iter().map(|q| normalize(&q.text)).collect();
+    let mut questions = Vec::new();
+    let mut report = ImportReport {
+        total_rows: 0,
+        imported: 0,
+        duplicates: Vec::new(),
+        errors: Vec::new(),
+        dry_run: true,
+    };
+
+    for (row_number, row) in rows {
+        // Helemaal lege regels (onderaan een spreadsheet) tellen niet mee
+        if row.as_ref().is_ok_and(|row| row.values().all(|v| v.trim().is_empty())) {
+            continue;
+        }
+        report.total_rows += 1;
+
+        let row = match row {
+            Ok(row) => row,
+            Err(message) => {
+                report.errors.push(RowIssue { row: row_number, message });
+                continue;
+            }
+        };
+        match question_from_row(&row) {
+            Ok(question) => {
+                if seen.insert(normalize(&question.text)) {
+                    questions.push(question);
+                } else {
+                    report.duplicates.push(RowIssue {
+                        row: row_number,
+                        message: format!("Vraag staat al in de vragenbank of eerder in het bestand: {}", question.text),
+                    });
+                }
+            }
+            Err(e) => report.errors.push(RowIssue { row: row_number, message: e.message }),
+        }
+    }
+
+    report.imported = questions.len();
+    Ok((questions, report))
+}
+
+/// Importeer een vragenset in de bank. Regels met fouten en dubbele vragen worden overgeslagen
+/// en in het rapport vermeld; met `dry_run` wordt alleen gecontroleerd.
+pub fn import_questions(
+    bank: &QuestionBank,
+    format: QuestionFormat,
+    data: &[u8],
+    dry_run: bool,
+) -> Result<ImportReport, GameError> {
+    let existing = bank.search(&Default::default())?;
+    let (questions, mut report) = parse_questions(format, data, &existing)?;
+
+    if !dry_run {
+        bank.add_many(&questions)?;
+    }
+    report.dry_run = dry_run;
+
+    println!("[import_questions] {} regels: {} geïmporteerd, {} dubbel, {} fouten{}",
+             report.total_rows, report.imported, report.duplicates.len(), report.errors.len(),
+             if dry_run { " (proefimport)" } else { "" });
+    Ok(report)
+}
+
+fn export_row(question: &Question) -> [String; 6] {
+    [
+        question.text.clone(),
+        question.answer.clone(),
+        question.accepted_variants.join("; "),
+        question.category.clone().unwrap_or_default(),
+        question.difficulty.map(|d| d.to_string()).unwrap_or_default(),
+        question.source.clone().unwrap_or_default(),
+    ]
+}
+
+/// Exporteer vragen in hetzelfde formaat dat de import weer inleest
+pub fn export_questions(format: QuestionFormat, questions: &[Question]) -> Result<Vec<u8>, GameError> {
+    match format {
+        QuestionFormat::Json => serde_json::to_vec_pretty(questions)
+            .map_err(|e| GameError::internal(format!("Kan vragen niet exporteren: {}", e))),
+        QuestionFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            writer.write_record(EXPORT_COLUMNS)
+                .map_err(|e| GameError::internal(format!("Kan CSV niet schrijven: {}", e)))?;
+            for question in questions {
+                writer.write_record(export_row(question))
+                    .map_err(|e| GameError::internal(format!("Kan CSV niet schrijven: {}", e)))?;
+            }
+            writer.into_inner()
+                .map_err(|e| GameError::internal(format!("Kan CSV niet schrijven: {}", e)))
+        }
+        QuestionFormat::Xlsx => {
+            let xlsx_error = |e: rust_xlsxwriter::XlsxError| GameError::internal(format!("Kan Excel bestand niet schrijven: {}", e));
+            let mut workbook = Workbook::new();
+            let bold = Format::new().set_bold();
+            let sheet = workbook.add_worksheet();
+            sheet.set_name("Vragen").map_err(xlsx_error)?;
+
+            for (col, header) in EXPORT_COLUMNS.iter().enumerate() {
+                sheet.write_string_with_format(0, col as u16, *header, &bold).map_err(xlsx_error)?;
+            }
+            for (index, question) in questions.iter().enumerate() {
+                let row = index as u32 + 1;
+                for (col, value) in export_row(question).iter().enumerate() {
+                    sheet.write_string(row, col as u16, value).map_err(xlsx_error)?;
+                }
+                if let Some(difficulty) = question.difficulty {
+                    sheet.write_number(row, 4, difficulty as f64).map_err(xlsx_error)?;
+                }
+            }
+
+            workbook.save_to_buffer().map_err(xlsx_error)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn existing(text: &str) -> Question {
+        Question {
+            id: 1,
+            text: text.to_string(),
+            answer: "Amsterdam".to_string(),
+            accepted_variants: Vec::new(),
+            category: None,
+            difficulty: None,
+            source: None,
+        }
+    }
+
+    fn rows(issues: &[RowIssue]) -> Vec<usize> {
+        issues.iter().map(|issue| issue.row).collect()
+    }
+
+    #[test]
+    fn test_csv_with_semicolons_and_bom() {
+        // Zoals Excel in NL het opslaat: BOM, puntkomma's en Nederlandse kolomnamen
+        let data = "\u{feff}Vraag;Antwoord;Varianten;Categorie;Moeilijkheid\nHoofdstad van Frankrijk?;Parijs;Paris|Parijs ;Aardrijkskunde;2\n";
+        let (questions, report) = parse_questions(QuestionFormat::Csv, data.as_bytes(), &[]).unwrap();
+
+        assert_eq!(report.total_rows, 1);
+        assert!(report.errors.is_empty(), "{:?}", report.errors);
+        assert_eq!(questions[0].text, "Hoofdstad van Frankrijk?");
+        assert_eq!(questions[0].accepted_variants, vec!["Paris", "Parijs"]);
+        assert_eq!(questions[0].category.as_deref(), Some("Aardrijkskunde"));
+        assert_eq!(questions[0].difficulty, Some(2));
+    }
+
+    #[test]
+    fn test_csv_with_commas() {
+        // Een puntkomma in een waarde maakt van de komma nog geen puntkomma-bestand
+        let data = "text,answer,accepted_variants\nWie schreef Max Havelaar?,Multatuli,Eduard Douwes Dekker; Douwes Dekker\n";
+        let (questions, report) = parse_questions(QuestionFormat::Csv, data.as_bytes(), &[]).unwrap();
+
+        assert_eq!(report.total_rows, 1);
+        assert_eq!(questions[0].answer, "Multatuli");
+        assert_eq!(questions[0].accepted_variants, vec!["Eduard Douwes Dekker", "Douwes Dekker"]);
+    }
+
+    #[test]
+    fn test_difficulty_must_be_a_whole_number_from_one_to_five() {
+        let data = "vraag,antwoord,niveau\nEen,1,3\nTwee,2,3.0\nDrie,3,2.5\nVier,4,makkelijk\nVijf,5,6\nZes,6,\n";
+        let (questions, report) = parse_questions(QuestionFormat::Csv, data.as_bytes(), &[]).unwrap();
+
+        let difficulties: Vec<Option<i32>> = questions.iter().map(|q| q.difficulty).collect();
+        assert_eq!(difficulties, vec![Some(3), Some(3), None]);
+        assert_eq!(rows(&report.errors), vec![4, 5, 6]);
+        assert_eq!(report.errors[0].message, "Moeilijkheid '2.5' is geen heel getal");
+        assert_eq!(report.errors[1].message, "Moeilijkheid 'makkelijk' is geen heel getal");
+        assert_eq!(report.errors[2].message, "Moeilijkheid moet tussen 1 en 5 liggen, niet 6");
+    }
+
+    #[test]
+    fn test_duplicates_in_the_bank_and_in_the_file() {
+        let data = "text,answer\nHoofdstad van Nederland?,Amsterdam\nWat is 2 + 2?,4\n  wat IS 2 +  2?,vier\nWat is 3 + 3?,6\n";
+        let (questions, report) = parse_questions(QuestionFormat::Csv, data.as_bytes(), &[existing("hoofdstad van  Nederland?")]).unwrap();
+
+        assert_eq!(report.total_rows, 4);
+        assert_eq!(report.imported, 2);
+        assert_eq!(questions.iter().map(|q| q.text.as_str()).collect::<Vec<_>>(), vec!["Wat is 2 + 2?", "Wat is 3 + 3?"]);
+        assert_eq!(rows(&report.duplicates), vec![2, 4]);
+        assert!(report.duplicates[1].message.ends_with("wat IS 2 +  2?"));
+        assert!(report.errors.is_empty());
+    }
+
+    #[test]
+    fn test_errors_per_row_and_empty_rows_skipped() {
+        let data = "text,answer\nGoede vraag?,Ja\n,Geen vraag\nGeen antwoord?,\n,\nNog een vraag?,Ook ja\n";
+        let (questions, report) = parse_questions(QuestionFormat::Csv, data.as_bytes(), &[]).unwrap();
+
+        assert_eq!(questions.len(), 2);
+        assert_eq!(report.total_rows, 4); // De lege regel telt niet mee
+        assert_eq!(report.errors, vec![
+            RowIssue { row: 3, message: "Vraag mag niet leeg zijn".to_string() },
+            RowIssue { row: 4, message: "Antwoord mag niet leeg zijn".to_string() },
+        ]);
+    }
+
+    #[test]
+    fn test_unreadable_csv_line_is_reported_and_the_rest_imported() {
+        let mut data = b"text,answer\nEerste vraag?,Ja\n".to_vec();
+        data.extend_from_slice(b"Kapotte \xff\xfe vraag?,Nee\n"); // Geen geldige UTF-8
+        data.extend_from_slice(b"Laatste vraag?,Ook ja\n");
+        let (questions, report) = parse_questions(QuestionFormat::Csv, &data, &[]).unwrap();
+
+        assert_eq!(questions.iter().map(|q| q.text.as_str()).collect::<Vec<_>>(), vec!["Eerste vraag?", "Laatste vraag?"]);
+        assert_eq!(report.total_rows, 3);
+        assert_eq!(rows(&report.errors), vec![3]);
+        assert!(report.errors[0].message.starts_with("Regel kan niet gelezen worden"), "{}", report.errors[0].message);
+    }
+
+    #[test]
+    fn test_missing_columns_and_json_rows() {
+        let error = parse_questions(QuestionFormat::Csv, b"vraag,categorie\nWaarom?,Filosofie\n", &[]).unwrap_err();
+        assert_eq!(error.message, "Kolom 'answer' ontbreekt in de kopregel");
+
+        // In JSON is de eerste vraag regel 1 en mogen varianten een lijst zijn
+        let data = r#"[{"vraag": "Kleur van de lucht?", "antwoord": "Blauw", "varianten": ["blauw", "azuur"], "difficulty": 1},
+                       {"vraag": "Zonder antwoord?", "antwoord": null}]"#;
+        let (questions, report) = parse_questions(QuestionFormat::Json, data.as_bytes(), &[]).unwrap();
+        assert_eq!(questions[0].accepted_variants, vec!["blauw", "azuur"]);
+        assert_eq!(questions[0].difficulty, Some(1));
+        assert_eq!(rows(&report.errors), vec![2]);
+    }
+
+    #[test]
+    fn test_dry_run_saves_nothing() {
+        let path = std::env::temp_dir().join(format!("mhmot_question_io_{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let bank = QuestionBank::open(&path).unwrap();
+        let data = "text,answer\nWat is 2 + 2?,4\nWat is 3 + 3?,6\n";
+
+        let report = import_questions(&bank, QuestionFormat::Csv, data.as_bytes(), true).unwrap();
+        assert!(report.dry_run);
+        assert_eq!(report.imported, 2);
+        assert!(bank.search(&Default::default()).unwrap().is_empty());
+
+        let report = import_questions(&bank, QuestionFormat::Csv, data.as_bytes(), false).unwrap();
+        assert!(!report.dry_run);
+        assert_eq!(bank.search(&Default::default()).unwrap().len(), 2);
+
+        // Nog een keer: alles dubbel
+        let report = import_questions(&bank, QuestionFormat::Csv, data.as_bytes(), false).unwrap();
+        assert_eq!((report.imported, rows(&report.duplicates)), (0, vec![2, 3]));
+        assert_eq!(bank.search(&Default::default()).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_export_reads_back_in() {
+        let mut question = existing("Hoofdstad van Nederland?");
+        question.accepted_variants = vec!["A'dam".to_string()];
+        question.difficulty = Some(1);
+
+        for format in [QuestionFormat::Csv, QuestionFormat::Json, QuestionFormat::Xlsx] {
+            let data = export_questions(format, std::slice::from_ref(&question)).unwrap();
+            let (questions, report) = parse_questions(format, &data, &[]).unwrap();
+            assert!(report.errors.is_empty(), "{:?}: {:?}", format, report.errors);
+            assert_eq!(questions[0].text, question.text, "{:?}", format);
+            assert_eq!(questions[0].accepted_variants, question.accepted_variants, "{:?}", format);
+            assert_eq!(questions[0].difficulty, Some(1), "{:?}", format);
+        }
+    }
+}
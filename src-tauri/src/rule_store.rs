@@ -0,0 +1,83 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::game::{ErrorCode, GameError, RuleSet};
+use crate::persistence::write_atomic;
+
+/// Regelbestanden voor afwijkende formats in `rules/<naam>.json`.
+/// De standaardregels zitten in de app en hoeven niet als bestand te bestaan.
+pub struct RuleSetStore {
+    dir: PathBuf,
+}
+
+/// Bestandsnaam voor een regelset: "Kinder editie" -> "kinder-editie"
+fn file_stem(name: &str) -> String {
+    name.trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect()
+}
+
+impl RuleSetStore {
+    pub fn new(data_dir: PathBuf) -> Self {
+        Self { dir: data_dir.join("rules") }
+    }
+
+    /// Alle regelsets: eerst de standaard, dan de bestanden. Onleesbare bestanden worden overgeslagen.
+    pub fn list(&self) -> Result<Vec<RuleSet>, GameError> {
+        let mut rule_sets = vec![RuleSet::default()];
+        if !self.dir.exists() {
+            return Ok(rule_sets);
+        }
+
+        let entries = fs::read_dir(&self.dir)
+            .map_err(|e| GameError::storage(format!("Kan regelbestanden niet lezen: {}", e)))?;
+        let mut from_files = Vec::new();
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            let parsed = fs::read(&path)
+                .map_err(|e| GameError::storage(e.to_string()))
+                .and_then(|data| serde_json::from_slice::<RuleSet>(&data).map_err(|e| GameError::storage(e.to_string())))
+                .and_then(|rules| rules.validate().map(|_| rules));
+            match parsed {
+                Ok(rules) => from_files.push(rules),
+                Err(e) => println!("[rule_store] ⚠️  {} overgeslagen: {}", path.display(), e),
+            }
+        }
+
+        from_files.sort_by(|a, b| a.name.cmp(&b.name));
+        rule_sets.extend(from_files);
+        Ok(rule_sets)
+    }
+
+    /// Zoek een regelset op naam; `None` of een lege naam geeft de standaardregels
+    pub fn load(&self, name: Option<&str>) -> Result<RuleSet, GameError> {
+        let Some(name) = name.filter(|n| !n.trim().is_empty()) else {
+            return Ok(RuleSet::default());
+        };
+        self.list()?
+            .into_iter()
+            .find(|rules| rules.name == name)
+            .ok_or_else(|| GameError::new(ErrorCode::NotFound, format!("Regelset '{}' niet gevonden", name)))
+    }
+
+    pub fn save(&self, rules: &RuleSet) -> Result<(), GameError> {
+        rules.validate()?;
+        if rules.name.trim().is_empty() || rules.name == RuleSet::default().name {
+            return Err(GameError::invalid_input("Geef de regelset een eigen naam"));
+        }
+        fs::create_dir_all(&self.dir)
+            .map_err(|e| GameError::storage(format!("Kan map voor regelbestanden niet aanmaken: {}", e)))?;
+
+        let json = serde_json::to_vec_pretty(rules)
+            .map_err(|e| GameError::internal(format!("Kan regelset niet serialiseren: {}", e)))?;
+        write_atomic(&self.dir.join(format!("{}.json", file_stem(&rules.name))), &json).map_err(GameError::storage)?;
+
+        println!("[rule_store] Regelset '{}' opgeslagen", rules.name);
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+//! De timer loopt in één achtergrondtaak, los van het aantal keer dat er op start wordt gedrukt.
+//! De klok zelf (`TimerClock`) staat in `AppState`; deze taak zet de stand in de `GameState`,
+//! stuurt hem naar de clients zodra de getoonde seconde verandert en handelt het aflopen af.
+
+use std::time::{Duration, Instant};
+use crate::commands::AppState;
+use crate::game::{GameAction, GameError};
+
+/// Actor in het event log voor acties die de timer zelf uitvoert
+pub const TIMER: &str = "timer";
+
+/// Vaak genoeg om de seconde-overgang binnen 0,1 s door te geven
+const TICK: Duration = Duration::from_millis(100);
+
+pub fn spawn(state: AppState) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(TICK);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        loop {
+            interval.tick().await;
+            match tick(&state) {
+                Ok(true) => {
+                    println!("[timer] ⏰ Tijd is om, schrijven geblokkeerd");
+                    if let Err(e) = state.dispatch(TIMER, GameAction::TimerExpired) {
+                        println!("[timer] ⚠️  Schrijven blokkeren mislukt: {}", e);
+                    }
+                }
+                Ok(false) => {}
+                Err(e) => println!("[timer] ⚠️  {}", e),
+            }
+        }
+    });
+}
+
+/// Eén tick. Geeft `true` als de timer net is afgelopen en schrijven geblokkeerd moet worden.
+fn tick(state: &AppState) -> Result<bool, GameError> {
+    let now = Instant::now();
+    let mut engine = state.engine.lock()?;
+    let Some(game) = engine.game_mut() else {
+        return Ok(false);
+    };
+    let mut clock = state.timer.lock()?;
+
+    let expired = clock.expire_if_due(now);
+    let changed = clock.write_to(game, now);
+    if expired {
+        state.commit(game);
+    } else if changed {
+        state.publish(game);
+    }
+    Ok(expired && game.rules.timer.lock_writing_on_expiry && game.writing_enabled)
+}
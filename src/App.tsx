@@ -4,21 +4,25 @@ import { GameSetup } from './components/GameSetup';
 import { ViewSelector } from './components/ViewSelector';
 import { UpdateNotification } from './components/UpdateNotification';
 import { useGame } from './hooks/useGame';
+import { Drawing, errorMessage } from './types/game';
 import './App.css';
 
 function App() {
   const [gameStarted, setGameStarted] = useState(false);
+  const [recoveryChecked, setRecoveryChecked] = useState(false);
   const {
     gameState,
     loading,
     error,
     startNewGame,
     updateAnswer,
-    clearPlayerAnswers,
     approveAnswer,
     collectInitialBets,
     addBetsToPot,
     placeBet,
+    checkBet,
+    callBet,
+    allIn,
     playerFold,
     completeRound,
     startNextRound,
@@ -29,19 +33,47 @@ function App() {
     setRoundNumber,
     updatePlayerName,
     undoLastAction,
+    getRecoverableGame,
+    resumeRecoveredGame,
+    discardRecoveredGame,
   } = useGame();
 
-  // Auto-start game met default namen bij eerste load
+  // Bij opstarten: bied een onafgerond spel uit de vorige sessie aan (crash recovery)
+  useEffect(() => {
+    const checkRecovery = async () => {
+      try {
+        const recovered = await getRecoverableGame();
+        if (recovered) {
+          const resume = window.confirm(
+            `Er is een onafgerond spel gevonden (ronde ${recovered.round_number}). Wil je dit spel hervatten?`
+          );
+          if (resume) {
+            await resumeRecoveredGame();
+            setGameStarted(true);
+          } else {
+            await discardRecoveredGame();
+          }
+        }
+      } catch (err) {
+        console.error('Fout bij herstellen spel:', err);
+      } finally {
+        setRecoveryChecked(true);
+      }
+    };
+    checkRecovery();
+  }, []);
+
+  // Auto-start game met default namen bij eerste load (pas na de recovery check)
   useEffect(() => {
     const autoStartGame = async () => {
-      if (!gameState && !loading && !error) {
+      if (recoveryChecked && !gameState && !loading && !error) {
         const defaultNames = ['Kandidaat 1', 'Kandidaat 2', 'Kandidaat 3'];
         await startNewGame(defaultNames);
         setGameStarted(true);
       }
     };
     autoStartGame();
-  }, [gameState, loading, error, startNewGame]);
+  }, [recoveryChecked, gameState, loading, error, startNewGame]);
 
   // Auto-update displays wanneer game state verandert
   useEffect(() => {
@@ -63,9 +95,9 @@ function App() {
     }
   };
 
-  const handleUpdateAnswer = async (playerId: string, questionNumber: number, imageData: string) => {
+  const handleUpdateAnswer = async (playerId: string, questionNumber: number, drawing: Drawing) => {
     try {
-      await updateAnswer(playerId, questionNumber, imageData);
+      await updateAnswer(playerId, questionNumber, drawing);
     } catch (err) {
       console.error('Fout bij updaten antwoord:', err);
     }
@@ -85,7 +117,34 @@ function App() {
       await placeBet(playerId, amount);
     } catch (err) {
       console.error('Fout bij plaatsen inzet:', err);
-      alert('Er is een fout opgetreden bij het plaatsen van de inzet');
+      alert(`Inzet niet geplaatst: ${errorMessage(err)}`);
+    }
+  };
+
+  const handleCheck = async (playerId: string) => {
+    try {
+      await checkBet(playerId);
+    } catch (err) {
+      console.error('Fout bij checken:', err);
+      alert(`Check niet mogelijk: ${errorMessage(err)}`);
+    }
+  };
+
+  const handleCall = async (playerId: string) => {
+    try {
+      await callBet(playerId);
+    } catch (err) {
+      console.error('Fout bij meegaan:', err);
+      alert(`Mee niet mogelijk: ${errorMessage(err)}`);
+    }
+  };
+
+  const handleAllIn = async (playerId: string) => {
+    try {
+      await allIn(playerId);
+    } catch (err) {
+      console.error('Fout bij all-in:', err);
+      alert(`All-in niet mogelijk: ${errorMessage(err)}`);
     }
   };
 
@@ -94,7 +153,7 @@ function App() {
       await playerFold(playerId);
     } catch (err) {
       console.error('Fout bij passen:', err);
-      alert('Er is een fout opgetreden bij het passen');
+      alert(`Passen niet mogelijk: ${errorMessage(err)}`);
     }
   };
 
@@ -104,18 +163,14 @@ function App() {
       await completeRound(winnerId);
     } catch (err) {
       console.error('Fout bij afronden ronde:', err);
-      alert('Er is een fout opgetreden bij het afronden van de ronde');
+      alert(`Ronde niet afgerond: ${errorMessage(err)}`);
+      throw err; // Zo start de QuizmasterView geen nieuwe ronde
     }
   };
 
   const handleStartNextRound = async () => {
     try {
-      // Clear all player answers before starting next round
-      if (gameState) {
-        for (const player of gameState.players) {
-          await clearPlayerAnswers(player.id);
-        }
-      }
+      // Antwoorden worden door de backend gewist bij het starten van de nieuwe ronde
       await startNextRound();
     } catch (err) {
       console.error('Fout bij starten nieuwe ronde:', err);
@@ -196,6 +251,9 @@ function App() {
         onCollectBets={handleCollectBets}
         onAddToPot={handleAddToPot}
         onPlaceBet={handlePlaceBet}
+        onCheck={handleCheck}
+        onCall={handleCall}
+        onAllIn={handleAllIn}
         onFold={handleFold}
         onCompleteRound={handleCompleteRound}
         onStartNextRound={handleStartNextRound}
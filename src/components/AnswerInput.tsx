@@ -1,14 +1,38 @@
-import { GameState } from '../types/game';
+import { ComponentProps, useEffect, useState } from 'react';
+import { Answer, BettingPhase, Drawing, GameState } from '../types/game';
 import { DrawingCanvas } from './DrawingCanvas';
+import { answerDrawingUrl } from './ServerConfig';
 import './AnswerInput.css';
 
 interface AnswerInputProps {
   gameState: GameState;
   playerId: string;
-  onUpdateAnswer: (playerId: string, questionNumber: number, imageData: string) => void;
+  onUpdateAnswer: (playerId: string, questionNumber: number, drawing: Drawing) => void;
+  rejection?: string | null; // Melding van de server als het laatste antwoord geweigerd is
 }
 
-export function AnswerInput({ gameState, playerId, onUpdateAnswer }: AnswerInputProps) {
+// Canvas met de opgeslagen pennenstreken van het antwoord; de state bevat alleen hun pad
+function AnswerCanvas({ answer, ...props }: { answer?: Answer } & Omit<ComponentProps<typeof DrawingCanvas>, 'initialDrawing'>) {
+  const [drawing, setDrawing] = useState<Drawing | undefined>(undefined);
+  const url = answerDrawingUrl(answer);
+
+  useEffect(() => {
+    if (!url) {
+      setDrawing(undefined);
+      return;
+    }
+    let cancelled = false;
+    fetch(url)
+      .then(response => response.ok ? response.json() : Promise.reject(new Error(`HTTP ${response.status}`)))
+      .then((loaded: Drawing) => { if (!cancelled) setDrawing(loaded); })
+      .catch(err => console.error(`[AnswerCanvas] Kan pennenstreken niet laden:`, err));
+    return () => { cancelled = true; };
+  }, [url]);
+
+  return <DrawingCanvas {...props} initialDrawing={drawing} />;
+}
+
+export function AnswerInput({ gameState, playerId, onUpdateAnswer, rejection }: AnswerInputProps) {
   const player = gameState.players.find(p => p.id === playerId);
   const questionsCount = gameState.current_round?.questions_count || 4;
 
@@ -22,18 +46,15 @@ export function AnswerInput({ gameState, playerId, onUpdateAnswer }: AnswerInput
     return <div className="answer-input-error">Speler niet gevonden</div>;
   }
 
-  const handleSave = (questionNumber: number, imageData: string) => {
-    console.log(`[AnswerInput] handleSave called: playerId=${playerId}, questionNumber=${questionNumber}, imageData.length=${imageData.length}`);
-    onUpdateAnswer(playerId, questionNumber, imageData);
-  };
+  // Zelfde regels als de server: schrijven open, ronde nog in de schrijffase, speler doet mee
+  const writingOpen = gameState.writing_enabled
+    && gameState.current_round?.phase === BettingPhase.Initial
+    && player.is_active
+    && !player.has_folded;
 
-  // Get existing answer image data
-  const getAnswerImage = (questionNumber: number): string | undefined => {
-    const answer = player.answers.find(a => a.question_number === questionNumber);
-    if (answer) {
-      console.log(`[AnswerInput] getAnswerImage for player ${player.name} (${playerId}), Q${questionNumber}: found answer, length=${answer.image_data.length}`);
-    }
-    return answer?.image_data;
+  const handleSave = (questionNumber: number, drawing: Drawing) => {
+    console.log(`[AnswerInput] handleSave called: playerId=${playerId}, questionNumber=${questionNumber}, strokes=${drawing.strokes.length}`);
+    onUpdateAnswer(playerId, questionNumber, drawing);
   };
 
   return (
@@ -47,16 +68,16 @@ export function AnswerInput({ gameState, playerId, onUpdateAnswer }: AnswerInput
             const isRevealed = gameState.current_round?.revealed_questions.includes(questionNumber) || false;
             
             return (
-              <DrawingCanvas
+              <AnswerCanvas
                 key={questionNumber}
+                answer={answer}
                 questionNumber={questionNumber}
                 playerId={playerId}
                 onSave={handleSave}
-                initialImage={getAnswerImage(questionNumber)}
                 autoSync={true}
                 isRevealed={isRevealed}
                 isCorrect={answer?.is_correct ?? null}
-                disabled={!gameState.writing_enabled}
+                disabled={!writingOpen}
               />
             );
           })}
@@ -65,10 +86,17 @@ export function AnswerInput({ gameState, playerId, onUpdateAnswer }: AnswerInput
 
       {/* Rechter kolom - Info */}
       <div className="info-column">
+        {rejection && (
+          <div className="answer-rejected">⚠️ Niet opgeslagen: {rejection}</div>
+        )}
+        {!writingOpen && !rejection && (
+          <div className="writing-closed">✋ Schrijven is gesloten</div>
+        )}
+
         {/* Ronde info */}
         <div className="round-info">
           <p className="round-number">Ronde</p>
-          <p className="round-value">{gameState.round_number}/7</p>
+          <p className="round-value">{gameState.round_number}/{gameState.rules.rounds}</p>
         </div>
 
         {/* Balances */}
@@ -2,6 +2,7 @@ import { useState, useEffect } from 'react';
 import { invoke } from '@tauri-apps/api/core';
 import { WebviewWindow } from '@tauri-apps/api/webviewWindow';
 import { DisplayConfig } from '../types/display';
+import { errorMessage } from '../types/game';
 import './DisplaySettings.css';
 
 export function DisplaySettings() {
@@ -46,7 +47,7 @@ export function DisplaySettings() {
       await invoke<DisplayConfig>('configure_display', { config });
       setMessage({ type: 'success', text: 'Display configuratie opgeslagen!' });
     } catch (err) {
-      setMessage({ type: 'error', text: `Fout: ${err}` });
+      setMessage({ type: 'error', text: `Fout: ${errorMessage(err)}` });
     } finally {
       setLoading(false);
     }
@@ -60,7 +61,7 @@ export function DisplaySettings() {
       await invoke('test_displays');
       setMessage({ type: 'success', text: 'Test verzonden! Zie je 8888, 7777, 6666, 5555?' });
     } catch (err) {
-      setMessage({ type: 'error', text: `Test mislukt: ${err}` });
+      setMessage({ type: 'error', text: `Test mislukt: ${errorMessage(err)}` });
     } finally {
       setLoading(false);
     }
@@ -74,7 +75,7 @@ export function DisplaySettings() {
       await invoke('clear_displays');
       setMessage({ type: 'success', text: 'Displays gewist!' });
     } catch (err) {
-      setMessage({ type: 'error', text: `Wissen mislukt: ${err}` });
+      setMessage({ type: 'error', text: `Wissen mislukt: ${errorMessage(err)}` });
     } finally {
       setLoading(false);
     }
@@ -88,7 +89,7 @@ export function DisplaySettings() {
       await invoke('update_display_values');
       setMessage({ type: 'success', text: 'Displays bijgewerkt met huidige spelwaarden!' });
     } catch (err) {
-      setMessage({ type: 'error', text: `Update mislukt: ${err}` });
+      setMessage({ type: 'error', text: `Update mislukt: ${errorMessage(err)}` });
     } finally {
       setLoading(false);
     }
@@ -1,22 +1,58 @@
 import { useEffect, useRef, useState } from 'react';
+import { Drawing, Stroke, StrokePoint } from '../types/game';
 import './DrawingCanvas.css';
 
+const CANVAS_WIDTH = 600;
+const CANVAS_HEIGHT = 150;
+const LINE_WIDTH = 5; // Zelfde als de renderer op de server: dikte × (0.5 + druk)
+const SYNC_INTERVAL_MS = 500; // Tijdens het schrijven hooguit twee syncs per seconde
+
 interface DrawingCanvasProps {
   questionNumber: number;
   playerId: string;
-  onSave: (questionNumber: number, imageData: string) => void;
-  initialImage?: string;
+  onSave: (questionNumber: number, drawing: Drawing) => void;
+  initialDrawing?: Drawing;
   autoSync?: boolean; // Auto-sync na elke stroke
   isRevealed?: boolean;
   isCorrect?: boolean | null;
   disabled?: boolean; // Blokkeer schrijven en wissen
 }
 
+// Is `older` een eerdere versie van `newer`? Dan is het een late echo van onze eigen sync.
+function isEarlierVersion(older: Stroke[], newer: Stroke[]): boolean {
+  if (older.length > newer.length) return false;
+  return older.every((stroke, i) => {
+    const points = newer[i].points;
+    const last = stroke.points.length - 1;
+    return stroke.points.length <= points.length && (last < 0 || stroke.points[last].t === points[last].t);
+  });
+}
+
+function drawSegment(ctx: CanvasRenderingContext2D, from: StrokePoint, to: StrokePoint) {
+  ctx.lineWidth = LINE_WIDTH * (0.5 + (from.pressure + to.pressure) / 2);
+  ctx.beginPath();
+  ctx.moveTo(from.x, from.y);
+  // Losse tik: minimale lijn voor zichtbaarheid
+  if (from === to) {
+    ctx.lineTo(to.x + 0.5, to.y + 0.5);
+  } else {
+    ctx.lineTo(to.x, to.y);
+  }
+  ctx.stroke();
+}
+
+function drawStrokes(ctx: CanvasRenderingContext2D, strokes: Stroke[]) {
+  ctx.clearRect(0, 0, CANVAS_WIDTH, CANVAS_HEIGHT);
+  for (const stroke of strokes) {
+    stroke.points.forEach((point, i) => drawSegment(ctx, i === 0 ? point : stroke.points[i - 1], point));
+  }
+}
+
 export function DrawingCanvas({ 
   questionNumber, 
   playerId, 
   onSave, 
-  initialImage,
+  initialDrawing,
   autoSync = true,
   isRevealed = false,
   isCorrect = null,
@@ -24,13 +60,11 @@ export function DrawingCanvas({
 }: DrawingCanvasProps) {
   const canvasRef = useRef<HTMLCanvasElement>(null);
   const [isDrawing, setIsDrawing] = useState(false);
-  const syncTimeoutRef = useRef<number>();
-  const rafRef = useRef<number>();
   const needsSyncRef = useRef(false);
-  const lastPosRef = useRef<{x: number, y: number} | null>(null);
-  const loadedImageRef = useRef<string>(''); // Track welke image we hebben geladen
+  const strokesRef = useRef<Stroke[]>([]); // Alle pennenstreken van dit antwoord
+  const startedAtRef = useRef<number | null>(null); // Moment van het eerste punt, voor de tijd per punt
   const hasDrawnRef = useRef(false); // Track of de user daadwerkelijk heeft getekend
-  const currentPathRef = useRef<Path2D | null>(null); // Track het huidige pad
+  const pendingClearRef = useRef(false); // Gewist, maar de server heeft de oude streken nog
 
   // Setup canvas - alleen 1x bij mount
   useEffect(() => {
@@ -41,17 +75,18 @@ export function DrawingCanvas({
     if (!ctx) return;
 
     // Set canvas size
-    canvas.width = 600;
-    canvas.height = 150;
+    canvas.width = CANVAS_WIDTH;
+    canvas.height = CANVAS_HEIGHT;
 
     // Drawing settings
     ctx.strokeStyle = '#2c3e50';
-    ctx.lineWidth = 5;
+    ctx.lineWidth = LINE_WIDTH;
     ctx.lineCap = 'round';
     ctx.lineJoin = 'round';
   }, []); // Geen dependencies - alleen bij mount
 
-  // Load initial image - maar niet tijdens tekenen EN alleen als de image echt is veranderd
+  // Streken van de server overnemen (na herladen, of na undo door de quizmaster) -
+  // maar niet tijdens tekenen en niet als het een oudere versie van onze eigen streken is
   useEffect(() => {
     const canvas = canvasRef.current;
     if (!canvas) return;
@@ -59,48 +94,50 @@ export function DrawingCanvas({
     const ctx = canvas.getContext('2d');
     if (!ctx) return;
 
-    // Als initialImage leeg is, reset de loadedImageRef (belangrijk voor undo!)
-    if (!initialImage || initialImage.length === 0) {
-      if (loadedImageRef.current !== '') {
-        console.log(`[DrawingCanvas] Clearing loaded image ref for question ${questionNumber}`);
-        loadedImageRef.current = '';
-      }
+    const strokes = initialDrawing?.strokes ?? [];
+    if (pendingClearRef.current) {
+      pendingClearRef.current = strokes.length > 0;
       return;
     }
+    if (isDrawing || isEarlierVersion(strokes, strokesRef.current)) return;
 
-    // Alleen laden als we niet aan het tekenen zijn EN de image is anders dan de laatst geladen
-    if (!isDrawing && initialImage !== loadedImageRef.current) {
-      console.log(`[DrawingCanvas] Loading initial image for question ${questionNumber}, length=${initialImage.length}`);
-      hasDrawnRef.current = false; // Reset DIRECT - user heeft niet getekend
-      const img = new Image();
-      img.onload = () => {
-        ctx.clearRect(0, 0, canvas.width, canvas.height);
-        ctx.drawImage(img, 0, 0);
-        loadedImageRef.current = initialImage; // Markeer deze image als geladen
-      };
-      img.src = initialImage;
-    }
-  }, [initialImage, isDrawing, questionNumber]);
+    console.log(`[DrawingCanvas] Loading ${strokes.length} strokes for question ${questionNumber}`);
+    hasDrawnRef.current = false; // Reset DIRECT - user heeft niet getekend
+    strokesRef.current = strokes.map(stroke => ({ points: [...stroke.points] }));
+    const lastT = Math.max(0, ...strokes.flatMap(stroke => stroke.points.map(p => p.t)));
+    startedAtRef.current = strokes.length > 0 ? performance.now() - lastT : null;
+    drawStrokes(ctx, strokesRef.current);
+  }, [initialDrawing, isDrawing, questionNumber]);
 
-  // Animation loop voor snelle sync
+  // Tijdens het schrijven elke SYNC_INTERVAL_MS syncen, zodat de quizmaster meekijkt
   useEffect(() => {
-    const syncLoop = () => {
-      // Only sync if user has drawn AND needs sync
-      if (needsSyncRef.current && autoSync && hasDrawnRef.current) {
-        saveCanvas();
+    if (!autoSync) return;
+
+    const interval = window.setInterval(() => {
+      if (needsSyncRef.current && hasDrawnRef.current) {
         needsSyncRef.current = false;
+        saveDrawing();
       }
-      rafRef.current = requestAnimationFrame(syncLoop);
-    };
-    
-    rafRef.current = requestAnimationFrame(syncLoop);
-    
-    return () => {
-      if (rafRef.current) {
-        cancelAnimationFrame(rafRef.current);
-      }
+    }, SYNC_INTERVAL_MS);
+
+    return () => window.clearInterval(interval);
+  }, [autoSync, questionNumber, playerId]);
+
+  // Punt in canvas coördinaten, met druk (Apple Pencil geeft `force`, muis en vinger niet) en tijd
+  const toPoint = (clientX: number, clientY: number, force?: number): StrokePoint => {
+    const canvas = canvasRef.current!;
+    const rect = canvas.getBoundingClientRect();
+    const now = performance.now();
+    if (startedAtRef.current === null) {
+      startedAtRef.current = now;
+    }
+    return {
+      x: ((clientX - rect.left) / rect.width) * canvas.width,
+      y: ((clientY - rect.top) / rect.height) * canvas.height,
+      pressure: force && force > 0 ? Math.min(force, 1) : 0.5,
+      t: Math.round(now - startedAtRef.current),
     };
-  }, [autoSync]);
+  };
 
   const startDrawing = (e: React.MouseEvent<HTMLCanvasElement> | React.TouchEvent<HTMLCanvasElement>) => {
     if (disabled) return; // Blokkeer schrijven als disabled
@@ -111,8 +148,7 @@ export function DrawingCanvas({
     const ctx = canvas.getContext('2d');
     if (!ctx) return;
 
-    const rect = canvas.getBoundingClientRect();
-    let clientX, clientY;
+    let point: StrokePoint;
 
     if ('touches' in e) {
       // Touch event
@@ -131,32 +167,18 @@ export function DrawingCanvas({
         return; // Negeer alleen expliciete vinger touches
       }
       
-      clientX = touch.clientX;
-      clientY = touch.clientY;
+      point = toPoint(touch.clientX, touch.clientY, touch.force);
     } else {
       // Mouse event (voor development op desktop)
-      clientX = e.clientX;
-      clientY = e.clientY;
+      point = toPoint(e.clientX, e.clientY);
     }
 
     setIsDrawing(true);
     hasDrawnRef.current = true; // Mark that user has actively drawn
 
-    // Schaal naar canvas resolutie
-    const x = ((clientX - rect.left) / rect.width) * canvas.width;
-    const y = ((clientY - rect.top) / rect.height) * canvas.height;
-
-    // Maak een nieuw Path2D object voor dit stroke
-    const path = new Path2D();
-    path.moveTo(x, y);
-    path.lineTo(x + 0.5, y + 0.5); // Minimale lijn voor zichtbaarheid
-    
-    // Teken het path
-    ctx.stroke(path);
-    
-    // Sla het path op voor verdere gebruik
-    currentPathRef.current = path;
-    lastPosRef.current = { x, y };
+    // Nieuwe streek, eerste punt direct zichtbaar
+    strokesRef.current.push({ points: [point] });
+    drawSegment(ctx, point, point);
     
     // Markeer voor sync
     needsSyncRef.current = true;
@@ -171,10 +193,13 @@ export function DrawingCanvas({
     const ctx = canvas.getContext('2d');
     if (!ctx) return;
 
-    const rect = canvas.getBoundingClientRect();
-    const path = currentPathRef.current;
-    
-    if (!path) return; // Geen actief path
+    const stroke = strokesRef.current[strokesRef.current.length - 1];
+    if (!stroke) return; // Geen actieve streek
+
+    const addPoint = (point: StrokePoint) => {
+      drawSegment(ctx, stroke.points[stroke.points.length - 1], point);
+      stroke.points.push(point);
+    };
 
     if ('touches' in e) {
       // Touch event
@@ -192,73 +217,42 @@ export function DrawingCanvas({
       // @ts-ignore - getCoalescedEvents is niet in alle type definitions
       const events = e.nativeEvent.getCoalescedEvents ? e.nativeEvent.getCoalescedEvents() : [e.nativeEvent];
       
-      // Voeg alle gemiste tussenliggende punten toe aan het path
+      // Voeg alle gemiste tussenliggende punten toe aan de streek
       for (const event of events) {
         const coalescedTouch = event.touches ? event.touches[0] : event;
-        const x = ((coalescedTouch.clientX - rect.left) / rect.width) * canvas.width;
-        const y = ((coalescedTouch.clientY - rect.top) / rect.height) * canvas.height;
-        
-        path.lineTo(x, y);
-        lastPosRef.current = { x, y };
+        addPoint(toPoint(coalescedTouch.clientX, coalescedTouch.clientY, coalescedTouch.force));
       }
-      
-      // Teken het complete path één keer
-      ctx.stroke(path);
     } else {
       // Mouse event (voor development op desktop)
-      const x = ((e.clientX - rect.left) / rect.width) * canvas.width;
-      const y = ((e.clientY - rect.top) / rect.height) * canvas.height;
-      
-      path.lineTo(x, y);
-      ctx.stroke(path);
-      
-      lastPosRef.current = { x, y };
+      addPoint(toPoint(e.clientX, e.clientY));
     }
     
-    // Markeer dat we moeten syncen (wordt gedaan in de animatieloop)
+    // Markeer dat we moeten syncen (wordt gedaan door de interval)
     needsSyncRef.current = true;
   };
 
   const stopDrawing = () => {
     setIsDrawing(false);
-    lastPosRef.current = null;
-    currentPathRef.current = null; // Clear het path
     
-    if (autoSync) {
-      // Debounced sync: only sync if 500ms passed since last sync
-      if (syncTimeoutRef.current) {
-        clearTimeout(syncTimeoutRef.current);
-      }
-      
-      syncTimeoutRef.current = setTimeout(() => {
-        saveCanvas();
-      }, 500);
+    // Pen eraf: meteen de laatste stand syncen
+    if (autoSync && needsSyncRef.current) {
+      needsSyncRef.current = false;
+      saveDrawing();
     }
   };
 
-  const saveCanvas = () => {
-    const canvas = canvasRef.current;
-    if (!canvas) return;
-
-    const ctx = canvas.getContext('2d');
-    if (!ctx) return;
-
-    // Check if canvas is empty (all pixels are transparent)
-    const imageData = ctx.getImageData(0, 0, canvas.width, canvas.height);
-    const data = imageData.data;
-    let isEmpty = true;
-    for (let i = 3; i < data.length; i += 4) {
-      if (data[i] !== 0) { // Check alpha channel
-        isEmpty = false;
-        break;
-      }
-    }
-
-    // Only save if canvas has content AND user has actually drawn
-    if (!isEmpty && hasDrawnRef.current) {
-      const imageDataUrl = canvas.toDataURL('image/png');
-      console.log(`[DrawingCanvas] saveCanvas called: playerId=${playerId}, questionNumber=${questionNumber}, imageData.length=${imageDataUrl.length}`);
-      onSave(questionNumber, imageDataUrl);
+  const currentDrawing = (): Drawing => ({
+    width: CANVAS_WIDTH,
+    height: CANVAS_HEIGHT,
+    strokes: strokesRef.current,
+  });
+
+  const saveDrawing = () => {
+    // Only save if there are strokes AND user has actually drawn
+    if (strokesRef.current.length > 0 && hasDrawnRef.current) {
+      const drawing = currentDrawing();
+      console.log(`[DrawingCanvas] saveDrawing called: playerId=${playerId}, questionNumber=${questionNumber}, strokes=${drawing.strokes.length}`);
+      onSave(questionNumber, drawing);
     } else if (!hasDrawnRef.current) {
       console.log(`[DrawingCanvas] User hasn't drawn yet, not saving: playerId=${playerId}, questionNumber=${questionNumber}`);
     } else {
@@ -278,14 +272,14 @@ export function DrawingCanvas({
     // Clear canvas (transparant)
     ctx.clearRect(0, 0, canvas.width, canvas.height);
     
-    // Reset loaded image ref so it doesn't reload
-    loadedImageRef.current = '';
+    strokesRef.current = [];
+    startedAtRef.current = null;
     hasDrawnRef.current = false; // Reset draw tracker
+    pendingClearRef.current = true;
 
-    // Save the EMPTY canvas to the server so it doesn't reload the old data
-    const emptyImageDataUrl = canvas.toDataURL('image/png');
-    console.log(`[DrawingCanvas] Canvas cleared and saving empty image for playerId=${playerId}, questionNumber=${questionNumber}`);
-    onSave(questionNumber, emptyImageDataUrl);
+    // Save the EMPTY drawing to the server so it doesn't reload the old strokes
+    console.log(`[DrawingCanvas] Canvas cleared and saving empty drawing for playerId=${playerId}, questionNumber=${questionNumber}`);
+    onSave(questionNumber, currentDrawing());
   };
 
   return (
@@ -81,7 +81,7 @@ export function GraphicsOutput({ gameState, mode, timer = 0 }: GraphicsOutputPro
       {/* Debug info (alleen in fill mode) */}
       {!isKeyMode && (
         <div className="debug-info">
-          <small>Ronde: {gameState.round_number}/7 | Mode: {mode.toUpperCase()}</small>
+          <small>Ronde: {gameState.round_number}/{gameState.rules.rounds} | Mode: {mode.toUpperCase()}</small>
         </div>
       )}
     </div>
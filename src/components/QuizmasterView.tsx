@@ -1,6 +1,8 @@
 import { useState, useEffect, useRef } from 'react';
-import { GameState, BettingPhase } from '../types/game';
+import { ArchiveReport, GameState, BettingPhase, Player, errorMessage } from '../types/game';
 import { invoke } from '@tauri-apps/api/core';
+import { answerAnimationUrl, answerImageUrl, episodeReportUrl } from './ServerConfig';
+import { RoundHistory } from './RoundHistory';
 import './QuizmasterView.css';
 
 interface QuizmasterViewProps {
@@ -8,7 +10,10 @@ interface QuizmasterViewProps {
   onApproveAnswer: (playerId: string, questionNumber: number, isCorrect: boolean) => void;
   onCollectBets: () => void;
   onAddToPot: () => void;
-  onPlaceBet?: (playerId: string, amount: number) => void;
+  onPlaceBet?: (playerId: string, amount: number) => void; // Verhogen met amount
+  onCheck?: (playerId: string) => void;
+  onCall?: (playerId: string) => void;
+  onAllIn?: (playerId: string) => void;
   onPlayerFold: (playerId: string) => void;
   onCompleteRound: (winnerId?: string) => void;
   onStartNextRound: () => void;
@@ -31,44 +36,15 @@ function getPlayerNumber(playerId: string): number {
   return 0;
 }
 
-// Bepaal wie de eerste hand heeft op basis van rondenummer
-function getFirstHandPlayer(roundNumber: number, players: any[]) {
-  // Basis patroon: (round - 1) % 3 = player index
-  // Ronde 1, 4, 7 -> player_0 (Kandidaat 1)
-  // Ronde 2, 5 -> player_1 (Kandidaat 2)
-  // Ronde 3, 6 -> player_2 (Kandidaat 3)
-  const basePlayerIndex = (roundNumber - 1) % 3;
-  
-  // Zoek de basis speler
-  const basePlayerId = `player_${basePlayerIndex}`;
-  const basePlayer = players.find(p => p.id === basePlayerId);
-  
-  // Als de basis speler actief is, gebruik die
-  if (basePlayer && basePlayer.is_active) {
-    return basePlayer;
-  }
-  
-  // Anders: zoek de VOLGENDE actieve speler in circulaire rotatie
-  // Start vanaf basePlayerIndex en loop door (0, 1, 2, 0, 1, 2, ...)
-  for (let i = 1; i <= 3; i++) {
-    const nextIndex = (basePlayerIndex + i) % 3;
-    const nextPlayerId = `player_${nextIndex}`;
-    const nextPlayer = players.find(p => p.id === nextPlayerId);
-    
-    if (nextPlayer && nextPlayer.is_active) {
-      return nextPlayer;
-    }
-  }
-  
-  return null; // Geen actieve spelers
-}
-
 export function QuizmasterView({
   gameState,
   onApproveAnswer,
   onCollectBets,
   onAddToPot,
   onPlaceBet,
+  onCheck,
+  onCall,
+  onAllIn,
   onPlayerFold,
   onCompleteRound,
   onStartNextRound,
@@ -80,32 +56,51 @@ export function QuizmasterView({
   onUpdatePlayerName,
   onUndoLastAction,
 }: QuizmasterViewProps) {
-  const { players, current_round, round_number, is_finished, writing_enabled, video_mode_active, timer_seconds, timer_running } = gameState;
+  const { players, current_round, round_number, is_finished, writing_enabled, video_mode_active, timer_seconds, timer_running, timer_mode, timer_duration, rules } = gameState;
   const [editingPlayerId, setEditingPlayerId] = useState<string | null>(null);
   const [editedName, setEditedName] = useState('');
   const [customTimerInput, setCustomTimerInput] = useState('');
   const [showUndo, setShowUndo] = useState(false);
   const undoTimerRef = useRef<number | null>(null);
   
-  // Bepaal wie de eerste hand heeft
-  const firstHandPlayer = getFirstHandPlayer(round_number, players);
+  // Wie de eerste hand heeft bepaalt de backend (deler roteert per ronde)
+  const firstHandPlayer = current_round ? players[current_round.dealer_index] : null;
   
   // Timer loopt nu volledig in de backend - geen frontend setInterval meer nodig!
   
   // Timer handlers
   const handleTimerStart = async () => {
     try {
-      await invoke('start_timer');
+      await invoke('start_timer'); // Start of hervat
     } catch (err) {
       console.error('Failed to start timer:', err);
+      alert(`Timer niet gestart: ${errorMessage(err)}`);
+    }
+  };
+  
+  const handleTimerPause = async () => {
+    try {
+      await invoke('pause_timer');
+    } catch (err) {
+      console.error('Failed to pause timer:', err);
+    }
+  };
+  
+  // Standaardtijd van de huidige fase (schrijven of inzetten) instellen en starten
+  const handlePhaseTimer = async () => {
+    try {
+      await invoke('start_phase_timer');
+    } catch (err) {
+      console.error('Failed to start phase timer:', err);
+      alert(`Timer niet gestart: ${errorMessage(err)}`);
     }
   };
   
-  const handleTimerStop = async () => {
+  const handleStopwatch = async () => {
     try {
-      await invoke('stop_timer');
+      await invoke('set_timer', { seconds: 0, mode: 'stopwatch' });
     } catch (err) {
-      console.error('Failed to stop timer:', err);
+      console.error('Failed to set stopwatch:', err);
     }
   };
   
@@ -151,8 +146,9 @@ export function QuizmasterView({
     return `${mins}:${secs.toString().padStart(2, '0')}`;
   };
   
-  // Gebruik de timer_seconds direct uit de backend
+  // Gebruik de timer_seconds direct uit de backend (aftellend: resterende tijd)
   const displayTime = timer_seconds;
+  const timerExpired = timer_mode === 'countdown' && timer_duration > 0 && timer_seconds === 0;
 
   const handleEditName = (playerId: string, currentName: string) => {
     setEditingPlayerId(playerId);
@@ -179,7 +175,7 @@ export function QuizmasterView({
   };
 
   const handleChangeRound = async (newRound: number) => {
-    if (newRound < 1 || newRound > 7) return;
+    if (newRound < 1 || newRound > rules.rounds) return;
     try {
       await onSetRoundNumber(newRound);
       // State wordt automatisch geüpdatet via de hook
@@ -191,10 +187,7 @@ export function QuizmasterView({
 
   const handleSchoon = async () => {
     try {
-      // EERST: Sla huidige state op voor undo
-      await invoke('save_state_for_undo');
-      
-      // DAN: Start nieuwe ronde (schonen)
+      // Start nieuwe ronde (schonen) - de backend legt zelf een undo stap vast
       await onStartNextRound();
       
       // Toon undo knop voor 15 seconden
@@ -231,6 +224,20 @@ export function QuizmasterView({
     }
   };
 
+  // Hele aflevering als zip voor montage en compliance: rondes, acties en tekeningen
+  const handleExportArchive = async () => {
+    try {
+      const report = await invoke<ArchiveReport>('export_episode_archive', { gameId: null });
+      const missing = report.missing_images.length > 0
+        ? `\n⚠️ ${report.missing_images.length} tekening(en) ontbraken op schijf`
+        : '';
+      alert(`Archief opgeslagen (${report.rounds} rondes, ${report.events} acties, ${report.images} tekeningen):\n${report.path}${missing}`);
+    } catch (err) {
+      console.error('Archive export failed:', err);
+      alert(`Archief exporteren mislukt: ${errorMessage(err)}`);
+    }
+  };
+
   // Cleanup undo timer bij unmount
   useEffect(() => {
     return () => {
@@ -264,6 +271,13 @@ export function QuizmasterView({
                 </div>
               ))}
           </div>
+
+          <button className="control-btn primary export-archive-btn" onClick={handleExportArchive}>
+            📦 Archief exporteren
+          </button>
+          <button className="control-btn export-archive-btn" onClick={() => window.open(episodeReportUrl(), 'episode_report')}>
+            📄 Rapport
+          </button>
         </div>
       </div>
     );
@@ -294,6 +308,24 @@ export function QuizmasterView({
           >
             🔄 Reset Spel
           </button>
+
+          <button
+            className="control-btn"
+            onClick={handleExportArchive}
+            style={{ marginTop: '10px' }}
+            title="Alle rondes, acties en tekeningen tot nu toe als zip"
+          >
+            📦 Archief exporteren
+          </button>
+
+          <button
+            className="control-btn"
+            onClick={() => window.open(episodeReportUrl(), 'episode_report')}
+            style={{ marginTop: '10px' }}
+            title="Rapport met alle rondes tot nu toe, om af te drukken of als PDF op te slaan"
+          >
+            📄 Rapport
+          </button>
         </div>
 
         <div className="player-grid">
@@ -340,29 +372,26 @@ export function QuizmasterView({
   }
 
   const phase = current_round.phase;
+  const isBettingPhase = phase === BettingPhase.FirstBetting || phase === BettingPhase.SecondBetting;
+  const isPlayersTurn = (playerId: string) =>
+    isBettingPhase && players[current_round.current_player_index]?.id === playerId;
 
-  // Bereken hoogste inzet voor "MEE" functionaliteit
-  const getHighestBet = () => {
-    return Math.max(...players.map(p => p.current_bet), current_round.min_bet);
-  };
-
-  const handleCallBet = (playerId: string) => {
-    if (!onPlaceBet) return;
-    
+  // Hoeveel een speler nog moet bijleggen om mee te gaan (backend houdt de hoogste inzet bij)
+  const getAmountToCall = (playerId: string) => {
     const player = players.find(p => p.id === playerId);
-    if (!player) return;
-    
-    const highestBet = getHighestBet();
-    const amountToCall = highestBet - player.current_bet;
-    
-    if (amountToCall > 0) {
-      onPlaceBet(playerId, amountToCall);
-    }
+    return player ? Math.max(current_round.highest_bet - player.current_bet, 0) : 0;
   };
 
-  const handleDeclareWinner = async (playerId: string) => {
+  // Gelijke stand: meerdere niet-gepaste spelers met het hoogste aantal goede antwoorden
+  const contenders = players.filter(p => p.is_active && !p.has_folded);
+  const correctCount = (player: Player) => player.answers.filter(a => a.is_correct === true).length;
+  const bestScore = Math.max(0, ...contenders.map(correctCount));
+  const tiedPlayers = contenders.filter(p => correctCount(p) === bestScore);
+  const isTie = phase === BettingPhase.DetermineWinner && tiedPlayers.length > 1;
+
+  const handleDeclareWinner = async (playerId?: string) => {
     const player = players.find(p => p.id === playerId);
-    console.log(`Declaring winner: ${player?.name} (ID: ${playerId}), Pot: €${current_round.pot}`);
+    console.log(`Declaring winner: ${player?.name ?? 'op goede antwoorden'} (ID: ${playerId}), Pot: €${current_round.pot}`);
     
     try {
       await onCompleteRound(playerId);
@@ -411,16 +440,16 @@ export function QuizmasterView({
             >
               ◀
             </button>
-            <span style={{ fontSize: '18px', fontWeight: 'bold', minWidth: '50px', textAlign: 'center', color: '#fff' }}>{round_number} / 7</span>
+            <span style={{ fontSize: '18px', fontWeight: 'bold', minWidth: '50px', textAlign: 'center', color: '#fff' }}>{round_number} / {rules.rounds}</span>
             <button 
               onClick={() => handleChangeRound(round_number + 1)}
-              disabled={round_number >= 7}
+              disabled={round_number >= rules.rounds}
               style={{ 
                 background: 'rgba(255,255,255,0.1)', 
                 border: 'none', 
-                color: round_number >= 7 ? '#888' : '#fff', 
+                color: round_number >= rules.rounds ? '#888' : '#fff', 
                 fontSize: '16px', 
-                cursor: round_number >= 7 ? 'not-allowed' : 'pointer',
+                cursor: round_number >= rules.rounds ? 'not-allowed' : 'pointer',
                 padding: '4px 8px',
                 borderRadius: '4px'
               }}
@@ -444,14 +473,14 @@ export function QuizmasterView({
               minWidth: '70px',
               textAlign: 'center',
               padding: '4px 8px',
-              background: 'rgba(255, 255, 255, 0.1)',
+              background: timerExpired ? '#f44336' : 'rgba(255, 255, 255, 0.1)',
               borderRadius: '4px',
               color: '#fff'
-            }}>
-              {formatTime(displayTime)}
+            }} title={timer_mode === 'countdown' ? `Aftellen vanaf ${formatTime(timer_duration)}` : 'Stopwatch'}>
+              {timer_mode === 'stopwatch' ? '⏱ ' : ''}{formatTime(displayTime)}
             </div>
             <button onClick={handleTimerStart} disabled={timer_running} style={{ padding: '4px 10px', fontSize: '14px', background: timer_running ? '#444' : '#4CAF50', border: 'none', borderRadius: '4px', color: 'white', cursor: timer_running ? 'not-allowed' : 'pointer' }}>▶</button>
-            <button onClick={handleTimerStop} disabled={!timer_running} style={{ padding: '4px 10px', fontSize: '14px', background: !timer_running ? '#444' : '#ff9800', border: 'none', borderRadius: '4px', color: 'white', cursor: !timer_running ? 'not-allowed' : 'pointer' }}>⏸</button>
+            <button onClick={handleTimerPause} disabled={!timer_running} style={{ padding: '4px 10px', fontSize: '14px', background: !timer_running ? '#444' : '#ff9800', border: 'none', borderRadius: '4px', color: 'white', cursor: !timer_running ? 'not-allowed' : 'pointer' }}>⏸</button>
             <button onClick={handleTimerReset} style={{ padding: '4px 10px', fontSize: '14px', background: '#f44336', border: 'none', borderRadius: '4px', color: 'white', cursor: 'pointer' }}>🔄</button>
           </div>
           <div style={{ display: 'flex', gap: '4px' }}>
@@ -475,6 +504,8 @@ export function QuizmasterView({
             <button onClick={() => handleSetTimer(60)} style={{ padding: '3px 6px', fontSize: '10px', background: '#555', border: 'none', borderRadius: '3px', color: 'white', cursor: 'pointer' }}>1m</button>
             <button onClick={() => handleSetTimer(90)} style={{ padding: '3px 6px', fontSize: '10px', background: '#555', border: 'none', borderRadius: '3px', color: 'white', cursor: 'pointer' }}>1:30</button>
             <button onClick={() => handleSetTimer(120)} style={{ padding: '3px 6px', fontSize: '10px', background: '#555', border: 'none', borderRadius: '3px', color: 'white', cursor: 'pointer' }}>2m</button>
+            <button onClick={handlePhaseTimer} title={`Schrijven ${formatTime(rules.timer.writing_seconds)}, inzetten ${formatTime(rules.timer.betting_seconds)}`} style={{ padding: '3px 6px', fontSize: '10px', background: '#2196F3', border: 'none', borderRadius: '3px', color: 'white', cursor: 'pointer' }}>Fase</button>
+            <button onClick={handleStopwatch} title="Optellen zonder einde" style={{ padding: '3px 6px', fontSize: '10px', background: '#555', border: 'none', borderRadius: '3px', color: 'white', cursor: 'pointer' }}>⏱</button>
           </div>
         </div>
 
@@ -635,12 +666,53 @@ export function QuizmasterView({
         })}
       </div>
 
+      {/* Vragen uit de vragenbank voor deze ronde */}
+      {current_round.questions.length > 0 && (
+        <div className="round-questions">
+          {current_round.questions.map((question, i) => (
+            <div key={i} className="round-question">
+              <span className="question-number">V{i + 1}</span>
+              <span className="round-question-text">{question.text}</span>
+              <span className="round-question-answer">
+                {question.answer}
+                {question.accepted_variants.length > 0 && (
+                  <span className="round-question-variants"> ({question.accepted_variants.join(', ')})</span>
+                )}
+              </span>
+              {current_round.question_notes[i] && (
+                <span className="round-question-notes">📝 {current_round.question_notes[i]}</span>
+              )}
+            </div>
+          ))}
+        </div>
+      )}
+
+      {/* Gelijke stand: afhandeling volgens de spelregels */}
+      {isTie && (
+        <div className="tie-banner">
+          <span>
+            ⚖️ Gelijke stand ({bestScore} goed): {tiedPlayers.map(p => p.name).join(', ')}
+          </span>
+          {rules.tie_rule === 'split_pot' && (
+            <button className="tie-split-btn" onClick={() => handleDeclareWinner()}>
+              POT DELEN
+            </button>
+          )}
+          {rules.tie_rule === 'sudden_death' && (
+            <span className="tie-hint">Stel de beslisvraag en kies daarna de winnaar</span>
+          )}
+          {rules.tie_rule === 'quizmaster_choice' && (
+            <span className="tie-hint">Kies de winnaar</span>
+          )}
+        </div>
+      )}
+
       {/* Player Panels */}
       <div className="players-grid">
         {players.map((player) => (
           <div 
             key={player.id} 
-            className={`player-panel ${player.has_folded ? 'folded' : ''} ${player.balance <= 0 ? 'eliminated' : ''}`}
+            className={`player-panel ${player.has_folded ? 'folded' : ''} ${player.balance <= 0 ? 'eliminated' : ''} ${isPlayersTurn(player.id) ? 'current-turn' : ''}`}
           >
             {/* Player Header */}
             <div className="player-header">
@@ -722,44 +794,54 @@ export function QuizmasterView({
                 </div>
               </div>
 
-              {/* Quick Bet Buttons - Altijd zichtbaar behalve in Initial en Completed fase */}
-              {onPlaceBet && phase !== BettingPhase.Initial && phase !== BettingPhase.Completed && (
+              {/* Verhoog Knoppen - Alleen tijdens een inzetronde, actief voor wie aan de beurt is */}
+              {onPlaceBet && isBettingPhase && (
                 <div className="quick-bet-buttons">
                   <button 
                     className="bet-btn"
                     onClick={() => onPlaceBet(player.id, 10)}
-                    disabled={player.has_folded || player.balance < 10}
+                    disabled={!isPlayersTurn(player.id) || player.has_folded || player.balance < getAmountToCall(player.id) + 10}
                   >
-                    €10
+                    +€10
                   </button>
                   <button 
                     className="bet-btn"
                     onClick={() => onPlaceBet(player.id, 20)}
-                    disabled={player.has_folded || player.balance < 20}
+                    disabled={!isPlayersTurn(player.id) || player.has_folded || player.balance < getAmountToCall(player.id) + 20}
                   >
-                    €20
+                    +€20
                   </button>
                   <button 
                     className="bet-btn"
                     onClick={() => onPlaceBet(player.id, 30)}
-                    disabled={player.has_folded || player.balance < 30}
+                    disabled={!isPlayersTurn(player.id) || player.has_folded || player.balance < getAmountToCall(player.id) + 30}
                   >
-                    €30
+                    +€30
                   </button>
                   <button 
                     className="bet-btn"
                     onClick={() => onPlaceBet(player.id, 40)}
-                    disabled={player.has_folded || player.balance < 40}
+                    disabled={!isPlayersTurn(player.id) || player.has_folded || player.balance < getAmountToCall(player.id) + 40}
                   >
-                    €40
+                    +€40
                   </button>
                   <button 
                     className="bet-btn"
                     onClick={() => onPlaceBet(player.id, 50)}
-                    disabled={player.has_folded || player.balance < 50}
+                    disabled={!isPlayersTurn(player.id) || player.has_folded || player.balance < getAmountToCall(player.id) + 50}
                   >
-                    €50
+                    +€50
                   </button>
+                  {onAllIn && (
+                    <button 
+                      className="bet-btn all-in"
+                      onClick={() => onAllIn(player.id)}
+                      disabled={!isPlayersTurn(player.id) || player.has_folded || player.balance <= 0}
+                      title={`Alles inzetten (€${player.balance})`}
+                    >
+                      ALL-IN
+                    </button>
+                  )}
                 </div>
               )}
 
@@ -777,7 +859,7 @@ export function QuizmasterView({
                   className={`status-btn pass ${player.has_folded ? 'active' : ''}`}
                   title="Speler past"
                   onClick={() => onPlayerFold(player.id)}
-                  disabled={player.has_folded}
+                  disabled={!isPlayersTurn(player.id) || player.has_folded}
                 >
                   PASS
                 </button>
@@ -793,15 +875,26 @@ export function QuizmasterView({
                     <span>Afvaller</span>
                   </label>
                 )}
-                {onPlaceBet && phase !== BettingPhase.Initial && phase !== BettingPhase.Completed ? (
-                  <button 
-                    className={`status-btn mee ${!player.has_folded && player.current_bet === getHighestBet() ? 'active' : ''}`}
-                    title={`Match hoogste inzet (€${getHighestBet() - player.current_bet} toevoegen)`}
-                    onClick={() => handleCallBet(player.id)}
-                    disabled={player.has_folded || player.current_bet >= getHighestBet()}
-                  >
-                    MEE
-                  </button>
+                {onCall && onCheck && isBettingPhase ? (
+                  getAmountToCall(player.id) > 0 ? (
+                    <button 
+                      className="status-btn mee"
+                      title={`Match hoogste inzet (€${getAmountToCall(player.id)} toevoegen)`}
+                      onClick={() => onCall(player.id)}
+                      disabled={!isPlayersTurn(player.id) || player.has_folded}
+                    >
+                      MEE €{getAmountToCall(player.id)}
+                    </button>
+                  ) : (
+                    <button 
+                      className="status-btn mee"
+                      title="Niets bijleggen"
+                      onClick={() => onCheck(player.id)}
+                      disabled={!isPlayersTurn(player.id) || player.has_folded}
+                    >
+                      CHECK
+                    </button>
+                  )
                 ) : (
                   <button 
                     className={`status-btn mee ${!player.has_folded && player.current_bet === current_round.min_bet ? 'active' : ''}`}
@@ -821,16 +914,22 @@ export function QuizmasterView({
               </div>
             )}
 
+            {/* All-in Indicator - speler doet nog mee om (een deel van) de pot */}
+            {player.is_all_in && !player.has_folded && (
+              <div className="all-in-badge">ALL-IN</div>
+            )}
+
             {/* Answers Section - LIVE BEOORDELING */}
             <div className="answers-section">
               {Array.from({ length: current_round.questions_count }, (_, index) => {
                 const questionNum = index + 1;
                 const answer = player.answers.find(a => a.question_number === questionNum);
                 const isCorrect = answer?.is_correct;
+                const question = current_round.questions[index];
                 
                 // Debug: Log answer data
-                if (answer && answer.image_data) {
-                  console.log(`[QuizmasterView] Player ${player.name} (${player.id}), Q${questionNum}: has answer, length=${answer.image_data.length}`);
+                if (answer && answer.image_path) {
+                  console.log(`[QuizmasterView] Player ${player.name} (${player.id}), Q${questionNum}: has answer, path=${answer.image_path}`);
                 }
                 
                 return (
@@ -840,19 +939,37 @@ export function QuizmasterView({
                     <div className="answer-controls">
                       {/* Answer preview */}
                       <div className="answer-preview">
-                        {answer && answer.image_data ? (
-                          <img 
-                            src={answer.image_data} 
-                            alt={`Antwoord ${questionNum}`}
-                            className="answer-thumbnail"
-                          />
+                        {answer && answer.image_path ? (
+                          <>
+                            <img 
+                              src={answerImageUrl(answer)} 
+                              alt={`Antwoord ${questionNum}`}
+                              className="answer-thumbnail"
+                            />
+                            {answer.drawing_path && (
+                              <button
+                                className="replay-answer-btn"
+                                onClick={() => window.open(answerAnimationUrl(answer), `replay_${player.id}_${questionNum}`)}
+                                title="Schrijven afspelen (voor een replay)"
+                              >
+                                ▶
+                              </button>
+                            )}
+                          </>
                         ) : (
                           <span className="no-answer">---</span>
                         )}
                       </div>
 
+                      {/* Goede antwoord naast de tekening, zodat de quizmaster direct kan beoordelen */}
+                      {question && (
+                        <div className="expected-answer" title={question.text}>
+                          {question.answer}
+                        </div>
+                      )}
+
                       {/* LIVE APPROVAL - Altijd beschikbaar tijdens schrijven */}
-                      {answer && answer.image_data && (
+                      {answer && answer.image_path && (
                         <div className="approval-controls">
                           <button
                             className={`approve-icon ${isCorrect === true ? 'active' : ''}`}
@@ -888,6 +1005,8 @@ export function QuizmasterView({
           </div>
         ))}
       </div>
+
+      <RoundHistory rounds={gameState.round_history} />
     </div>
   );
 }
@@ -0,0 +1,72 @@
+import { RoundResult } from '../types/game';
+import { answerImageUrl } from './ServerConfig';
+import './RoundHistory.css';
+
+interface RoundHistoryProps {
+  rounds: RoundResult[];
+}
+
+// Afgeronde rondes terugkijken: per speler de tekeningen met het oordeel van de quizmaster
+export function RoundHistory({ rounds }: RoundHistoryProps) {
+  if (rounds.length === 0) {
+    return null;
+  }
+
+  return (
+    <details className="round-history">
+      <summary>📜 Rondegeschiedenis ({rounds.length})</summary>
+      {/* Laatste ronde bovenaan */}
+      {[...rounds].reverse().map((round, index) => {
+        // Snapshots van vóór de geschiedenis hebben geen rondenummer
+        const roundNumber = round.round_number || rounds.length - index;
+        return (
+          <div key={roundNumber} className="history-round">
+            <div className="history-round-header">
+              <span className="history-round-number">Ronde {roundNumber}</span>
+              <span className="history-round-winner">
+                🏆 {round.winners.length > 0 ? round.winners.map(w => w.player_name).join(' & ') : round.winner_name}
+              </span>
+              <span className="history-round-pot">€{round.pot_amount}</span>
+            </div>
+
+            {round.answers.length === 0 ? (
+              <div className="history-empty">Geen antwoorden bewaard</div>
+            ) : (
+              round.answers.map(playerAnswers => (
+                <div key={playerAnswers.player_id} className={`history-player ${playerAnswers.has_folded ? 'folded' : ''}`}>
+                  <div className="history-player-name">
+                    {playerAnswers.player_name}
+                    {playerAnswers.has_folded && <span className="history-folded"> (gepast)</span>}
+                  </div>
+                  <div className="history-answers">
+                    {playerAnswers.answers.map(answer => {
+                      const question = round.questions[answer.question_number - 1];
+                      const verdict = answer.is_correct === true ? 'correct' : answer.is_correct === false ? 'wrong' : 'open';
+                      return (
+                        <div
+                          key={answer.question_number}
+                          className={`history-answer ${verdict}`}
+                          title={`${question ? `${question.text} — ${question.answer}\n` : ''}Geschreven: ${new Date(answer.timestamp).toLocaleTimeString('nl-NL')}`}
+                        >
+                          <span className="history-question-number">V{answer.question_number}</span>
+                          {answer.image_path ? (
+                            <img src={answerImageUrl(answer)} alt={`Antwoord ${answer.question_number}`} />
+                          ) : (
+                            <span className="no-answer">---</span>
+                          )}
+                          <span className="history-verdict">
+                            {verdict === 'correct' ? '✓' : verdict === 'wrong' ? '✗' : '?'}
+                          </span>
+                        </div>
+                      );
+                    })}
+                  </div>
+                </div>
+              ))
+            )}
+          </div>
+        );
+      })}
+    </details>
+  );
+}
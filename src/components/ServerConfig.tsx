@@ -1,4 +1,5 @@
 import { useState, useEffect } from 'react';
+import { Answer } from '../types/game';
 import './ServerConfig.css';
 
 const DEFAULT_SERVER = 'http://localhost:3001';
@@ -190,3 +191,26 @@ export function getServerUrl(): string {
   return localStorage.getItem(STORAGE_KEY) || DEFAULT_SERVER;
 }
 
+
+// URL van de tekening bij een antwoord. De timestamp zorgt dat een nieuwe versie niet uit de browser cache komt.
+export function answerImageUrl(answer: Answer | undefined): string | undefined {
+  if (!answer?.image_path) return undefined;
+  return `${getServerUrl()}/api/answers/${answer.image_path}?v=${encodeURIComponent(answer.timestamp)}`;
+}
+
+// Pennenstreken van een antwoord (JSON), om verder te schrijven op een eerder antwoord
+export function answerDrawingUrl(answer: Answer | undefined): string | undefined {
+  if (!answer?.drawing_path) return undefined;
+  return `${getServerUrl()}/api/drawings/${answer.drawing_path}?v=${encodeURIComponent(answer.timestamp)}`;
+}
+
+// Geanimeerde PNG van het schrijven, op broadcast breedte, voor replays
+export function answerAnimationUrl(answer: Answer | undefined, width = 1920): string | undefined {
+  const url = answerImageUrl(answer);
+  return url && `${url}&animate=true&width=${width}`;
+}
+
+// Rapport van de aflevering (HTML), af te drukken of als PDF op te slaan vanuit de browser
+export function episodeReportUrl(): string {
+  return `${getServerUrl()}/api/report`;
+}
@@ -1,6 +1,7 @@
 import { useEffect, useState } from 'react';
 import { invoke } from '@tauri-apps/api/core';
 import { open } from '@tauri-apps/plugin-shell';
+import { errorMessage } from '../types/game';
 import './UpdateNotification.css';
 
 interface UpdateInfo {
@@ -32,7 +33,7 @@ export function UpdateNotification() {
       }
     } catch (err) {
       console.error('❌ Update check failed:', err);
-      setError(String(err));
+      setError(errorMessage(err));
     } finally {
       setChecking(false);
     }
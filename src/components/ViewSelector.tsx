@@ -1,5 +1,5 @@
 import { useState } from 'react';
-import { GameState } from '../types/game';
+import { Drawing, GameState } from '../types/game';
 import { QuizmasterView } from './QuizmasterView';
 import { AnswerInput } from './AnswerInput';
 import { DisplaySettings } from './DisplaySettings';
@@ -9,11 +9,14 @@ import './ViewSelector.css';
 
 interface ViewSelectorProps {
   gameState: GameState;
-  onUpdateAnswer: (playerId: string, questionNumber: number, imageData: string) => void;
+  onUpdateAnswer: (playerId: string, questionNumber: number, drawing: Drawing) => void;
   onApproveAnswer: (playerId: string, questionNumber: number, isCorrect: boolean) => void;
   onCollectBets: () => void;
   onAddToPot: () => void;
   onPlaceBet: (playerId: string, amount: number) => void;
+  onCheck: (playerId: string) => void;
+  onCall: (playerId: string) => void;
+  onAllIn: (playerId: string) => void;
   onFold: (playerId: string) => void;
   onCompleteRound: (winnerId?: string) => void;
   onStartNextRound: () => void;
@@ -54,6 +57,9 @@ export function ViewSelector(props: ViewSelectorProps) {
             onCollectBets={props.onCollectBets}
             onAddToPot={props.onAddToPot}
             onPlaceBet={props.onPlaceBet}
+            onCheck={props.onCheck}
+            onCall={props.onCall}
+            onAllIn={props.onAllIn}
             onRevealQuestion={props.onRevealQuestion}
             onPlayerFold={props.onFold}
             onCompleteRound={props.onCompleteRound}
@@ -1,21 +1,21 @@
 import { useState, useEffect } from 'react';
 import { invoke } from '@tauri-apps/api/core';
-import { GameState } from '../types/game';
+import { Drawing, Episode, EpisodeSummary, errorMessage, GameState, HistoryOverview, ImportReport, NewQuestion, PlayerTimeline, Question, QuestionFilter, QuestionFormat, RuleSet } from '../types/game';
 
 export function useGame() {
   const [gameState, setGameState] = useState<GameState | null>(null);
   const [loading, setLoading] = useState(false);
   const [error, setError] = useState<string | null>(null);
 
-  const startNewGame = async (playerNames: string[]) => {
+  const startNewGame = async (playerNames: string[], ruleSet?: string) => {
     try {
       setLoading(true);
       setError(null);
-      const game = await invoke<GameState>('start_new_game', { playerNames });
+      const game = await invoke<GameState>('start_new_game', { playerNames, ruleSet: ruleSet || null });
       setGameState(game);
       return game;
     } catch (err) {
-      const errorMsg = err instanceof Error ? err.message : String(err);
+      const errorMsg = errorMessage(err);
       setError(errorMsg);
       throw err;
     } finally {
@@ -29,23 +29,23 @@ export function useGame() {
       setGameState(game);
       return game;
     } catch (err) {
-      const errorMsg = err instanceof Error ? err.message : String(err);
+      const errorMsg = errorMessage(err);
       setError(errorMsg);
       throw err;
     }
   };
 
-  const updateAnswer = async (playerId: string, questionNumber: number, imageData: string) => {
+  const updateAnswer = async (playerId: string, questionNumber: number, drawing: Drawing) => {
     try {
       const game = await invoke<GameState>('update_answer', { 
         playerId, 
         questionNumber, 
-        imageData 
+        drawing 
       });
       setGameState(game);
       return game;
     } catch (err) {
-      const errorMsg = err instanceof Error ? err.message : String(err);
+      const errorMsg = errorMessage(err);
       setError(errorMsg);
       throw err;
     }
@@ -57,7 +57,7 @@ export function useGame() {
       setGameState(game);
       return game;
     } catch (err) {
-      const errorMsg = err instanceof Error ? err.message : String(err);
+      const errorMsg = errorMessage(err);
       setError(errorMsg);
       throw err;
     }
@@ -73,12 +73,13 @@ export function useGame() {
       setGameState(game);
       return game;
     } catch (err) {
-      const errorMsg = err instanceof Error ? err.message : String(err);
+      const errorMsg = errorMessage(err);
       setError(errorMsg);
       throw err;
     }
   };
 
+  // Verhogen met `amount` bovenop de hoogste inzet
   const placeBet = async (playerId: string, amount: number) => {
     try {
       setLoading(true);
@@ -87,7 +88,55 @@ export function useGame() {
       setGameState(game);
       return game;
     } catch (err) {
-      const errorMsg = err instanceof Error ? err.message : String(err);
+      const errorMsg = errorMessage(err);
+      setError(errorMsg);
+      throw err;
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  const checkBet = async (playerId: string) => {
+    try {
+      setLoading(true);
+      setError(null);
+      const game = await invoke<GameState>('check_bet', { playerId });
+      setGameState(game);
+      return game;
+    } catch (err) {
+      const errorMsg = errorMessage(err);
+      setError(errorMsg);
+      throw err;
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  const callBet = async (playerId: string) => {
+    try {
+      setLoading(true);
+      setError(null);
+      const game = await invoke<GameState>('call_bet', { playerId });
+      setGameState(game);
+      return game;
+    } catch (err) {
+      const errorMsg = errorMessage(err);
+      setError(errorMsg);
+      throw err;
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  const allIn = async (playerId: string) => {
+    try {
+      setLoading(true);
+      setError(null);
+      const game = await invoke<GameState>('all_in', { playerId });
+      setGameState(game);
+      return game;
+    } catch (err) {
+      const errorMsg = errorMessage(err);
       setError(errorMsg);
       throw err;
     } finally {
@@ -103,7 +152,7 @@ export function useGame() {
       setGameState(game);
       return game;
     } catch (err) {
-      const errorMsg = err instanceof Error ? err.message : String(err);
+      const errorMsg = errorMessage(err);
       setError(errorMsg);
       throw err;
     } finally {
@@ -119,7 +168,7 @@ export function useGame() {
       setGameState(game);
       return game;
     } catch (err) {
-      const errorMsg = err instanceof Error ? err.message : String(err);
+      const errorMsg = errorMessage(err);
       setError(errorMsg);
       throw err;
     } finally {
@@ -135,7 +184,7 @@ export function useGame() {
       setGameState(game);
       return game;
     } catch (err) {
-      const errorMsg = err instanceof Error ? err.message : String(err);
+      const errorMsg = errorMessage(err);
       setError(errorMsg);
       throw err;
     } finally {
@@ -149,7 +198,7 @@ export function useGame() {
       setGameState(game);
       return game;
     } catch (err) {
-      const errorMsg = err instanceof Error ? err.message : String(err);
+      const errorMsg = errorMessage(err);
       setError(errorMsg);
       throw err;
     }
@@ -161,7 +210,7 @@ export function useGame() {
       setGameState(game);
       return game;
     } catch (err) {
-      const errorMsg = err instanceof Error ? err.message : String(err);
+      const errorMsg = errorMessage(err);
       setError(errorMsg);
       throw err;
     }
@@ -175,7 +224,7 @@ export function useGame() {
       setGameState(game);
       return game;
     } catch (err) {
-      const errorMsg = err instanceof Error ? err.message : String(err);
+      const errorMsg = errorMessage(err);
       setError(errorMsg);
       throw err;
     } finally {
@@ -202,7 +251,7 @@ export function useGame() {
       setGameState(game);
       return game;
     } catch (err) {
-      const errorMsg = err instanceof Error ? err.message : String(err);
+      const errorMsg = errorMessage(err);
       setError(errorMsg);
       throw err;
     } finally {
@@ -218,7 +267,7 @@ export function useGame() {
       setGameState(game);
       return game;
     } catch (err) {
-      const errorMsg = err instanceof Error ? err.message : String(err);
+      const errorMsg = errorMessage(err);
       setError(errorMsg);
       throw err;
     } finally {
@@ -234,7 +283,7 @@ export function useGame() {
       setGameState(game);
       return game;
     } catch (err) {
-      const errorMsg = err instanceof Error ? err.message : String(err);
+      const errorMsg = errorMessage(err);
       setError(errorMsg);
       throw err;
     } finally {
@@ -250,7 +299,7 @@ export function useGame() {
       setGameState(game);
       return game;
     } catch (err) {
-      const errorMsg = err instanceof Error ? err.message : String(err);
+      const errorMsg = errorMessage(err);
       setError(errorMsg);
       throw err;
     } finally {
@@ -266,7 +315,7 @@ export function useGame() {
       setGameState(game);
       return game;
     } catch (err) {
-      const errorMsg = err instanceof Error ? err.message : String(err);
+      const errorMsg = errorMessage(err);
       setError(errorMsg);
       throw err;
     } finally {
@@ -282,7 +331,143 @@ export function useGame() {
       setGameState(game);
       return game;
     } catch (err) {
-      const errorMsg = err instanceof Error ? err.message : String(err);
+      const errorMsg = errorMessage(err);
+      setError(errorMsg);
+      throw err;
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  const redoLastAction = async () => {
+    try {
+      setLoading(true);
+      setError(null);
+      const game = await invoke<GameState>('redo_last_action');
+      setGameState(game);
+      return game;
+    } catch (err) {
+      const errorMsg = errorMessage(err);
+      setError(errorMsg);
+      throw err;
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  const getUndoHistory = async () => {
+    return await invoke<HistoryOverview>('get_undo_history');
+  };
+
+  const getRecoverableGame = async () => {
+    return await invoke<GameState | null>('get_recoverable_game');
+  };
+
+  const resumeRecoveredGame = async () => {
+    try {
+      setLoading(true);
+      setError(null);
+      const game = await invoke<GameState>('resume_recovered_game');
+      setGameState(game);
+      return game;
+    } catch (err) {
+      const errorMsg = errorMessage(err);
+      setError(errorMsg);
+      throw err;
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  const discardRecoveredGame = async () => {
+    await invoke('discard_recovered_game');
+  };
+
+  const searchQuestions = async (filter?: QuestionFilter) => {
+    return await invoke<Question[]>('search_questions', { filter: filter || null });
+  };
+
+  const getQuestionCategories = async () => {
+    return await invoke<string[]>('get_question_categories');
+  };
+
+  const addQuestion = async (question: NewQuestion) => {
+    return await invoke<Question>('add_question', { question });
+  };
+
+  const updateQuestion = async (id: number, question: NewQuestion) => {
+    return await invoke<Question>('update_question', { id, question });
+  };
+
+  const deleteQuestion = async (id: number) => {
+    await invoke('delete_question', { id });
+  };
+
+  // Bestand uit een <input type="file"> naar de backend; dryRun geeft alleen het rapport
+  const importQuestions = async (file: File, dryRun = false) => {
+    const data = Array.from(new Uint8Array(await file.arrayBuffer()));
+    return await invoke<ImportReport>('import_questions', { fileName: file.name, data, dryRun });
+  };
+
+  const exportQuestions = async (format: QuestionFormat, filter?: QuestionFilter) => {
+    const bytes = await invoke<number[]>('export_questions', { format, filter: filter || null });
+    return new Uint8Array(bytes);
+  };
+
+  const assignRoundQuestions = async (questionIds: number[]) => {
+    try {
+      setLoading(true);
+      setError(null);
+      const game = await invoke<GameState>('assign_round_questions', { questionIds });
+      setGameState(game);
+      return game;
+    } catch (err) {
+      const errorMsg = errorMessage(err);
+      setError(errorMsg);
+      throw err;
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  // Saldoverloop per speler; zonder playerId alle spelers
+  const getBalanceTimeline = async (playerId?: string) => {
+    return await invoke<PlayerTimeline[]>('get_balance_timeline', { playerId: playerId || null });
+  };
+
+  const listRuleSets = async () => {
+    return await invoke<RuleSet[]>('list_rule_sets');
+  };
+
+  const saveRuleSet = async (rules: RuleSet) => {
+    await invoke('save_rule_set', { rules });
+  };
+
+  const listEpisodes = async () => {
+    return await invoke<EpisodeSummary[]>('list_episodes');
+  };
+
+  const getEpisode = async (episodeId: string) => {
+    return await invoke<Episode>('get_episode', { episodeId });
+  };
+
+  const saveEpisode = async (episode: Episode) => {
+    await invoke('save_episode', { episode });
+  };
+
+  const deleteEpisode = async (episodeId: string) => {
+    await invoke('delete_episode', { episodeId });
+  };
+
+  const startEpisode = async (episodeId: string) => {
+    try {
+      setLoading(true);
+      setError(null);
+      const game = await invoke<GameState>('start_episode', { episodeId });
+      setGameState(game);
+      return game;
+    } catch (err) {
+      const errorMsg = errorMessage(err);
       setError(errorMsg);
       throw err;
     } finally {
@@ -302,6 +487,9 @@ export function useGame() {
     collectInitialBets,
     addBetsToPot,
     placeBet,
+    checkBet,
+    callBet,
+    allIn,
     playerFold,
     completeRound,
     startNextRound,
@@ -312,5 +500,26 @@ export function useGame() {
     setRoundNumber,
     updatePlayerName,
     undoLastAction,
+    redoLastAction,
+    getUndoHistory,
+    getBalanceTimeline,
+    getRecoverableGame,
+    resumeRecoveredGame,
+    discardRecoveredGame,
+    searchQuestions,
+    getQuestionCategories,
+    addQuestion,
+    updateQuestion,
+    deleteQuestion,
+    importQuestions,
+    exportQuestions,
+    assignRoundQuestions,
+    listRuleSets,
+    saveRuleSet,
+    listEpisodes,
+    getEpisode,
+    saveEpisode,
+    deleteEpisode,
+    startEpisode,
   };
 }
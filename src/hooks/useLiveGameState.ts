@@ -0,0 +1,68 @@
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { GameState } from '../types/game';
+import { getServerUrl } from '../components/ServerConfig';
+
+export type LiveClient = 'full' | 'fill' | 'key' | 'player';
+
+interface LiveOptions {
+  playerId?: string;     // Verplicht voor client 'player'
+  pollInterval?: number; // Alleen binnen Tauri (geen WebSocket nodig)
+}
+
+// Live game state voor graphics en tablets.
+// Binnen Tauri pollen we de command, daarbuiten pusht de HTTP server elke wijziging via /api/ws.
+export function useLiveGameState(client: LiveClient, { playerId, pollInterval = 100 }: LiveOptions = {}) {
+  const [gameState, setGameState] = useState<GameState | null>(null);
+
+  useEffect(() => {
+    if (typeof window !== 'undefined' && window.__TAURI_INTERNALS__) {
+      const fetchState = async () => {
+        try {
+          setGameState(await invoke<GameState>('get_game_state'));
+        } catch (err) {
+          console.error('Failed to fetch game state:', err);
+        }
+      };
+      fetchState();
+      const interval = setInterval(fetchState, pollInterval);
+      return () => clearInterval(interval);
+    }
+
+    let socket: WebSocket | null = null;
+    let reconnectTimer: number | undefined;
+    let closed = false;
+
+    const connect = () => {
+      const wsUrl = new URL(`${getServerUrl().replace(/^http/, 'ws')}/api/ws`);
+      wsUrl.searchParams.set('client', client);
+      if (playerId) {
+        wsUrl.searchParams.set('player_id', playerId);
+      }
+
+      socket = new WebSocket(wsUrl.toString());
+      socket.onmessage = (event) => {
+        try {
+          setGameState(JSON.parse(event.data));
+        } catch (err) {
+          console.error('Invalid game state from server:', err);
+        }
+      };
+      socket.onclose = () => {
+        // Server herstart of netwerk weg: na een seconde opnieuw verbinden
+        if (!closed) {
+          reconnectTimer = window.setTimeout(connect, 1000);
+        }
+      };
+    };
+
+    connect();
+    return () => {
+      closed = true;
+      window.clearTimeout(reconnectTimer);
+      socket?.close();
+    };
+  }, [client, playerId, pollInterval]);
+
+  return gameState;
+}
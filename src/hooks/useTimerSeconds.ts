@@ -0,0 +1,29 @@
+import { useEffect, useRef, useState } from 'react';
+import { GameState } from '../types/game';
+
+// Timer zoals de server hem ziet, vloeiend doorgeteld tussen twee updates.
+// De server stuurt de stand van zijn monotone klok mee (timer_remaining_ms); wij tellen verder vanaf
+// het moment van ontvangst, zodat een client met een verkeerde systeemklok toch de juiste tijd toont.
+export function useTimerSeconds(gameState: GameState | null): number {
+  const [seconds, setSeconds] = useState(gameState?.timer_seconds ?? 0);
+  const received = useRef({ ms: 0, at: 0 });
+
+  useEffect(() => {
+    if (!gameState) return;
+    received.current = { ms: gameState.timer_remaining_ms, at: performance.now() };
+    setSeconds(gameState.timer_seconds);
+  }, [gameState?.timer_remaining_ms, gameState?.timer_seconds, gameState?.timer_running]);
+
+  useEffect(() => {
+    if (!gameState?.timer_running) return;
+    const countdown = gameState.timer_mode === 'countdown';
+    const interval = setInterval(() => {
+      const passed = performance.now() - received.current.at;
+      const ms = countdown ? Math.max(0, received.current.ms - passed) : received.current.ms + passed;
+      setSeconds(countdown ? Math.ceil(ms / 1000) : Math.floor(ms / 1000));
+    }, 100);
+    return () => clearInterval(interval);
+  }, [gameState?.timer_running, gameState?.timer_mode]);
+
+  return seconds;
+}
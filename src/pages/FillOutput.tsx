@@ -1,7 +1,7 @@
 import { useEffect, useState } from 'react';
-import { invoke } from '@tauri-apps/api/core';
-import { GameState } from '../types/game';
-import { getServerUrl } from '../components/ServerConfig';
+import { useLiveGameState } from '../hooks/useLiveGameState';
+import { useTimerSeconds } from '../hooks/useTimerSeconds';
+import { answerImageUrl } from '../components/ServerConfig';
 import '../styles/fill-output.css';
 
 // ============================================================
@@ -73,37 +73,9 @@ function getFirstHandPlayer(roundNumber: number, players: any[]) {
 }
 
 export function FillOutput() {
-  const [gameState, setGameState] = useState<GameState | null>(null);
+  const gameState = useLiveGameState('fill');
   const [scale, setScale] = useState(1);
-  const [localTimerSeconds, setLocalTimerSeconds] = useState(0);
-
-  useEffect(() => {
-    const fetchState = async () => {
-      try {
-        // Check if running in Tauri context
-        if (typeof window !== 'undefined' && window.__TAURI_INTERNALS__) {
-          const state = await invoke<GameState>('get_game_state');
-          setGameState(state);
-        } else {
-          // Gebruik geconfigureerde server URL (voor OBS Browser Source / externe displays)
-          const serverUrl = getServerUrl();
-          const response = await fetch(`${serverUrl}/api/gamestate`);
-          if (response.ok) {
-            const state = await response.json();
-            setGameState(state);
-          }
-        }
-      } catch (err) {
-        console.error('Failed to fetch game state:', err);
-      }
-    };
-
-    // Poll very frequently for smooth updates (100ms = 10fps)
-    fetchState();
-    const interval = setInterval(fetchState, 100);
-
-    return () => clearInterval(interval);
-  }, []);
+  const timerSeconds = useTimerSeconds(gameState);
 
   // Bereken schaal voor 1920x1080 canvas
   useEffect(() => {
@@ -117,24 +89,6 @@ export function FillOutput() {
     return () => window.removeEventListener('resize', updateScale);
   }, []);
 
-  // Sync lokale timer met backend
-  useEffect(() => {
-    if (gameState) {
-      setLocalTimerSeconds(gameState.timer_seconds);
-    }
-  }, [gameState?.timer_seconds]);
-
-  // Lokale timer interval voor smooth updates
-  useEffect(() => {
-    if (gameState?.timer_running) {
-      const interval = setInterval(() => {
-        setLocalTimerSeconds(prev => prev + 1);
-      }, 1000);
-      
-      return () => clearInterval(interval);
-    }
-  }, [gameState?.timer_running]);
-
   // Use default values if game not started yet
   const players = gameState?.players || [
     { id: 'player_0', name: 'Kandidaat 1', balance: 750, answers: [], has_folded: false, is_active: true, current_bet: 0 },
@@ -303,7 +257,7 @@ export function FillOutput() {
         minWidth: '120px',
         textAlign: 'center'
       }}>
-        {formatTime(localTimerSeconds)}
+        {formatTime(timerSeconds)}
       </div>
       
       {/* Bedragen - alleen actieve spelers op hun oorspronkelijke posities */}
@@ -408,9 +362,9 @@ export function FillOutput() {
                 overflow: 'hidden'
               }}
             >
-              {answer?.image_data && (
+              {answer?.image_path && (
                 <img 
-                  src={answer.image_data} 
+                  src={answerImageUrl(answer)} 
                   alt={`Answer ${qIndex + 1}`}
                   style={{
                     maxWidth: '100%',
@@ -1,40 +1,11 @@
 import { useEffect, useState } from 'react';
-import { invoke } from '@tauri-apps/api/core';
-import { GameState } from '../types/game';
-import { getServerUrl } from '../components/ServerConfig';
+import { useLiveGameState } from '../hooks/useLiveGameState';
 import '../styles/key-output.css';
 
 export function KeyOutput() {
-  const [gameState, setGameState] = useState<GameState | null>(null);
+  const gameState = useLiveGameState('key', { pollInterval: 1000 });
   const [scale, setScale] = useState(1);
 
-  useEffect(() => {
-    const fetchState = async () => {
-      try {
-        // Check if running in Tauri context
-        if (typeof window !== 'undefined' && window.__TAURI_INTERNALS__) {
-          const state = await invoke<GameState>('get_game_state');
-          setGameState(state);
-        } else {
-          // Gebruik geconfigureerde server URL (voor OBS Browser Source / externe displays)
-          const serverUrl = getServerUrl();
-          const response = await fetch(`${serverUrl}/api/gamestate`);
-          if (response.ok) {
-            const state = await response.json();
-            setGameState(state);
-          }
-        }
-      } catch (err) {
-        console.error('Failed to fetch game state:', err);
-      }
-    };
-
-    fetchState();
-    const interval = setInterval(fetchState, 1000);
-
-    return () => clearInterval(interval);
-  }, []);
-
   // Bereken schaal voor 1920x1080 canvas
   useEffect(() => {
     const updateScale = () => {
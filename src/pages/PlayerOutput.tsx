@@ -1,40 +1,26 @@
-import { useEffect, useState } from 'react';
+import { useState } from 'react';
 import { invoke } from '@tauri-apps/api/core';
-import { GameState } from '../types/game';
 import { AnswerInput } from '../components/AnswerInput';
 import { VideoDisplay } from '../components/VideoDisplay';
 import { getServerUrl } from '../components/ServerConfig';
+import { useLiveGameState } from '../hooks/useLiveGameState';
+import { Drawing, GameError, errorMessage } from '../types/game';
 
 interface PlayerOutputProps {
   playerNumber: 1 | 2 | 3;
 }
 
 export function PlayerOutput({ playerNumber }: PlayerOutputProps) {
-  const [gameState, setGameState] = useState<GameState | null>(null);
-
-  const fetchState = async () => {
-    try {
-      // Check if we're in Tauri context
-      if (window.__TAURI_INTERNALS__) {
-        const state = await invoke<GameState>('get_game_state');
-        setGameState(state);
-      } else {
-        // Gebruik geconfigureerde server URL of fallback
-        const serverUrl = getServerUrl();
-        const response = await fetch(`${serverUrl}/api/gamestate`);
-        const data = await response.json();
-        setGameState(data);
-      }
-    } catch (error) {
-      console.error('Failed to fetch game state:', error);
-    }
-  };
+  const playerId = `player_${playerNumber - 1}`;
+  const gameState = useLiveGameState('player', { playerId });
+  // Waarom de server het laatste antwoord weigerde (schrijven gesloten, speler uitgeschakeld, ...)
+  const [rejection, setRejection] = useState<string | null>(null);
 
-  const handleUpdateAnswer = async (playerId: string, questionNumber: number, imageData: string) => {
+  const handleUpdateAnswer = async (playerId: string, questionNumber: number, drawing: Drawing) => {
     try {
       if (window.__TAURI_INTERNALS__) {
-        await invoke('update_answer', { playerId, questionNumber, imageData });
-        fetchState();
+        await invoke('update_answer', { playerId, questionNumber, drawing });
+        setRejection(null);
       } else {
         // Gebruik geconfigureerde server URL
         const serverUrl = getServerUrl();
@@ -46,27 +32,25 @@ export function PlayerOutput({ playerNumber }: PlayerOutputProps) {
           body: JSON.stringify({
             player_id: playerId,
             question_number: questionNumber,
-            image_data: imageData,
+            drawing,
           }),
         });
         
         if (response.ok) {
           console.log('Answer updated via HTTP API');
+          setRejection(null);
         } else {
-          console.error('Failed to update answer via HTTP API');
+          const error: GameError = await response.json();
+          console.error(`Failed to update answer via HTTP API (${error.code}): ${error.message}`);
+          setRejection(error.message);
         }
       }
     } catch (error) {
       console.error('Failed to update answer:', error);
+      setRejection(errorMessage(error));
     }
   };
 
-  useEffect(() => {
-    fetchState();
-    const interval = setInterval(fetchState, 100);
-    return () => clearInterval(interval);
-  }, []);
-
   if (!gameState) {
     return (
       <div style={{ 
@@ -83,8 +67,6 @@ export function PlayerOutput({ playerNumber }: PlayerOutputProps) {
     );
   }
 
-  const playerId = `player_${playerNumber - 1}`;
-  
   // Als video mode actief is, toon de video display
   if (gameState.video_mode_active) {
     return <VideoDisplay deviceId={gameState.video_device_id || undefined} />;
@@ -95,6 +77,7 @@ export function PlayerOutput({ playerNumber }: PlayerOutputProps) {
       gameState={gameState}
       playerId={playerId}
       onUpdateAnswer={handleUpdateAnswer}
+      rejection={rejection}
     />
   );
 }
@@ -6,15 +6,34 @@ export interface Player {
   answers: Answer[];
   is_active: boolean;
   has_folded: boolean;
+  is_all_in: boolean; // Heeft deze ronde alles ingezet wat er nog was
 }
 
 export interface Answer {
   question_number: number;
-  image_data: string; // Base64 encoded canvas image
+  image_path: string; // Pad van de tekening op de server, zie answerImageUrl
+  drawing_path: string; // Pad van de pennenstreken, zie answerDrawingUrl; alleen voor de eigen tablet en de quizmaster
   is_correct: boolean | null; // null = nog niet beoordeeld
   timestamp: string;
 }
 
+export interface StrokePoint {
+  x: number; // Canvas coördinaten van de tablet
+  y: number;
+  pressure: number; // 0 - 1, 0.5 zonder drukgevoelige pen
+  t: number; // Milliseconden sinds het eerste punt van de tekening
+}
+
+export interface Stroke {
+  points: StrokePoint[];
+}
+
+export interface Drawing {
+  width: number; // Afmetingen van het canvas waarop getekend is
+  height: number;
+  strokes: Stroke[];
+}
+
 export enum BettingPhase {
   Initial = "Initial",                    // Antwoorden schrijven
   CollectingBets = "CollectingBets",      // Verzamel 3x min inzet
@@ -34,14 +53,89 @@ export interface Round {
   current_player_index: number;
   dealer_index: number;
   revealed_questions: number[]; // Welke vraagnummers zijn ge-revealed (1-4)
+  questions: Question[]; // Vragen uit de vragenbank, index 0 = vraag 1
+  question_notes: (string | null)[]; // Aantekeningen uit het draaiboek, zelfde volgorde
+  highest_bet: number; // Hoogste inzet in de lopende inzetronde
+  acted: string[]; // Spelers die sinds de laatste verhoging aan de beurt zijn geweest
+  contributions: Record<string, number>; // Totaal per speler in de pot deze ronde
+}
+
+export interface PotShare {
+  player_id: string;
+  player_name: string;
+  amount: number;
+}
+
+export interface PotResult {
+  amount: number;
+  shares: PotShare[]; // Meer dan één bij een gedeelde pot
+}
+
+export interface Question {
+  id: number;
+  text: string;
+  answer: string;
+  accepted_variants: string[]; // Andere schrijfwijzen die ook goed zijn
+  category: string | null;
+  difficulty: number | null; // 1 (makkelijk) t/m 5 (moeilijk)
+  source: string | null;
+}
+
+export type NewQuestion = Omit<Question, 'id'>;
+
+export type QuestionFormat = 'csv' | 'json' | 'xlsx';
+
+export interface RowIssue {
+  row: number; // Regelnummer zoals in de spreadsheet (kopregel = 1)
+  message: string;
+}
+
+export interface ImportReport {
+  total_rows: number;
+  imported: number;
+  duplicates: RowIssue[];
+  errors: RowIssue[];
+  dry_run: boolean; // Alleen gecontroleerd, niets opgeslagen
+}
+
+export interface ArchiveReport {
+  path: string; // Zip in de archiefmap van de app
+  game_id: string;
+  rounds: number;
+  events: number;
+  images: number;
+  missing_images: string[]; // Tekeningen en pennenstreken die niet meer op schijf stonden
+}
+
+export interface QuestionFilter {
+  text?: string; // Zoekt in vraag en antwoord
+  category?: string;
+  difficulty?: number;
+  unused_only?: boolean;
+  random?: boolean;
+  limit?: number;
+}
+
+export interface RoundAnswers {
+  player_id: string;
+  player_name: string;
+  has_folded: boolean;
+  answers: Answer[]; // Met het oordeel van de quizmaster zoals het aan het eind van de ronde stond
 }
 
 export interface RoundResult {
+  round_number: number;
   winner_id: string;
   winner_name: string;
   pot_amount: number;
+  min_bet: number; // Minimale inzet aan het eind van de ronde
   correct_answers: number;
   player_scores: [string, number][];
+  pots: PotResult[]; // Hoofdpot eerst, dan de zijpotten
+  winners: PotShare[]; // Totaal per winnaar; meer dan één bij een gedeelde pot
+  questions: Question[]; // Vragen van deze ronde
+  answers: RoundAnswers[]; // Antwoorden per speler, de lopende ronde begint weer leeg
+  balances: [string, number][]; // Saldo per speler na het uitbetalen
 }
 
 export interface GameState {
@@ -55,6 +149,126 @@ export interface GameState {
   video_mode_active: boolean;
   video_device_id: string | null;
   writing_enabled: boolean;
-  timer_seconds: number;
+  timer_seconds: number; // Zoals getoond: resterend bij aftellen, gelopen bij stopwatch
   timer_running: boolean;
+  timer_mode: TimerMode;
+  timer_duration: number; // Ingestelde tijd bij aftellen
+  timer_remaining_ms: number; // Stand van de serverklok bij deze update, zie useTimerSeconds
+  episode: Episode | null; // Draaiboek van deze aflevering, als die vooraf is voorbereid
+  rules: RuleSet; // Spelregels van dit format
+  balance_timeline: BalanceChange[]; // Elke saldowijziging; niet meegestuurd naar tablets en fill graphics
+}
+
+export type BalanceReason = 'start' | 'ante' | 'bet' | 'pot_win' | 'correction' | 'reset';
+
+export interface BalanceChange {
+  player_id: string;
+  timestamp: string;
+  round_number: number;
+  reason: BalanceReason;
+  amount: number; // Positief bij winst, negatief bij inzetten
+  balance: number; // Saldo na deze wijziging
+}
+
+export interface PlayerTimeline {
+  player_id: string;
+  player_name: string;
+  changes: BalanceChange[];
+}
+
+export type TimerMode = 'countdown' | 'stopwatch';
+
+// Standaardtijden per fase
+export interface TimerPresets {
+  writing_seconds: number;
+  betting_seconds: number;
+  lock_writing_on_expiry: boolean; // Schrijven blokkeren als de tijd om is
+}
+
+// Wat er gebeurt bij een gelijke stand in goede antwoorden
+export type TieRule = 'split_pot' | 'sudden_death' | 'quizmaster_choice';
+
+export interface RuleSet {
+  name: string;
+  starting_balance: number;
+  min_bets: number[]; // Minimale inzet per ronde; de laatste geldt ook voor latere rondes
+  bet_min: number;
+  bet_max: number;
+  rounds: number;
+  questions_per_round: number;
+  elimination_after_round: number | null; // Na deze ronde valt de speler met het minste geld af
+  elimination_min_players: number;
+  tie_rule: TieRule;
+  timer: TimerPresets;
+}
+
+export interface EpisodeQuestion extends Question {
+  notes: string | null; // Regie-aantekening voor de quizmaster
+}
+
+export interface EpisodeRound {
+  round_number: number;
+  questions: EpisodeQuestion[];
+}
+
+export interface Episode {
+  id: string;
+  title: string;
+  recording_date: string | null;
+  player_names: string[];
+  rule_set: string | null; // Naam van de regelset, null = standaardregels
+  rounds: EpisodeRound[];
+}
+
+export interface EpisodeSummary {
+  id: string;
+  title: string;
+  recording_date: string | null;
+  player_names: string[];
+}
+
+export interface HistoryItem {
+  label: string; // Bijv. "place_bet player_1 €30"
+  timestamp: string;
+}
+
+export interface HistoryOverview {
+  undo: HistoryItem[]; // Meest recente eerst
+  redo: HistoryItem[];
+}
+
+// Vaste foutcodes van de backend (Tauri commands en HTTP API)
+export type ErrorCode =
+  | 'NoActiveGame'
+  | 'NoActiveRound'
+  | 'GameFinished'
+  | 'PlayerNotFound'
+  | 'PlayerNotActive'
+  | 'NotPlayersTurn'
+  | 'IllegalPhase'
+  | 'InsufficientBalance'
+  | 'InvalidBet'
+  | 'TieUnresolved'
+  | 'InvalidInput'
+  | 'NotFound'
+  | 'NothingToUndo'
+  | 'StorageError'
+  | 'SerialPortError'
+  | 'Internal'
+  | 'MoneyMismatch'
+  | 'WritingLocked';
+
+export interface GameError {
+  code: ErrorCode;
+  message: string; // Nederlandse melding voor de quizmaster
+}
+
+export function isGameError(err: unknown): err is GameError {
+  return typeof err === 'object' && err !== null && 'code' in err && 'message' in err;
+}
+
+// Leesbare melding voor elke fout: GameError van de backend, Error of iets anders
+export function errorMessage(err: unknown): string {
+  if (isGameError(err)) return err.message;
+  return err instanceof Error ? err.message : String(err);
 }